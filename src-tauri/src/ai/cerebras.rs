@@ -5,14 +5,17 @@ use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::future::ready;
 use std::pin::Pin;
+use std::sync::Arc;
 
 // use super::{AIProvider, StreamResult};
-use super::AIProvider;
+use super::{check_token_budget, AIProvider, ConversationMessage, RateLimiter};
 
 pub struct CerebrasProvider {
     api_key: String,
     model: String,
     client: Client,
+    max_input_tokens: Option<usize>,
+    rate_limiter: Option<Arc<RateLimiter>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -49,6 +52,8 @@ impl CerebrasProvider {
             api_key,
             model: "llama3.1-8b".to_string(),
             client: Client::new(),
+            max_input_tokens: None,
+            rate_limiter: None,
         }
     }
 
@@ -57,6 +62,23 @@ impl CerebrasProvider {
         self
     }
 
+    pub fn with_max_input_tokens(mut self, max_input_tokens: Option<usize>) -> Self {
+        self.max_input_tokens = max_input_tokens;
+        self
+    }
+
+    pub fn with_rate_limiter(mut self, rate_limiter: Option<Arc<RateLimiter>>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    async fn wait_for_rate_limit(&self) -> Result<(), String> {
+        match &self.rate_limiter {
+            Some(limiter) => limiter.acquire().await,
+            None => Ok(()),
+        }
+    }
+
     fn _build_system_prompt(&self, instruction: &str) -> String {
         format!(
             "You are a helpful writing assistant. {}. Provide only the rewritten text without explanations.",
@@ -73,20 +95,41 @@ impl AIProvider for CerebrasProvider {
         instruction: String,
         context: String,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<String, String>> + Send>>, String> {
+        self.stream_completion_with_history(
+            system_prompt,
+            vec![ConversationMessage {
+                role: "user".to_string(),
+                content: format!("{}:\n\n{}", instruction, context),
+            }],
+        )
+        .await
+    }
+
+    async fn stream_completion_with_history(
+        &self,
+        system_prompt: String,
+        messages: Vec<ConversationMessage>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<String, String>> + Send>>, String> {
+        check_token_budget(&system_prompt, &messages, self.max_input_tokens)?;
+        self.wait_for_rate_limit().await?;
+
         let url = "https://api.cerebras.ai/v1/chat/completions";
 
+        let mut request_messages = Vec::new();
+        if !system_prompt.is_empty() {
+            request_messages.push(CerebrasMessage {
+                role: "system".to_string(),
+                content: system_prompt,
+            });
+        }
+        request_messages.extend(messages.into_iter().map(|message| CerebrasMessage {
+            role: message.role,
+            content: message.content,
+        }));
+
         let request_body = CerebrasRequest {
             model: self.model.clone(),
-            messages: vec![
-                CerebrasMessage {
-                    role: "system".to_string(),
-                    content: system_prompt,
-                },
-                CerebrasMessage {
-                    role: "user".to_string(),
-                    content: format!("{}:\n\n{}", instruction, context),
-                },
-            ],
+            messages: request_messages,
             stream: true,
         };
 
@@ -0,0 +1,139 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock};
+use tauri::{command, AppHandle, Manager};
+
+use super::RateLimiter;
+
+// ============================================================================
+// AI Configuration
+// ============================================================================
+//
+// Provider settings are app-wide (like the API keys in the keyring), so the
+// config lives under the app data directory rather than inside a vault.
+
+const AI_CONFIG_FILE_NAME: &str = ".moss/ai_config.json";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AIConfig {
+    #[serde(default)]
+    pub token_budgets: HashMap<String, usize>, // Provider -> max input tokens
+    #[serde(default)]
+    pub rate_limits: HashMap<String, u32>, // Provider -> requests per minute
+    #[serde(default)]
+    pub fallback_chain: Vec<(String, String)>, // (provider, model), tried in order
+}
+
+/// Limiters live for the whole process so the window spans separate commands
+static RATE_LIMITERS: OnceLock<Mutex<HashMap<String, Arc<RateLimiter>>>> = OnceLock::new();
+
+/// Shared rate limiter for a provider, if one is configured
+pub fn rate_limiter(config: &AIConfig, provider: &str) -> Option<Arc<RateLimiter>> {
+    let requests_per_minute = *config.rate_limits.get(provider)?;
+
+    let limiters = RATE_LIMITERS.get_or_init(|| Mutex::new(HashMap::new()));
+    let mut limiters = limiters.lock().ok()?;
+
+    // Replace the limiter if the configured rate has changed
+    let limiter = limiters
+        .entry(provider.to_string())
+        .and_modify(|limiter| {
+            if limiter.requests_per_minute != requests_per_minute {
+                *limiter = Arc::new(RateLimiter::new(requests_per_minute));
+            }
+        })
+        .or_insert_with(|| Arc::new(RateLimiter::new(requests_per_minute)));
+
+    Some(limiter.clone())
+}
+
+fn config_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    let data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve app data directory: {}", e))?;
+    Ok(data_dir.join(AI_CONFIG_FILE_NAME))
+}
+
+/// Load the AI config, falling back to defaults if it doesn't exist yet
+pub fn load_ai_config(app_handle: &AppHandle) -> Result<AIConfig, String> {
+    let path = config_path(app_handle)?;
+    if !path.exists() {
+        return Ok(AIConfig::default());
+    }
+
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read AI config: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse AI config: {}", e))
+}
+
+pub fn save_ai_config(app_handle: &AppHandle, config: &AIConfig) -> Result<(), String> {
+    let path = config_path(app_handle)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create config directory: {}", e))?;
+    }
+
+    let json = serde_json::to_string_pretty(config)
+        .map_err(|e| format!("Failed to serialize AI config: {}", e))?;
+    fs::write(&path, json).map_err(|e| format!("Failed to write AI config: {}", e))
+}
+
+/// Set the maximum estimated input tokens for a provider (0 removes the limit)
+#[command]
+pub async fn ai_set_token_budget(
+    app_handle: AppHandle,
+    provider: String,
+    max_tokens: usize,
+) -> Result<(), String> {
+    let mut config = load_ai_config(&app_handle)?;
+
+    if max_tokens == 0 {
+        config.token_budgets.remove(&provider);
+    } else {
+        config.token_budgets.insert(provider, max_tokens);
+    }
+
+    save_ai_config(&app_handle, &config)
+}
+
+/// Set the maximum requests per minute for a provider (0 removes the limit)
+#[command]
+pub async fn ai_set_rate_limit(
+    app_handle: AppHandle,
+    provider: String,
+    requests_per_minute: u32,
+) -> Result<(), String> {
+    let mut config = load_ai_config(&app_handle)?;
+
+    if requests_per_minute == 0 {
+        config.rate_limits.remove(&provider);
+    } else {
+        config.rate_limits.insert(provider, requests_per_minute);
+    }
+
+    save_ai_config(&app_handle, &config)
+}
+
+/// Providers that can take part in a fallback chain
+const KNOWN_PROVIDERS: [&str; 4] = ["gemini", "cerebras", "openrouter", "ollama"];
+
+/// Set the ordered `(provider, model)` fallback chain (empty clears it)
+#[command]
+pub async fn ai_configure_fallback_chain(
+    app_handle: AppHandle,
+    providers: Vec<(String, String)>,
+) -> Result<(), String> {
+    if let Some((provider, _)) = providers
+        .iter()
+        .find(|(provider, _)| !KNOWN_PROVIDERS.contains(&provider.as_str()))
+    {
+        return Err(format!("Unknown provider: {}", provider));
+    }
+
+    let mut config = load_ai_config(&app_handle)?;
+    config.fallback_chain = providers;
+    save_ai_config(&app_handle, &config)
+}
@@ -6,14 +6,17 @@ use serde::Deserialize;
 use serde_json::json;
 use std::future::ready;
 use std::pin::Pin;
+use std::sync::Arc;
 
 // use super::{AIProvider, StreamResult};
-use super::AIProvider;
+use super::{check_token_budget, AIProvider, ConversationMessage, RateLimiter};
 
 pub struct GeminiProvider {
     api_key: String,
     model: String,
     client: Client,
+    max_input_tokens: Option<usize>,
+    rate_limiter: Option<Arc<RateLimiter>>,
 }
 
 // #[derive(Debug, Serialize)]
@@ -57,6 +60,8 @@ impl GeminiProvider {
             api_key,
             model: "gemini-2.5-flash".to_string(),
             client: Client::new(),
+            max_input_tokens: None,
+            rate_limiter: None,
         }
     }
 
@@ -64,6 +69,23 @@ impl GeminiProvider {
         self.model = model;
         self
     }
+
+    pub fn with_max_input_tokens(mut self, max_input_tokens: Option<usize>) -> Self {
+        self.max_input_tokens = max_input_tokens;
+        self
+    }
+
+    pub fn with_rate_limiter(mut self, rate_limiter: Option<Arc<RateLimiter>>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    async fn wait_for_rate_limit(&self) -> Result<(), String> {
+        match &self.rate_limiter {
+            Some(limiter) => limiter.acquire().await,
+            None => Ok(()),
+        }
+    }
 }
 
 #[async_trait]
@@ -74,23 +96,60 @@ impl AIProvider for GeminiProvider {
         instruction: String,
         context: String,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<String, String>> + Send>>, String> {
+        let prompt = format!(
+            "{}\n\n{}:\n\n{}\n\nPlease provide the rewritten text without any explanation or additional commentary.",
+            system_prompt, instruction, context
+        );
+
+        self.stream_completion_with_history(
+            String::new(),
+            vec![ConversationMessage {
+                role: "user".to_string(),
+                content: prompt,
+            }],
+        )
+        .await
+    }
+
+    async fn stream_completion_with_history(
+        &self,
+        system_prompt: String,
+        messages: Vec<ConversationMessage>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<String, String>> + Send>>, String> {
+        check_token_budget(&system_prompt, &messages, self.max_input_tokens)?;
+        self.wait_for_rate_limit().await?;
+
         let url = format!(
             "https://generativelanguage.googleapis.com/v1beta/models/{}:streamGenerateContent?key={}&alt=sse",
             self.model, self.api_key
         );
 
-        let prompt = format!(
-            "{}\n\n{}:\n\n{}\n\nPlease provide the rewritten text without any explanation or additional commentary.",
-            system_prompt, instruction, context
-        );
+        // Gemini calls the assistant role "model"
+        let contents: Vec<serde_json::Value> = messages
+            .into_iter()
+            .map(|message| {
+                let role = if message.role == "assistant" || message.role == "model" {
+                    "model"
+                } else {
+                    "user"
+                };
+                json!({
+                    "role": role,
+                    "parts": [{
+                        "text": message.content
+                    }]
+                })
+            })
+            .collect();
 
-        let body = json!({
-            "contents": [{
+        let mut body = json!({ "contents": contents });
+        if !system_prompt.is_empty() {
+            body["systemInstruction"] = json!({
                 "parts": [{
-                    "text": prompt
+                    "text": system_prompt
                 }]
-            }]
-        });
+            });
+        }
 
         let response = self
             .client
@@ -186,6 +245,8 @@ impl AIProvider for GeminiProvider {
             }
         });
 
+        self.wait_for_rate_limit().await?;
+
         let response = self
             .client
             .post(&url)
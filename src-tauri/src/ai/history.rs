@@ -0,0 +1,195 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::command;
+
+// ============================================================================
+// AI Rewrite History
+// ============================================================================
+//
+// An undo stack for AI rewrites, kept apart from the git history so a rewrite
+// can be reverted even after later edits have been committed.
+
+const HISTORY_FILE_NAME: &str = ".moss/ai_history.json";
+
+/// Most records kept per note; older ones are dropped first
+const MAX_RECORDS_PER_NOTE: usize = 50;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AIRewriteRecord {
+    pub id: String,
+    pub note_path: String, // Relative to the vault
+    pub original_content: String,
+    pub rewritten_content: String,
+    pub provider: String,
+    pub model: String,
+    pub instruction: String,
+    pub created_at: u64, // Unix timestamp
+}
+
+fn vault_dir(vault_path: &str) -> Result<&Path, String> {
+    let vault = Path::new(vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+    Ok(vault)
+}
+
+/// Normalize a note path (absolute or relative) to its vault-relative form
+fn relative_note_path(vault: &Path, note_path: &str) -> Result<String, String> {
+    let path = Path::new(note_path);
+    let relative = if path.is_absolute() {
+        path.strip_prefix(vault)
+            .map_err(|_| format!("Note '{}' is outside the vault", note_path))?
+    } else {
+        path
+    };
+    if !relative
+        .components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+    {
+        return Err(format!("Note '{}' is outside the vault", note_path));
+    }
+    Ok(relative.to_string_lossy().to_string())
+}
+
+/// Full path of a recorded note, refusing anything that resolves outside the vault
+/// (the history file is plain JSON and may have been edited)
+fn note_file_in_vault(vault: &Path, note_path: &str) -> Result<PathBuf, String> {
+    let path = vault.join(relative_note_path(vault, note_path)?);
+    let outside = || format!("Note '{}' is outside the vault", note_path);
+
+    let vault = vault
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve vault path: {}", e))?;
+    let parent = path
+        .parent()
+        .ok_or_else(outside)?
+        .canonicalize()
+        .map_err(|_| format!("Folder of note '{}' no longer exists", note_path))?;
+    if !parent.starts_with(&vault) {
+        return Err(outside());
+    }
+
+    // A symlinked note could still point elsewhere
+    if let Ok(target) = path.canonicalize() {
+        if !target.starts_with(&vault) {
+            return Err(outside());
+        }
+    }
+    Ok(path)
+}
+
+/// Records in the order they were saved, oldest first
+fn load_history(vault: &Path) -> Result<Vec<AIRewriteRecord>, String> {
+    let path = vault.join(HISTORY_FILE_NAME);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read AI history: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse AI history: {}", e))
+}
+
+fn save_history(vault: &Path, records: &[AIRewriteRecord]) -> Result<(), String> {
+    let path = vault.join(HISTORY_FILE_NAME);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create .moss dir: {}", e))?;
+    }
+
+    let json = serde_json::to_string_pretty(records)
+        .map_err(|e| format!("Failed to serialize AI history: {}", e))?;
+    fs::write(&path, json).map_err(|e| format!("Failed to write AI history: {}", e))
+}
+
+/// Record an AI rewrite so it can be undone later
+#[command]
+pub async fn save_ai_rewrite_record(
+    vault_path: String,
+    note_path: String,
+    original_content: String,
+    rewritten_content: String,
+    provider: String,
+    model: String,
+    instruction: String,
+) -> Result<AIRewriteRecord, String> {
+    let vault = vault_dir(&vault_path)?;
+    let note_path = relative_note_path(vault, &note_path)?;
+
+    let record = AIRewriteRecord {
+        id: uuid::Uuid::new_v4().to_string(),
+        note_path,
+        original_content,
+        rewritten_content,
+        provider,
+        model,
+        instruction,
+        created_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+    };
+
+    let mut records = load_history(vault)?;
+    records.push(record.clone());
+
+    // Keep only the newest records for this note
+    let note_count = records
+        .iter()
+        .filter(|r| r.note_path == record.note_path)
+        .count();
+    let mut to_drop = note_count.saturating_sub(MAX_RECORDS_PER_NOTE);
+    records.retain(|r| {
+        if to_drop > 0 && r.note_path == record.note_path {
+            to_drop -= 1;
+            return false;
+        }
+        true
+    });
+
+    save_history(vault, &records)?;
+    Ok(record)
+}
+
+/// AI rewrites of a note, newest first
+#[command]
+pub async fn list_ai_rewrite_records(
+    vault_path: String,
+    note_path: String,
+    limit: Option<usize>,
+) -> Result<Vec<AIRewriteRecord>, String> {
+    let vault = vault_dir(&vault_path)?;
+    let note_path = relative_note_path(vault, &note_path)?;
+
+    Ok(load_history(vault)?
+        .into_iter()
+        .rev()
+        .filter(|r| r.note_path == note_path)
+        .take(limit.unwrap_or(MAX_RECORDS_PER_NOTE))
+        .collect())
+}
+
+/// Write a rewrite's original content back to its note
+#[command]
+pub async fn restore_ai_rewrite(vault_path: String, record_id: String) -> Result<(), String> {
+    let vault = vault_dir(&vault_path)?;
+
+    let record = load_history(vault)?
+        .into_iter()
+        .find(|r| r.id == record_id)
+        .ok_or_else(|| format!("AI rewrite '{}' not found", record_id))?;
+
+    let path = note_file_in_vault(vault, &record.note_path)?;
+    fs::write(&path, &record.original_content)
+        .map_err(|e| format!("Failed to write note: {}", e))?;
+
+    // Auto-commit if Git repository, silently ignoring commit failures
+    if let Some(repo) = crate::git_manager::open_repository(vault) {
+        let _ =
+            crate::git_manager::auto_commit_mosaic_changes(&repo, "Reverted AI rewrite", &[&path]);
+    }
+
+    Ok(())
+}
@@ -1,14 +1,30 @@
 use async_trait::async_trait;
-use futures::stream::Stream;
+use futures::stream::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use std::pin::Pin;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
 
 pub mod cerebras;
+pub mod config;
 pub mod gemini;
+pub mod history;
+pub mod notes;
 pub mod ollama;
 pub mod openrouter;
+pub mod prompts;
 
 pub type StreamResult = Result<Pin<Box<dyn Stream<Item = Result<String, String>> + Send>>, String>;
 
+/// A single turn in a conversation
+/// `role` is "user" or "assistant"
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversationMessage {
+    pub role: String,
+    pub content: String,
+}
+
 #[async_trait]
 pub trait AIProvider: Send + Sync {
     /// Stream a completion from the AI provider
@@ -19,9 +35,370 @@ pub trait AIProvider: Send + Sync {
         context: String,
     ) -> StreamResult;
 
+    /// Stream a completion for a multi-turn conversation
+    async fn stream_completion_with_history(
+        &self,
+        system_prompt: String,
+        messages: Vec<ConversationMessage>,
+    ) -> StreamResult;
+
     /// Test if the API key is valid
     async fn test_connection(&self) -> Result<bool, String>;
 
     /// Generate embeddings for the given text
     async fn get_embedding(&self, text: &str) -> Result<Vec<f32>, String>;
 }
+
+/// Rough token estimate for budgeting (about 4 characters per token)
+pub fn estimate_token_count(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
+
+/// Fail early when the prompt would exceed the configured input token budget
+pub fn check_token_budget(
+    system_prompt: &str,
+    messages: &[ConversationMessage],
+    limit: Option<usize>,
+) -> Result<(), String> {
+    let limit = match limit {
+        Some(limit) => limit,
+        None => return Ok(()),
+    };
+
+    let total = estimate_token_count(system_prompt)
+        + messages
+            .iter()
+            .map(|message| estimate_token_count(&message.content))
+            .sum::<usize>();
+
+    if total > limit {
+        return Err(format!("Prompt exceeds token budget of {}", limit));
+    }
+
+    Ok(())
+}
+
+/// Sliding-window limiter shared by all requests to one provider
+pub struct RateLimiter {
+    pub requests_per_minute: u32,
+    window: tokio::sync::Mutex<VecDeque<Instant>>,
+}
+
+impl RateLimiter {
+    pub fn new(requests_per_minute: u32) -> Self {
+        Self {
+            requests_per_minute,
+            window: tokio::sync::Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Wait until a request slot is free within the last minute, then claim it
+    pub async fn acquire(&self) -> Result<(), String> {
+        if self.requests_per_minute == 0 {
+            return Ok(());
+        }
+
+        let period = Duration::from_secs(60);
+
+        loop {
+            let wait = {
+                let mut window = self.window.lock().await;
+                let now = Instant::now();
+
+                while window
+                    .front()
+                    .is_some_and(|&sent| now.duration_since(sent) >= period)
+                {
+                    window.pop_front();
+                }
+
+                if window.len() < self.requests_per_minute as usize {
+                    window.push_back(now);
+                    return Ok(());
+                }
+
+                match window.front() {
+                    Some(&oldest) => period.saturating_sub(now.duration_since(oldest)),
+                    None => return Err("Rate limiter window is empty".to_string()),
+                }
+            };
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// Build a provider with the app-wide token budget and rate limit applied
+pub async fn create_provider(
+    app_handle: &AppHandle,
+    provider: &str,
+    model: String,
+) -> Result<Box<dyn AIProvider>, String> {
+    let api_key = match crate::get_api_key(provider.to_string()).await {
+        Ok(key) => key,
+        Err(_) if provider == "ollama" => "".to_string(),
+        Err(e) => return Err(e),
+    };
+
+    let config = config::load_ai_config(app_handle)?;
+    let budget = config.token_budgets.get(provider).copied();
+    let rate_limiter = config::rate_limiter(&config, provider);
+
+    let ai_provider: Box<dyn AIProvider> = match provider {
+        "gemini" => Box::new(
+            gemini::GeminiProvider::new(api_key)
+                .with_model(model)
+                .with_max_input_tokens(budget)
+                .with_rate_limiter(rate_limiter),
+        ),
+        "cerebras" => Box::new(
+            cerebras::CerebrasProvider::new(api_key)
+                .with_model(model)
+                .with_max_input_tokens(budget)
+                .with_rate_limiter(rate_limiter),
+        ),
+        "openrouter" => Box::new(
+            openrouter::OpenRouterProvider::new(api_key)
+                .with_model(model)
+                .with_max_input_tokens(budget)
+                .with_rate_limiter(rate_limiter),
+        ),
+        "ollama" => Box::new(
+            ollama::OllamaProvider::new(api_key)
+                .with_model(model)
+                .with_max_input_tokens(budget)
+                .with_rate_limiter(rate_limiter),
+        ),
+        _ => return Err("Invalid provider".to_string()),
+    };
+
+    Ok(ai_provider)
+}
+
+/// Payload of the `ai-provider-fallback` event
+#[derive(Debug, Clone, Serialize)]
+pub struct ProviderFallback {
+    pub from: String,
+    pub to: String,
+    pub reason: String,
+}
+
+/// Tries each provider in turn, moving on when a request fails to start
+/// Errors raised after a stream has started are passed through as-is
+pub struct FallbackAIProvider {
+    providers: Vec<Box<dyn AIProvider>>,
+    names: Vec<String>, // Provider name for each entry, used in fallback events
+    retry_on_error: bool,
+    app_handle: Option<AppHandle>,
+}
+
+impl FallbackAIProvider {
+    /// With `retry_on_error` off, only the first provider is ever used
+    pub fn new(providers: Vec<(String, Box<dyn AIProvider>)>, retry_on_error: bool) -> Self {
+        let (names, providers) = providers.into_iter().unzip();
+        Self {
+            providers,
+            names,
+            retry_on_error,
+            app_handle: None,
+        }
+    }
+
+    /// Emit `ai-provider-fallback` events through this handle
+    pub fn with_app_handle(mut self, app_handle: AppHandle) -> Self {
+        self.app_handle = Some(app_handle);
+        self
+    }
+
+    /// Whether to try the provider after `failed`, announcing the switch if so
+    fn fall_through(&self, failed: usize, reason: &str) -> bool {
+        let Some(next) = self.names.get(failed + 1) else {
+            return false;
+        };
+        if !self.retry_on_error {
+            return false;
+        }
+
+        if let Some(app_handle) = &self.app_handle {
+            let _ = app_handle.emit(
+                "ai-provider-fallback",
+                ProviderFallback {
+                    from: self.names[failed].clone(),
+                    to: next.clone(),
+                    reason: reason.to_string(),
+                },
+            );
+        }
+        true
+    }
+}
+
+const NO_PROVIDERS_ERROR: &str = "No AI providers in the fallback chain";
+
+#[async_trait]
+impl AIProvider for FallbackAIProvider {
+    async fn stream_completion(
+        &self,
+        system_prompt: String,
+        instruction: String,
+        context: String,
+    ) -> StreamResult {
+        for (index, provider) in self.providers.iter().enumerate() {
+            match provider
+                .stream_completion(system_prompt.clone(), instruction.clone(), context.clone())
+                .await
+            {
+                Ok(stream) => return Ok(stream),
+                Err(e) if self.fall_through(index, &e) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        Err(NO_PROVIDERS_ERROR.to_string())
+    }
+
+    async fn stream_completion_with_history(
+        &self,
+        system_prompt: String,
+        messages: Vec<ConversationMessage>,
+    ) -> StreamResult {
+        for (index, provider) in self.providers.iter().enumerate() {
+            match provider
+                .stream_completion_with_history(system_prompt.clone(), messages.clone())
+                .await
+            {
+                Ok(stream) => return Ok(stream),
+                Err(e) if self.fall_through(index, &e) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        Err(NO_PROVIDERS_ERROR.to_string())
+    }
+
+    async fn test_connection(&self) -> Result<bool, String> {
+        for (index, provider) in self.providers.iter().enumerate() {
+            match provider.test_connection().await {
+                Ok(connected) => return Ok(connected),
+                Err(e) if self.fall_through(index, &e) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        Err(NO_PROVIDERS_ERROR.to_string())
+    }
+
+    async fn get_embedding(&self, text: &str) -> Result<Vec<f32>, String> {
+        for (index, provider) in self.providers.iter().enumerate() {
+            match provider.get_embedding(text).await {
+                Ok(embedding) => return Ok(embedding),
+                Err(e) if self.fall_through(index, &e) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        Err(NO_PROVIDERS_ERROR.to_string())
+    }
+}
+
+/// Build the configured fallback chain, or `None` if none is configured
+/// Entries whose provider can't be created (e.g. no API key) are skipped
+pub async fn create_fallback_provider(
+    app_handle: &AppHandle,
+) -> Result<Option<FallbackAIProvider>, String> {
+    let config = config::load_ai_config(app_handle)?;
+    if config.fallback_chain.is_empty() {
+        return Ok(None);
+    }
+
+    let mut providers = Vec::new();
+    for (provider, model) in &config.fallback_chain {
+        if let Ok(ai_provider) = create_provider(app_handle, provider, model.clone()).await {
+            providers.push((provider.clone(), ai_provider));
+        }
+    }
+    if providers.is_empty() {
+        return Err("None of the providers in the fallback chain are available".to_string());
+    }
+
+    Ok(Some(
+        FallbackAIProvider::new(providers, true).with_app_handle(app_handle.clone()),
+    ))
+}
+
+/// Forward a completion stream to the frontend as ai-stream-* events
+pub async fn emit_stream(
+    app_handle: &AppHandle,
+    mut stream: Pin<Box<dyn Stream<Item = Result<String, String>> + Send>>,
+) -> Result<(), String> {
+    while let Some(chunk_result) = stream.next().await {
+        match chunk_result {
+            Ok(chunk) => {
+                app_handle
+                    .emit("ai-stream-chunk", chunk)
+                    .map_err(|e| e.to_string())?;
+            }
+            Err(e) => {
+                app_handle
+                    .emit("ai-stream-error", e)
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+    }
+
+    app_handle
+        .emit("ai-stream-done", ())
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// Run a completion to the end and return the full text
+pub async fn complete(
+    provider: &dyn AIProvider,
+    system_prompt: String,
+    messages: Vec<ConversationMessage>,
+) -> Result<String, String> {
+    let mut stream = provider
+        .stream_completion_with_history(system_prompt, messages)
+        .await?;
+
+    let mut output = String::new();
+    while let Some(chunk) = stream.next().await {
+        output.push_str(&chunk?);
+    }
+
+    Ok(output)
+}
+
+/// Like `emit_stream`, but also returns the full streamed text
+/// Fails with the first stream error once the stream has ended
+pub async fn emit_stream_collect(
+    app_handle: &AppHandle,
+    mut stream: Pin<Box<dyn Stream<Item = Result<String, String>> + Send>>,
+) -> Result<String, String> {
+    let mut text = String::new();
+    let mut first_error = None;
+
+    while let Some(chunk_result) = stream.next().await {
+        match chunk_result {
+            Ok(chunk) => {
+                text.push_str(&chunk);
+                app_handle
+                    .emit("ai-stream-chunk", chunk)
+                    .map_err(|e| e.to_string())?;
+            }
+            Err(e) => {
+                first_error.get_or_insert_with(|| e.clone());
+                app_handle
+                    .emit("ai-stream-error", e)
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+    }
+
+    app_handle
+        .emit("ai-stream-done", ())
+        .map_err(|e| e.to_string())?;
+
+    match first_error {
+        Some(e) => Err(e),
+        None => Ok(text),
+    }
+}
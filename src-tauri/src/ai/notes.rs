@@ -0,0 +1,879 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use tauri::{command, AppHandle, Emitter};
+
+use super::{complete, AIProvider, ConversationMessage};
+
+// ============================================================================
+// AI Note Helpers
+// ============================================================================
+
+/// Longest note excerpt sent to the model (roughly 2-3k tokens)
+const MAX_NOTE_CHARS: usize = 8000;
+
+/// Most existing tags listed in the prompt as vocabulary
+const MAX_VOCABULARY_TAGS: usize = 200;
+
+fn truncate_chars(text: &str, max_chars: usize) -> String {
+    if text.chars().count() > max_chars {
+        text.chars().take(max_chars).collect()
+    } else {
+        text.to_string()
+    }
+}
+
+/// Read a note by vault-relative (or absolute) path
+fn read_note(vault: &Path, note_path: &str) -> Result<(std::path::PathBuf, String), String> {
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!(
+            "Vault path '{}' does not exist",
+            vault.to_string_lossy()
+        ));
+    }
+
+    let path = crate::tools::resolve_note_path(vault, note_path)
+        .ok_or_else(|| format!("Note '{}' not found", note_path))?;
+    let content = fs::read_to_string(&path).map_err(|e| format!("Failed to read note: {}", e))?;
+
+    Ok((path, content))
+}
+
+/// `create_provider`, with an empty provider or model filled in from the vault config
+async fn vault_provider(
+    app_handle: &AppHandle,
+    vault: &Path,
+    provider: &str,
+    model: String,
+) -> Result<Box<dyn AIProvider>, String> {
+    let config = crate::vault_config::load_config(vault).ok();
+    let provider = match provider.trim() {
+        "" => config
+            .as_ref()
+            .and_then(|c| c.ai_provider.clone())
+            .ok_or_else(|| "No AI provider given and the vault has no default".to_string())?,
+        provider => provider.to_string(),
+    };
+    let model = match model.trim() {
+        "" => config.and_then(|c| c.default_ai_model).unwrap_or_default(),
+        _ => model,
+    };
+
+    super::create_provider(app_handle, &provider, model).await
+}
+
+/// Normalize a tag to the vault's `#tag` form (lowercase, dashes for spaces)
+fn normalize_tag(tag: &str) -> String {
+    tag.trim()
+        .trim_start_matches('#')
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Pull a JSON string array out of a model response
+/// Models often wrap the array in prose or code fences, so only the outermost
+/// `[...]` is parsed
+fn parse_tag_array(response: &str) -> Result<Vec<String>, String> {
+    let start = response.find('[');
+    let end = response.rfind(']');
+
+    match (start, end) {
+        (Some(start), Some(end)) if start < end => {
+            serde_json::from_str::<Vec<String>>(&response[start..=end])
+                .map_err(|e| format!("Failed to parse tag suggestions: {}", e))
+        }
+        _ => Err("AI response did not contain a tag list".to_string()),
+    }
+}
+
+// ============================================================================
+// Tag Suggestions
+// ============================================================================
+
+/// Ask the model for 3-7 tags for a note, preferring the vault's existing tags
+/// Tags the note already has are left out; the note itself isn't modified
+#[command]
+pub async fn ai_suggest_tags(
+    app_handle: AppHandle,
+    vault_path: String,
+    note_path: String,
+    provider: String,
+    model: String,
+) -> Result<Vec<String>, String> {
+    let vault = Path::new(&vault_path);
+    let (_, content) = read_note(vault, &note_path)?;
+
+    let tags_data = crate::tags::get_tags_data_with_cache(vault)?;
+    let mut vocabulary: Vec<&crate::tags::TagInfo> = tags_data.tags.iter().collect();
+    vocabulary.sort_by_key(|info| std::cmp::Reverse(info.count));
+    let vocabulary: Vec<&str> = vocabulary
+        .iter()
+        .take(MAX_VOCABULARY_TAGS)
+        .map(|info| info.tag.as_str())
+        .collect();
+
+    let system_prompt = "You are a tagging assistant for a personal knowledge base. \
+        Respond only with a JSON array of 3 to 7 lowercase tag strings, without the # prefix \
+        and without any explanation. Prefer tags from the existing vocabulary, and only \
+        introduce a new tag when none of the existing ones fit."
+        .to_string();
+
+    let instruction = format!(
+        "Existing tags: {}\n\nNote:\n\n{}",
+        if vocabulary.is_empty() {
+            "(none)".to_string()
+        } else {
+            vocabulary.join(", ")
+        },
+        truncate_chars(crate::frontmatter::strip(&content), MAX_NOTE_CHARS)
+    );
+
+    let ai_provider = vault_provider(&app_handle, Path::new(&vault_path), &provider, model).await?;
+    let response = complete(
+        ai_provider.as_ref(),
+        system_prompt,
+        vec![ConversationMessage {
+            role: "user".to_string(),
+            content: instruction,
+        }],
+    )
+    .await?;
+
+    let existing: HashSet<String> = crate::tags::extract_tags_from_content(&content)
+        .into_iter()
+        .collect();
+
+    let mut seen = HashSet::new();
+    let suggestions = parse_tag_array(&response)?
+        .iter()
+        .map(|tag| normalize_tag(tag))
+        .filter(|tag| !tag.is_empty() && !existing.contains(tag))
+        .filter(|tag| seen.insert(tag.clone()))
+        .take(7)
+        .collect();
+
+    Ok(suggestions)
+}
+
+/// Add tags to a note's YAML frontmatter `tags:` list
+#[command]
+pub async fn ai_apply_suggested_tags(
+    vault_path: String,
+    note_path: String,
+    tags: Vec<String>,
+) -> Result<(), String> {
+    let vault = Path::new(&vault_path);
+    let (path, content) = read_note(vault, &note_path)?;
+
+    let mut mapping = crate::frontmatter::parse(&content)?;
+    let mut merged = crate::frontmatter::tags(&mapping);
+
+    let mut added = 0;
+    for tag in tags.iter().map(|tag| normalize_tag(tag)) {
+        if !tag.is_empty() && !merged.iter().any(|t| t.to_lowercase() == tag) {
+            merged.push(tag);
+            added += 1;
+        }
+    }
+
+    if added == 0 {
+        return Ok(());
+    }
+
+    crate::frontmatter::set_tags(&mut mapping, &merged);
+    let new_content = crate::frontmatter::compose(&mapping, crate::frontmatter::strip(&content))?;
+
+    fs::write(&path, new_content).map_err(|e| format!("Failed to write note: {}", e))?;
+
+    // Auto-commit if Git repository
+    if let Some(repo) = crate::git_manager::open_repository(vault) {
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let _ = crate::git_manager::auto_commit_mosaic_changes(
+            &repo,
+            &format!(
+                "Added {} tag{} to {}",
+                added,
+                if added == 1 { "" } else { "s" },
+                file_name
+            ),
+            &[&path],
+        ); // Silently fail if commit fails
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// Summaries
+// ============================================================================
+
+/// Longest combined input for a folder digest
+const MAX_DIGEST_CHARS: usize = 24000;
+
+/// Stream a summary of a note in exactly `max_sentences` sentences
+/// Output is emitted as ai-stream-* events, like `ai_rewrite_text`
+#[command]
+pub async fn ai_summarize_note(
+    app_handle: AppHandle,
+    vault_path: String,
+    note_path: String,
+    provider: String,
+    model: String,
+    max_sentences: usize,
+) -> Result<(), String> {
+    if max_sentences == 0 {
+        return Err("max_sentences must be at least 1".to_string());
+    }
+
+    let vault = Path::new(&vault_path);
+    let (_, content) = read_note(vault, &note_path)?;
+    let body = crate::frontmatter::strip(&content);
+
+    let system_prompt = format!(
+        "You summarize notes from a personal knowledge base. Write a concise summary of \
+         exactly {} sentence{}. Reply with the summary only, without a heading, preamble \
+         or commentary.",
+        max_sentences,
+        if max_sentences == 1 { "" } else { "s" }
+    );
+
+    let ai_provider = vault_provider(&app_handle, Path::new(&vault_path), &provider, model).await?;
+    let stream = ai_provider
+        .stream_completion_with_history(
+            system_prompt,
+            vec![ConversationMessage {
+                role: "user".to_string(),
+                content: truncate_chars(body, MAX_NOTE_CHARS),
+            }],
+        )
+        .await?;
+
+    super::emit_stream(&app_handle, stream).await
+}
+
+/// Stream a combined digest of every note in a folder (including subfolders)
+#[command]
+pub async fn ai_summarize_folder(
+    app_handle: AppHandle,
+    vault_path: String,
+    folder_path: String,
+    provider: String,
+    model: String,
+) -> Result<(), String> {
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let folder = if Path::new(&folder_path).is_absolute() {
+        Path::new(&folder_path).to_path_buf()
+    } else {
+        vault.join(&folder_path)
+    };
+    if !folder.is_dir() {
+        return Err(format!("Folder '{}' does not exist", folder_path));
+    }
+
+    let mut notes = Vec::new();
+    crate::tools::collect_notes(&folder, &mut notes, vault)?;
+    notes.retain(|note| !note.is_encrypted);
+    if notes.is_empty() {
+        return Err(format!("Folder '{}' contains no notes", folder_path));
+    }
+    notes.sort_by(|a, b| a.path.cmp(&b.path));
+
+    // Share the input budget evenly so every note is represented
+    let per_note_chars = (MAX_DIGEST_CHARS / notes.len()).max(200);
+    let mut sections = Vec::new();
+    for note in &notes {
+        if let Ok(content) = fs::read_to_string(vault.join(&note.path)) {
+            sections.push(format!(
+                "## {}\n\n{}",
+                note.title,
+                truncate_chars(crate::frontmatter::strip(&content), per_note_chars)
+            ));
+        }
+    }
+
+    let system_prompt = "You write digests of folders from a personal knowledge base. \
+        Summarize the notes below as a single digest: start with a short overview of the \
+        common themes, then give one bullet point per note with its key idea. Reply in \
+        markdown without any preamble."
+        .to_string();
+
+    let ai_provider = vault_provider(&app_handle, Path::new(&vault_path), &provider, model).await?;
+    let stream = ai_provider
+        .stream_completion_with_history(
+            system_prompt,
+            vec![ConversationMessage {
+                role: "user".to_string(),
+                content: truncate_chars(&sections.join("\n\n"), MAX_DIGEST_CHARS),
+            }],
+        )
+        .await?;
+
+    super::emit_stream(&app_handle, stream).await
+}
+
+/// Characters of each note included in a weekly summary prompt
+const WEEKLY_NOTE_CHARS: usize = 500;
+
+/// Most recently modified notes included in a weekly summary
+const MAX_WEEKLY_NOTES: usize = 50;
+
+/// Folder weekly summaries are saved to
+const SUMMARIES_FOLDER: &str = "Summaries";
+
+/// Stream a summary of the notes modified in the week starting at `week_start`
+/// (Unix timestamp), then save it as `Summaries/Week of YYYY-MM-DD.md`
+/// Regenerating a week replaces its summary note
+#[command]
+pub async fn ai_generate_weekly_summary(
+    app_handle: AppHandle,
+    vault_path: String,
+    week_start: u64,
+    provider: String,
+    model: String,
+) -> Result<(), String> {
+    use chrono::TimeZone;
+
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let week_end = week_start + 7 * 24 * 3600;
+    let local_date = |timestamp: u64| {
+        chrono::Local
+            .timestamp_opt(timestamp as i64, 0)
+            .single()
+            .map(|time| time.format("%Y-%m-%d").to_string())
+            .ok_or_else(|| format!("Invalid timestamp {}", timestamp))
+    };
+    let period_start = local_date(week_start)?;
+    let period_end = local_date(week_end - 1)?;
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_err(|e| format!("Time error: {}", e))?
+        .as_secs();
+    let days = now.saturating_sub(week_start).div_ceil(24 * 3600);
+
+    let mut notes =
+        crate::tools::agent_list_recent_notes(vault_path.clone(), usize::MAX, Some(days)).await?;
+    notes.retain(|note| {
+        note.modified >= week_start
+            && note.modified < week_end
+            && !note.is_encrypted
+            && !Path::new(&note.path).starts_with(SUMMARIES_FOLDER)
+    });
+    notes.truncate(MAX_WEEKLY_NOTES);
+    if notes.is_empty() {
+        return Err(format!(
+            "No notes were modified in the week of {}",
+            period_start
+        ));
+    }
+
+    let mut sections = Vec::new();
+    for note in &notes {
+        if let Ok(content) = fs::read_to_string(vault.join(&note.path)) {
+            sections.push(format!(
+                "## {}\n\n{}",
+                note.title,
+                truncate_chars(
+                    crate::frontmatter::strip(&content).trim(),
+                    WEEKLY_NOTE_CHARS
+                )
+            ));
+        }
+    }
+
+    let system_prompt = "You write weekly reviews for a personal knowledge base. Reply in \
+        markdown without a title or any preamble."
+        .to_string();
+    let prompt = format!(
+        "Summarize the key themes, ideas, and progress from these notes written this week:\n\n{}",
+        sections.join("\n\n")
+    );
+
+    let ai_provider = vault_provider(&app_handle, Path::new(&vault_path), &provider, model).await?;
+    let stream = ai_provider
+        .stream_completion_with_history(
+            system_prompt,
+            vec![ConversationMessage {
+                role: "user".to_string(),
+                content: prompt,
+            }],
+        )
+        .await?;
+    let summary = super::emit_stream_collect(&app_handle, stream).await?;
+
+    let mut mapping = serde_yaml::Mapping::new();
+    mapping.insert("type".into(), "summary".into());
+    mapping.insert("period_start".into(), period_start.clone().into());
+    mapping.insert("period_end".into(), period_end.into());
+    mapping.insert(
+        "source_notes".into(),
+        serde_yaml::Value::Sequence(notes.iter().map(|n| n.path.as_str().into()).collect()),
+    );
+    let title = format!("Week of {}", period_start);
+    let content =
+        crate::frontmatter::compose(&mapping, &format!("# {}\n\n{}\n", title, summary.trim()))?;
+
+    let folder = vault.join(SUMMARIES_FOLDER);
+    fs::create_dir_all(&folder).map_err(|e| format!("Failed to create summaries folder: {}", e))?;
+    let path = folder.join(format!("{}.md", title));
+    fs::write(&path, content).map_err(|e| format!("Failed to write summary: {}", e))?;
+
+    // Auto-commit if Git repository
+    if let Some(repo) = crate::git_manager::open_repository(vault) {
+        let _ = crate::git_manager::auto_commit_mosaic_changes(
+            &repo,
+            &format!("Weekly summary for {}", period_start),
+            &[&path],
+        ); // Silently fail if commit fails
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// Link Suggestions
+// ============================================================================
+
+/// Longest excerpt of each note sent when asking why two notes relate
+const MAX_REASON_NOTE_CHARS: usize = 1500;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkSuggestion {
+    pub target_note: String, // Relative path
+    pub target_title: String,
+    pub similarity: f32, // Cosine similarity of the averaged note embeddings
+    pub reason: String,  // One-sentence explanation from the model
+}
+
+/// Vault-relative paths of the notes this note already links to
+fn outgoing_links(vault: &Path, note: &Path) -> Result<HashSet<String>, String> {
+    let graph_data = crate::graph::get_graph_data_with_cache(vault, None)?;
+    let note_id = note.to_string_lossy();
+
+    Ok(graph_data
+        .links
+        .iter()
+        .filter(|link| link.source == note_id)
+        .map(|link| {
+            let target = Path::new(&link.target);
+            target
+                .strip_prefix(vault)
+                .unwrap_or(target)
+                .to_string_lossy()
+                .to_string()
+        })
+        .collect())
+}
+
+/// Suggest notes to link from this one, ranked by embedding similarity
+///
+/// Notes already linked are skipped. A note that hasn't been indexed yet is
+/// embedded on the fly; each suggestion gets a short AI-written reason.
+#[command]
+pub async fn ai_suggest_links(
+    app_handle: AppHandle,
+    vault_path: String,
+    note_path: String,
+    limit: usize,
+    provider: String,
+    model: String,
+) -> Result<Vec<LinkSuggestion>, String> {
+    let vault = Path::new(&vault_path);
+    let (path, content) = read_note(vault, &note_path)?;
+    let relative_path = path
+        .strip_prefix(vault)
+        .unwrap_or(&path)
+        .to_string_lossy()
+        .to_string();
+
+    let store_path = vault.join(".moss/vector_store.db");
+    let mut embeddings = crate::vector_store::VectorStore::open(&store_path)?.file_embeddings()?;
+
+    let note_vector = match embeddings.remove(&relative_path) {
+        Some(vector) => vector,
+        None => {
+            let api_key = crate::get_api_key("gemini".to_string()).await?;
+            let config = super::config::load_ai_config(&app_handle)?;
+            super::gemini::GeminiProvider::new(api_key)
+                .with_rate_limiter(super::config::rate_limiter(&config, "gemini"))
+                .get_embedding(&truncate_chars(
+                    crate::frontmatter::strip(&content),
+                    MAX_NOTE_CHARS,
+                ))
+                .await?
+        }
+    };
+    if embeddings.is_empty() {
+        return Err("No embeddings found. Run indexing first.".to_string());
+    }
+
+    let linked = outgoing_links(vault, &path)?;
+    let mut ranked: Vec<(String, f32)> = embeddings
+        .iter()
+        // Vectors from a different embedding model can't be compared
+        .filter(|(file, vector)| vector.len() == note_vector.len() && !linked.contains(*file))
+        .map(|(file, vector)| {
+            (
+                file.clone(),
+                crate::vector_store::cosine_similarity(&note_vector, vector),
+            )
+        })
+        .collect();
+    ranked.sort_by(|a, b| {
+        b.1.partial_cmp(&a.1)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.0.cmp(&b.0))
+    });
+
+    let system_prompt = "You help connect notes in a personal knowledge base. \
+        Given two notes, explain in one short sentence how they relate. \
+        Reply with the sentence only."
+        .to_string();
+    let note_excerpt = truncate_chars(crate::frontmatter::strip(&content), MAX_REASON_NOTE_CHARS);
+
+    let ai_provider = vault_provider(&app_handle, Path::new(&vault_path), &provider, model).await?;
+    let mut suggestions = Vec::new();
+    for (target_note, similarity) in ranked {
+        if suggestions.len() >= limit {
+            break;
+        }
+        // The index may still list notes that have since been deleted
+        let Ok(target_content) = fs::read_to_string(vault.join(&target_note)) else {
+            continue;
+        };
+
+        let target_title = crate::tools::extract_note_title(&target_content).unwrap_or_else(|| {
+            Path::new(&target_note)
+                .file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_default()
+        });
+
+        let reason = complete(
+            ai_provider.as_ref(),
+            system_prompt.clone(),
+            vec![ConversationMessage {
+                role: "user".to_string(),
+                content: format!(
+                    "Note A:\n\n{}\n\nNote B ({}):\n\n{}",
+                    note_excerpt,
+                    target_title,
+                    truncate_chars(
+                        crate::frontmatter::strip(&target_content),
+                        MAX_REASON_NOTE_CHARS
+                    )
+                ),
+            }],
+        )
+        .await?;
+
+        suggestions.push(LinkSuggestion {
+            target_note,
+            target_title,
+            similarity,
+            reason: reason.trim().to_string(),
+        });
+    }
+
+    Ok(suggestions)
+}
+
+/// Append a `[[target]]` wikilink to the end of the source note
+/// Does nothing if the source already links to the target
+#[command]
+pub async fn apply_link_suggestion(
+    vault_path: String,
+    source_note: String,
+    target_note: String,
+) -> Result<(), String> {
+    let vault = Path::new(&vault_path);
+    let (path, content) = read_note(vault, &source_note)?;
+
+    let target_stem = Path::new(&target_note)
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .ok_or_else(|| format!("Invalid target note '{}'", target_note))?;
+    if crate::links::wikilink_regex(&target_stem).is_match(&content) {
+        return Ok(());
+    }
+
+    let mut new_content = content.trim_end().to_string();
+    if !new_content.is_empty() {
+        new_content.push_str("\n\n");
+    }
+    new_content.push_str(&format!("[[{}]]\n", target_stem));
+
+    fs::write(&path, new_content).map_err(|e| format!("Failed to write note: {}", e))?;
+
+    // Auto-commit if Git repository
+    if let Some(repo) = crate::git_manager::open_repository(vault) {
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let _ = crate::git_manager::auto_commit_mosaic_changes(
+            &repo,
+            &format!("Linked {} to {}", file_name, target_stem),
+            &[&path],
+        ); // Silently fail if commit fails
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// Rewrite Preview
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AIRewritePreview {
+    pub original: String,
+    pub rewritten: String,
+    pub unified_diff: String,
+    pub additions: usize,
+    pub deletions: usize,
+}
+
+/// Unified diff between two versions of a file, with added and removed line counts
+fn line_diff(path: &Path, old: &str, new: &str) -> Result<(String, usize, usize), git2::Error> {
+    let file_name = path.file_name().map(Path::new);
+    let mut patch =
+        git2::Patch::from_buffers(old.as_bytes(), file_name, new.as_bytes(), file_name, None)?;
+    let (_context, additions, deletions) = patch.line_stats()?;
+    let diff = patch.to_buf()?.as_str().unwrap_or_default().to_string();
+    Ok((diff, additions, deletions))
+}
+
+/// Ask the model to rewrite a note, keeping its frontmatter unchanged
+async fn rewrite_note(
+    provider: &dyn AIProvider,
+    original: &str,
+    instruction: &str,
+) -> Result<String, String> {
+    let body = crate::frontmatter::strip(original);
+    let frontmatter = &original[..original.len() - body.len()];
+
+    let system_prompt = "You rewrite notes from a personal knowledge base. \
+        Follow the instruction and reply with the full rewritten markdown only, \
+        without any explanation or code fences around it."
+        .to_string();
+
+    let response = complete(
+        provider,
+        system_prompt,
+        vec![ConversationMessage {
+            role: "user".to_string(),
+            content: format!("{}:\n\n{}", instruction, body),
+        }],
+    )
+    .await?;
+
+    let mut rewritten = format!("{}{}", frontmatter, response.trim());
+    if original.ends_with('\n') {
+        rewritten.push('\n');
+    }
+    Ok(rewritten)
+}
+
+/// Rewrite a note with the model without touching the file
+/// Frontmatter is left out of the prompt and kept unchanged in `rewritten`
+#[command]
+pub async fn ai_preview_rewrite(
+    app_handle: AppHandle,
+    vault_path: String,
+    note_path: String,
+    provider: String,
+    model: String,
+    instruction: String,
+) -> Result<AIRewritePreview, String> {
+    let vault = Path::new(&vault_path);
+    let (path, original) = read_note(vault, &note_path)?;
+    let ai_provider = vault_provider(&app_handle, Path::new(&vault_path), &provider, model).await?;
+    let rewritten = rewrite_note(ai_provider.as_ref(), &original, &instruction).await?;
+
+    let (unified_diff, additions, deletions) = line_diff(&path, &original, &rewritten)
+        .map_err(|e| format!("Failed to diff rewrite: {}", e))?;
+
+    Ok(AIRewritePreview {
+        original,
+        rewritten,
+        unified_diff,
+        additions,
+        deletions,
+    })
+}
+
+/// Write a previewed rewrite to the note
+#[command]
+pub async fn ai_apply_preview(
+    vault_path: String,
+    note_path: String,
+    rewritten_content: String,
+) -> Result<(), String> {
+    let vault = Path::new(&vault_path);
+    let (path, _) = read_note(vault, &note_path)?;
+
+    fs::write(&path, rewritten_content).map_err(|e| format!("Failed to write note: {}", e))?;
+
+    // Auto-commit if Git repository
+    if let Some(repo) = crate::git_manager::open_repository(vault) {
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let _ = crate::git_manager::auto_commit_mosaic_changes(
+            &repo,
+            &format!("AI rewrite of {}", file_name),
+            &[&path],
+        ); // Silently fail if commit fails
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// Batch Improvement
+// ============================================================================
+
+/// Most notes rewritten in one batch, to keep API costs bounded
+const MAX_BATCH_NOTES: usize = 20;
+
+/// Pause between requests so batches don't trip provider rate limits
+const BATCH_REQUEST_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchProgress {
+    pub file: String, // Relative path
+    pub index: usize, // 1-based
+    pub total: usize,
+    pub applied: bool, // Whether the rewrite was written to disk
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchComplete {
+    pub improved: usize,
+    pub skipped: usize, // Rewrites identical to the original
+    pub failed: usize,
+}
+
+/// Rewrite up to 20 notes in a folder with the same instruction
+///
+/// With `dry_run` the notes are rewritten but nothing is written. Progress is
+/// reported through `ai-batch-progress` and `ai-batch-complete` events.
+#[command]
+pub async fn ai_batch_improve_notes(
+    app_handle: AppHandle,
+    vault_path: String,
+    folder_path: String,
+    provider: String,
+    model: String,
+    instruction: String,
+    dry_run: bool,
+) -> Result<(), String> {
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let folder = if Path::new(&folder_path).is_absolute() {
+        Path::new(&folder_path).to_path_buf()
+    } else {
+        vault.join(&folder_path)
+    };
+    if !folder.is_dir() {
+        return Err(format!("Folder '{}' does not exist", folder_path));
+    }
+
+    let mut notes = Vec::new();
+    crate::tools::collect_notes(&folder, &mut notes, vault)?;
+    notes.retain(|note| !note.is_encrypted);
+    notes.sort_by(|a, b| a.path.cmp(&b.path));
+    notes.truncate(MAX_BATCH_NOTES);
+
+    let ai_provider = vault_provider(&app_handle, Path::new(&vault_path), &provider, model).await?;
+
+    let total = notes.len();
+    let mut improved = 0;
+    let mut skipped = 0;
+    let mut failed = 0;
+    let mut written = Vec::new();
+
+    for (index, note) in notes.iter().enumerate() {
+        if index > 0 {
+            tokio::time::sleep(BATCH_REQUEST_INTERVAL).await;
+        }
+
+        let path = vault.join(&note.path);
+        let mut applied = false;
+        match fs::read_to_string(&path) {
+            Ok(original) => match rewrite_note(ai_provider.as_ref(), &original, &instruction).await
+            {
+                Ok(rewritten) if rewritten == original => skipped += 1,
+                Ok(rewritten) => {
+                    if dry_run {
+                        improved += 1;
+                    } else if fs::write(&path, rewritten).is_ok() {
+                        improved += 1;
+                        applied = true;
+                        written.push(path);
+                    } else {
+                        failed += 1;
+                    }
+                }
+                Err(_) => failed += 1,
+            },
+            Err(_) => failed += 1,
+        }
+
+        let _ = app_handle.emit(
+            "ai-batch-progress",
+            BatchProgress {
+                file: note.path.clone(),
+                index: index + 1,
+                total,
+                applied,
+            },
+        );
+    }
+
+    if !written.is_empty() {
+        if let Some(repo) = crate::git_manager::open_repository(vault) {
+            let paths: Vec<&Path> = written.iter().map(|p| p.as_path()).collect();
+            let _ = crate::git_manager::auto_commit_mosaic_changes(
+                &repo,
+                &format!(
+                    "AI improved {} note{} in {}",
+                    written.len(),
+                    if written.len() == 1 { "" } else { "s" },
+                    folder_path
+                ),
+                &paths,
+            ); // Silently fail if commit fails
+        }
+    }
+
+    app_handle
+        .emit(
+            "ai-batch-complete",
+            BatchComplete {
+                improved,
+                skipped,
+                failed,
+            },
+        )
+        .map_err(|e| e.to_string())
+}
@@ -1,17 +1,20 @@
 use async_trait::async_trait;
 use futures::stream::{Stream, StreamExt};
 use reqwest::Client;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::future::ready;
 use std::pin::Pin;
+use std::sync::Arc;
 
-use super::AIProvider;
+use super::{check_token_budget, AIProvider, ConversationMessage, RateLimiter};
 
 pub struct OllamaProvider {
     host: String,
     model: String,
     client: Client,
+    max_input_tokens: Option<usize>,
+    rate_limiter: Option<Arc<RateLimiter>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -30,6 +33,19 @@ struct OllamaEmbeddingResponse {
     embedding: Vec<f32>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OllamaModelInfo {
+    pub name: String,
+    pub size: u64, // Bytes on disk
+    pub digest: String,
+    pub modified_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaTagsResponse {
+    models: Vec<OllamaModelInfo>,
+}
+
 impl OllamaProvider {
     pub fn new(host: String) -> Self {
         let host_url = if host.trim().is_empty() {
@@ -43,6 +59,8 @@ impl OllamaProvider {
             // Default model, can be overridden
             model: "llama3.2".to_string(),
             client: Client::new(),
+            max_input_tokens: None,
+            rate_limiter: None,
         }
     }
 
@@ -50,6 +68,46 @@ impl OllamaProvider {
         self.model = model;
         self
     }
+
+    pub fn with_max_input_tokens(mut self, max_input_tokens: Option<usize>) -> Self {
+        self.max_input_tokens = max_input_tokens;
+        self
+    }
+
+    pub fn with_rate_limiter(mut self, rate_limiter: Option<Arc<RateLimiter>>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// List the models installed on the Ollama host
+    pub async fn list_models(&self) -> Result<Vec<OllamaModelInfo>, String> {
+        let url = format!("{}/api/tags", self.host);
+
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| format!("Failed to reach Ollama: {}", e))?;
+
+        if !response.status().is_success() {
+            return Err(format!("Ollama returned error: {}", response.status()));
+        }
+
+        let tags: OllamaTagsResponse = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse Ollama models: {}", e))?;
+
+        Ok(tags.models)
+    }
+
+    async fn wait_for_rate_limit(&self) -> Result<(), String> {
+        match &self.rate_limiter {
+            Some(limiter) => limiter.acquire().await,
+            None => Ok(()),
+        }
+    }
 }
 
 #[async_trait]
@@ -60,20 +118,43 @@ impl AIProvider for OllamaProvider {
         instruction: String,
         context: String,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<String, String>> + Send>>, String> {
+        self.stream_completion_with_history(
+            system_prompt,
+            vec![ConversationMessage {
+                role: "user".to_string(),
+                content: format!("{}\n\nContext:\n{}", instruction, context),
+            }],
+        )
+        .await
+    }
+
+    async fn stream_completion_with_history(
+        &self,
+        system_prompt: String,
+        messages: Vec<ConversationMessage>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<String, String>> + Send>>, String> {
+        check_token_budget(&system_prompt, &messages, self.max_input_tokens)?;
+        self.wait_for_rate_limit().await?;
+
         let url = format!("{}/api/chat", self.host);
 
+        let mut request_messages = Vec::new();
+        if !system_prompt.is_empty() {
+            request_messages.push(json!({
+                "role": "system",
+                "content": system_prompt
+            }));
+        }
+        request_messages.extend(messages.into_iter().map(|message| {
+            json!({
+                "role": message.role,
+                "content": message.content
+            })
+        }));
+
         let body = json!({
             "model": self.model,
-            "messages": [
-                {
-                    "role": "system",
-                    "content": system_prompt
-                },
-                {
-                    "role": "user",
-                    "content": format!("{}\n\nContext:\n{}", instruction, context)
-                }
-            ],
+            "messages": request_messages,
             "stream": true,
             "options": {
                 "num_ctx": 4096
@@ -169,6 +250,8 @@ impl AIProvider for OllamaProvider {
             "prompt": text
         });
 
+        self.wait_for_rate_limit().await?;
+
         let response = self
             .client
             .post(&url)
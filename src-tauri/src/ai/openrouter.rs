@@ -4,13 +4,17 @@ use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::future::ready;
 use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
-use super::AIProvider;
+use super::{check_token_budget, AIProvider, ConversationMessage, RateLimiter};
 
 pub struct OpenRouterProvider {
     api_key: String,
     model: String,
     client: Client,
+    max_input_tokens: Option<usize>,
+    rate_limiter: Option<Arc<RateLimiter>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -47,6 +51,8 @@ impl OpenRouterProvider {
             api_key,
             model: "google/gemini-2.0-flash-lite-preview-02-05:free".to_string(), // Default fallback
             client: Client::new(),
+            max_input_tokens: None,
+            rate_limiter: None,
         }
     }
 
@@ -54,6 +60,23 @@ impl OpenRouterProvider {
         self.model = model;
         self
     }
+
+    pub fn with_max_input_tokens(mut self, max_input_tokens: Option<usize>) -> Self {
+        self.max_input_tokens = max_input_tokens;
+        self
+    }
+
+    pub fn with_rate_limiter(mut self, rate_limiter: Option<Arc<RateLimiter>>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    async fn wait_for_rate_limit(&self) -> Result<(), String> {
+        match &self.rate_limiter {
+            Some(limiter) => limiter.acquire().await,
+            None => Ok(()),
+        }
+    }
 }
 
 #[async_trait]
@@ -64,20 +87,41 @@ impl AIProvider for OpenRouterProvider {
         instruction: String,
         context: String,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<String, String>> + Send>>, String> {
+        self.stream_completion_with_history(
+            system_prompt,
+            vec![ConversationMessage {
+                role: "user".to_string(),
+                content: format!("{}:\n\n{}", instruction, context),
+            }],
+        )
+        .await
+    }
+
+    async fn stream_completion_with_history(
+        &self,
+        system_prompt: String,
+        messages: Vec<ConversationMessage>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<String, String>> + Send>>, String> {
+        check_token_budget(&system_prompt, &messages, self.max_input_tokens)?;
+        self.wait_for_rate_limit().await?;
+
         let url = "https://openrouter.ai/api/v1/chat/completions";
 
+        let mut request_messages = Vec::new();
+        if !system_prompt.is_empty() {
+            request_messages.push(OpenRouterMessage {
+                role: "system".to_string(),
+                content: system_prompt,
+            });
+        }
+        request_messages.extend(messages.into_iter().map(|message| OpenRouterMessage {
+            role: message.role,
+            content: message.content,
+        }));
+
         let request_body = OpenRouterRequest {
             model: self.model.clone(),
-            messages: vec![
-                OpenRouterMessage {
-                    role: "system".to_string(),
-                    content: system_prompt,
-                },
-                OpenRouterMessage {
-                    role: "user".to_string(),
-                    content: format!("{}:\n\n{}", instruction, context),
-                },
-            ],
+            messages: request_messages,
             stream: true,
         };
 
@@ -177,3 +221,81 @@ impl AIProvider for OpenRouterProvider {
         Err("Embeddings are not supported by OpenRouter provider yet.".to_string())
     }
 }
+
+// ============================================================================
+// Model Catalog
+// ============================================================================
+
+const MODELS_URL: &str = "https://openrouter.ai/api/v1/models";
+const MODEL_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Catalog cache, refreshed once it is older than `MODEL_CACHE_TTL`
+static MODEL_CACHE: Mutex<Option<(Instant, Vec<OpenRouterModel>)>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenRouterModel {
+    pub id: String,
+    pub name: String,
+    pub context_length: usize,
+    pub pricing_prompt: f64,     // USD per token
+    pub pricing_completion: f64, // USD per token
+    pub provider: String,        // Vendor prefix of the id, e.g. "anthropic"
+}
+
+/// List every model available on OpenRouter (public endpoint, no API key)
+pub async fn list_openrouter_models() -> Result<Vec<OpenRouterModel>, String> {
+    if let Ok(cache) = MODEL_CACHE.lock() {
+        if let Some((fetched_at, models)) = cache.as_ref() {
+            if fetched_at.elapsed() < MODEL_CACHE_TTL {
+                return Ok(models.clone());
+            }
+        }
+    }
+
+    let response = Client::new()
+        .get(MODELS_URL)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch OpenRouter models: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("OpenRouter API error: {}", response.status()));
+    }
+
+    let data: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse OpenRouter models: {}", e))?;
+
+    // Prices come back as decimal strings
+    let price = |value: &serde_json::Value| -> f64 {
+        value
+            .as_str()
+            .and_then(|p| p.parse().ok())
+            .or_else(|| value.as_f64())
+            .unwrap_or(0.0)
+    };
+
+    let models: Vec<OpenRouterModel> = data["data"]
+        .as_array()
+        .ok_or("Unexpected OpenRouter models response")?
+        .iter()
+        .filter_map(|model| {
+            let id = model["id"].as_str()?.to_string();
+            Some(OpenRouterModel {
+                name: model["name"].as_str().unwrap_or(&id).to_string(),
+                context_length: model["context_length"].as_u64().unwrap_or(0) as usize,
+                pricing_prompt: price(&model["pricing"]["prompt"]),
+                pricing_completion: price(&model["pricing"]["completion"]),
+                provider: id.split('/').next().unwrap_or("").to_string(),
+                id,
+            })
+        })
+        .collect();
+
+    if let Ok(mut cache) = MODEL_CACHE.lock() {
+        *cache = Some((Instant::now(), models.clone()));
+    }
+
+    Ok(models)
+}
@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::command;
+
+// ============================================================================
+// Saved System Prompts
+// ============================================================================
+
+const PROMPTS_FILE_NAME: &str = ".moss/ai_prompts.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedPrompt {
+    pub name: String,
+    pub provider: String,
+    pub content: String,
+    pub created_at: u64, // Unix timestamp
+}
+
+fn prompts_path(vault_path: &str) -> Result<PathBuf, String> {
+    let vault = Path::new(vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+    Ok(vault.join(PROMPTS_FILE_NAME))
+}
+
+fn load_prompts(path: &Path) -> Result<Vec<SavedPrompt>, String> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content =
+        fs::read_to_string(path).map_err(|e| format!("Failed to read saved prompts: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse saved prompts: {}", e))
+}
+
+fn save_prompts(path: &Path, prompts: &[SavedPrompt]) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create .moss dir: {}", e))?;
+    }
+
+    let json = serde_json::to_string_pretty(prompts)
+        .map_err(|e| format!("Failed to serialize saved prompts: {}", e))?;
+    fs::write(path, json).map_err(|e| format!("Failed to write saved prompts: {}", e))
+}
+
+/// Save a reusable system prompt for a provider
+/// Saving under an existing name replaces its content
+#[command]
+pub async fn save_ai_system_prompt(
+    vault_path: String,
+    provider: String,
+    prompt_name: String,
+    prompt_content: String,
+) -> Result<(), String> {
+    let name = prompt_name.trim();
+    if name.is_empty() {
+        return Err("Prompt name cannot be empty".to_string());
+    }
+
+    let path = prompts_path(&vault_path)?;
+    let mut prompts = load_prompts(&path)?;
+
+    match prompts
+        .iter_mut()
+        .find(|p| p.provider == provider && p.name == name)
+    {
+        Some(existing) => existing.content = prompt_content,
+        None => prompts.push(SavedPrompt {
+            name: name.to_string(),
+            provider,
+            content: prompt_content,
+            created_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+        }),
+    }
+
+    save_prompts(&path, &prompts)
+}
+
+/// List saved system prompts, optionally only those for one provider
+#[command]
+pub async fn list_ai_system_prompts(
+    vault_path: String,
+    provider: Option<String>,
+) -> Result<Vec<SavedPrompt>, String> {
+    let path = prompts_path(&vault_path)?;
+    let mut prompts = load_prompts(&path)?;
+
+    if let Some(provider) = provider {
+        prompts.retain(|p| p.provider == provider);
+    }
+
+    prompts.sort_by_key(|p| p.name.to_lowercase());
+
+    Ok(prompts)
+}
+
+/// Delete a saved system prompt
+#[command]
+pub async fn delete_ai_system_prompt(
+    vault_path: String,
+    provider: String,
+    prompt_name: String,
+) -> Result<(), String> {
+    let path = prompts_path(&vault_path)?;
+    let mut prompts = load_prompts(&path)?;
+
+    let before = prompts.len();
+    prompts.retain(|p| !(p.provider == provider && p.name == prompt_name));
+
+    if prompts.len() == before {
+        return Err(format!(
+            "Prompt '{}' not found for provider '{}'",
+            prompt_name, provider
+        ));
+    }
+
+    save_prompts(&path, &prompts)
+}
@@ -0,0 +1,195 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::State;
+use tokio::task::JoinHandle;
+
+const CONFIG_FILE_NAME: &str = ".moss/auto_commit.json";
+
+/// Commit message used when auto-commit is enabled from the vault config
+const DEFAULT_MESSAGE_TEMPLATE: &str = "Auto-commit: {{changed_files_count}} files ({{datetime}})";
+
+/// Running auto-commit tasks, keyed by vault path
+pub struct AutoCommitState {
+    pub handles: Mutex<HashMap<String, JoinHandle<()>>>,
+}
+
+impl AutoCommitState {
+    pub fn new() -> Self {
+        Self {
+            handles: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Abort every running auto-commit task (called on app shutdown)
+    pub fn abort_all(&self) {
+        if let Ok(mut handles) = self.handles.lock() {
+            for (_, handle) in handles.drain() {
+                handle.abort();
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoCommitConfig {
+    pub enabled: bool,
+    pub interval_minutes: u32,
+    pub message_template: String,
+}
+
+fn config_path(vault_path: &str) -> Result<PathBuf, String> {
+    let vault = Path::new(vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+    Ok(vault.join(CONFIG_FILE_NAME))
+}
+
+fn save_config(path: &Path, config: &AutoCommitConfig) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create .moss dir: {}", e))?;
+    }
+
+    let json = serde_json::to_string_pretty(config)
+        .map_err(|e| format!("Failed to serialize auto-commit config: {}", e))?;
+    fs::write(path, json).map_err(|e| format!("Failed to write auto-commit config: {}", e))
+}
+
+/// Fill in `{{datetime}}` and `{{changed_files_count}}`
+fn render_message(template: &str, changed_files_count: usize) -> String {
+    let datetime = chrono::Local::now().format("%Y-%m-%d %H:%M").to_string();
+    template
+        .replace("{{datetime}}", &datetime)
+        .replace("{{changed_files_count}}", &changed_files_count.to_string())
+}
+
+/// Commit everything in the vault if anything changed since the last commit
+fn commit_pending_changes(vault_path: &str, message_template: &str) {
+    let Some(repo) = crate::git_manager::open_repository(Path::new(vault_path)) else {
+        return;
+    };
+
+    if !crate::git_manager::has_uncommitted_changes(&repo).unwrap_or(false) {
+        return;
+    }
+
+    let changed_files_count = repo.statuses(None).map(|s| s.len()).unwrap_or(0);
+    let message = render_message(message_template, changed_files_count);
+    if let Err(e) = crate::git_manager::commit_all_changes(&repo, &message) {
+        eprintln!("Auto-commit failed for {}: {}", vault_path, e);
+    }
+}
+
+/// Start committing the vault every `interval_minutes` minutes
+/// Without an interval, the vault config's auto-commit interval is used
+/// Restarting replaces any timer already running for the vault
+#[tauri::command]
+pub async fn start_auto_commit(
+    state: State<'_, AutoCommitState>,
+    vault_path: String,
+    interval_minutes: Option<u32>,
+    message_template: String,
+) -> Result<(), String> {
+    let path = config_path(&vault_path)?;
+    let interval_minutes = match interval_minutes {
+        Some(minutes) => minutes,
+        None => {
+            crate::vault_config::load_config(Path::new(&vault_path))?.auto_commit_interval_minutes
+        }
+    };
+    if interval_minutes == 0 {
+        return Err("Interval must be at least one minute".to_string());
+    }
+
+    save_config(
+        &path,
+        &AutoCommitConfig {
+            enabled: true,
+            interval_minutes,
+            message_template: message_template.clone(),
+        },
+    )?;
+
+    let task_vault_path = vault_path.clone();
+    let handle = tokio::spawn(async move {
+        let interval = Duration::from_secs(interval_minutes as u64 * 60);
+        loop {
+            tokio::time::sleep(interval).await;
+            // git2 status and commit calls block, keep them off the async workers
+            let vault_path = task_vault_path.clone();
+            let template = message_template.clone();
+            if let Err(e) =
+                tokio::task::spawn_blocking(move || commit_pending_changes(&vault_path, &template))
+                    .await
+            {
+                eprintln!("Auto-commit task failed: {}", e);
+            }
+        }
+    });
+
+    let mut handles = state.handles.lock().map_err(|e| e.to_string())?;
+    if let Some(previous) = handles.insert(vault_path, handle) {
+        previous.abort();
+    }
+
+    Ok(())
+}
+
+/// Stop the auto-commit timer for a vault
+#[tauri::command]
+pub async fn stop_auto_commit(
+    state: State<'_, AutoCommitState>,
+    vault_path: String,
+) -> Result<(), String> {
+    if let Some(handle) = state
+        .handles
+        .lock()
+        .map_err(|e| e.to_string())?
+        .remove(&vault_path)
+    {
+        handle.abort();
+    }
+
+    // Keep the interval and template around, just mark it disabled
+    let path = config_path(&vault_path)?;
+    if let Some(mut config) = load_auto_commit_config(&path)? {
+        config.enabled = false;
+        save_config(&path, &config)?;
+    }
+
+    Ok(())
+}
+
+fn load_auto_commit_config(path: &Path) -> Result<Option<AutoCommitConfig>, String> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read auto-commit config: {}", e))?;
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| format!("Failed to parse auto-commit config: {}", e))
+}
+
+/// Get the saved auto-commit configuration so the frontend can resume it
+/// Before auto-commit was ever started or stopped, the vault config decides
+#[tauri::command]
+pub async fn get_auto_commit_config(
+    vault_path: String,
+) -> Result<Option<AutoCommitConfig>, String> {
+    if let Some(config) = load_auto_commit_config(&config_path(&vault_path)?)? {
+        return Ok(Some(config));
+    }
+
+    let vault_config = crate::vault_config::load_config(Path::new(&vault_path))?;
+    Ok(vault_config.auto_commit_enabled.then(|| AutoCommitConfig {
+        enabled: true,
+        interval_minutes: vault_config.auto_commit_interval_minutes,
+        message_template: DEFAULT_MESSAGE_TEMPLATE.to_string(),
+    }))
+}
@@ -0,0 +1,230 @@
+use ignore::WalkBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{Cursor, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{command, State};
+use tokio::task::JoinHandle;
+use zip::write::SimpleFileOptions;
+
+// ============================================================================
+// ZIP Backups
+// ============================================================================
+
+const BACKUPS_TO_KEEP: usize = 7;
+
+/// Scheduled backup tasks, keyed by vault path
+pub struct BackupScheduleState {
+    pub handles: Mutex<HashMap<String, JoinHandle<()>>>,
+}
+
+impl BackupScheduleState {
+    pub fn new() -> Self {
+        Self {
+            handles: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Abort every scheduled backup (called on app shutdown)
+    pub fn abort_all(&self) {
+        if let Ok(mut handles) = self.handles.lock() {
+            for (_, handle) in handles.drain() {
+                handle.abort();
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupReport {
+    pub file_path: String,
+    pub total_files: usize,
+    pub total_size_bytes: u64, // Uncompressed size of the archived files
+    pub created_at: u64,       // Unix timestamp
+}
+
+/// Zip the vault into `output_path`, skipping `exclude_dir` (where backups live)
+fn write_backup(
+    vault: &Path,
+    output_path: &Path,
+    include_hidden: bool,
+    exclude_dir: Option<&Path>,
+) -> Result<BackupReport, String> {
+    let output_for_filter = output_path.to_path_buf();
+    let exclude_for_filter = exclude_dir.map(Path::to_path_buf);
+    let walker = WalkBuilder::new(vault)
+        .hidden(false)
+        .git_ignore(true)
+        .require_git(false)
+        .filter_entry(move |entry| {
+            let path = entry.path();
+            if path == output_for_filter || exclude_for_filter.as_deref() == Some(path) {
+                return false;
+            }
+            if !include_hidden {
+                let name = entry.file_name().to_string_lossy();
+                return name != ".git" && name != ".moss";
+            }
+            true
+        })
+        .build();
+
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    let mut total_files = 0;
+    let mut total_size_bytes = 0;
+
+    for entry in walker.flatten() {
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+
+        // Zip entries always use forward slashes
+        let Ok(relative) = path.strip_prefix(vault) else {
+            continue;
+        };
+        let name = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().to_string())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        let data = fs::read(path)
+            .map_err(|e| format!("Failed to read '{}': {}", relative.display(), e))?;
+        writer
+            .start_file(name, options)
+            .map_err(|e| format!("Failed to add '{}': {}", relative.display(), e))?;
+        writer
+            .write_all(&data)
+            .map_err(|e| format!("Failed to add '{}': {}", relative.display(), e))?;
+
+        total_files += 1;
+        total_size_bytes += data.len() as u64;
+    }
+
+    let buffer = writer
+        .finish()
+        .map_err(|e| format!("Failed to finish archive: {}", e))?
+        .into_inner();
+
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create backup folder: {}", e))?;
+    }
+    fs::write(output_path, buffer).map_err(|e| format!("Failed to write backup: {}", e))?;
+
+    Ok(BackupReport {
+        file_path: output_path.to_string_lossy().to_string(),
+        total_files,
+        total_size_bytes,
+        created_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+    })
+}
+
+/// Write a ZIP archive of the vault, honouring `.gitignore`
+/// Without `include_hidden`, `.git` and `.moss` are left out
+#[command]
+pub async fn backup_vault_to_zip(
+    vault_path: String,
+    output_path: String,
+    include_hidden: bool,
+) -> Result<BackupReport, String> {
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    // Zipping a large vault takes a while; keep it off the async runtime
+    let vault = vault.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        write_backup(&vault, Path::new(&output_path), include_hidden, None)
+    })
+    .await
+    .map_err(|e| format!("Backup failed: {}", e))?
+}
+
+/// Delete all but the newest `BACKUPS_TO_KEEP` archives with the given prefix
+fn prune_backups(output_dir: &Path, prefix: &str) -> Result<(), String> {
+    let mut backups: Vec<PathBuf> = fs::read_dir(output_dir)
+        .map_err(|e| format!("Failed to read backup folder: {}", e))?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .map(|n| n.to_string_lossy())
+                .is_some_and(|n| n.starts_with(prefix) && n.ends_with(".zip"))
+        })
+        .collect();
+
+    // Names embed a sortable timestamp, newest last
+    backups.sort();
+    let excess = backups.len().saturating_sub(BACKUPS_TO_KEEP);
+    for old in &backups[..excess] {
+        let _ = fs::remove_file(old);
+    }
+
+    Ok(())
+}
+
+fn run_scheduled_backup(vault: &Path, output_dir: &Path) -> Result<(), String> {
+    let vault_name = vault
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "vault".to_string());
+    let prefix = format!("{}-backup-", vault_name);
+    let timestamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let output_path = output_dir.join(format!("{}{}.zip", prefix, timestamp));
+
+    write_backup(vault, &output_path, false, Some(output_dir))?;
+    prune_backups(output_dir, &prefix)
+}
+
+/// Back the vault up to `output_dir` every `interval_hours`, keeping the last 7
+/// The first backup runs immediately; rescheduling replaces the previous timer
+#[command]
+pub async fn schedule_vault_backup(
+    state: State<'_, BackupScheduleState>,
+    vault_path: String,
+    output_dir: String,
+    interval_hours: u32,
+) -> Result<(), String> {
+    let vault = PathBuf::from(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+    if interval_hours == 0 {
+        return Err("Interval must be at least one hour".to_string());
+    }
+
+    let output_dir = PathBuf::from(output_dir);
+    let handle = tokio::spawn(async move {
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(interval_hours as u64 * 60 * 60));
+        loop {
+            interval.tick().await;
+            // Zipping a large vault blocks, keep it off the async workers
+            let (vault, output_dir) = (vault.clone(), output_dir.clone());
+            match tokio::task::spawn_blocking(move || run_scheduled_backup(&vault, &output_dir))
+                .await
+            {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => eprintln!("Scheduled backup failed: {}", e),
+                Err(e) => eprintln!("Scheduled backup task failed: {}", e),
+            }
+        }
+    });
+
+    let mut schedules = state.handles.lock().map_err(|e| e.to_string())?;
+    if let Some(previous) = schedules.insert(vault_path, handle) {
+        previous.abort();
+    }
+
+    Ok(())
+}
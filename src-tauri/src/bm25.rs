@@ -0,0 +1,114 @@
+use crate::vector_store::DocumentChunk;
+use rusqlite::{params, Connection};
+use std::path::Path;
+
+// ============================================================================
+// BM25 Keyword Index
+// ============================================================================
+//
+// An FTS5 table living in the same SQLite database as the vector store. It is
+// filled before embeddings are generated, so keyword search works straight
+// away and keeps working when no embedding provider is available.
+
+pub struct BM25Index {
+    conn: Connection,
+}
+
+impl BM25Index {
+    pub fn open(path: &Path) -> Result<Self, String> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+
+        let conn = Connection::open(path).map_err(|e| e.to_string())?;
+
+        conn.execute(
+            "CREATE VIRTUAL TABLE IF NOT EXISTS bm25_chunks USING fts5(id UNINDEXED, file_path UNINDEXED, content)",
+            [],
+        )
+        .map_err(|e| e.to_string())?;
+
+        Ok(Self { conn })
+    }
+
+    pub fn add_batch(&mut self, chunks: &[DocumentChunk]) -> Result<(), String> {
+        let tx = self.conn.transaction().map_err(|e| e.to_string())?;
+
+        {
+            let mut stmt = tx
+                .prepare("INSERT INTO bm25_chunks (id, file_path, content) VALUES (?1, ?2, ?3)")
+                .map_err(|e| e.to_string())?;
+
+            for chunk in chunks {
+                stmt.execute(params![chunk.id, chunk.file_path, chunk.content])
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+
+        tx.commit().map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// Rank chunks with FTS5's BM25, best match first
+    /// Scores are positive (higher is better); returned chunks have no vector
+    pub fn search(&self, query: &str, limit: usize) -> Result<Vec<(DocumentChunk, f32)>, String> {
+        let match_query = to_match_query(query);
+        if match_query.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT id, file_path, content, rank FROM bm25_chunks
+                 WHERE bm25_chunks MATCH ?1 ORDER BY rank LIMIT ?2",
+            )
+            .map_err(|e| e.to_string())?;
+
+        let rows = stmt
+            .query_map(params![match_query, limit as i64], |row| {
+                let rank: f64 = row.get(3)?;
+                Ok((
+                    DocumentChunk {
+                        id: row.get(0)?,
+                        file_path: row.get(1)?,
+                        content: row.get(2)?,
+                        vector: Vec::new(),
+                    },
+                    -rank as f32, // FTS5 ranks are negative, lower is better
+                ))
+            })
+            .map_err(|e| e.to_string())?;
+
+        rows.collect::<rusqlite::Result<Vec<_>>>()
+            .map_err(|e| e.to_string())
+    }
+
+    pub fn remove_file(&self, file_path: &str) -> Result<(), String> {
+        self.conn
+            .execute(
+                "DELETE FROM bm25_chunks WHERE file_path = ?1",
+                params![file_path],
+            )
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    pub fn clear(&self) -> Result<(), String> {
+        self.conn
+            .execute("DELETE FROM bm25_chunks", [])
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
+
+/// Turn free text into an FTS5 query matching any of its words
+/// Each term is quoted so punctuation can't be read as query syntax
+fn to_match_query(query: &str) -> String {
+    query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(|term| format!("\"{}\"", term))
+        .collect::<Vec<_>>()
+        .join(" OR ")
+}
@@ -0,0 +1,428 @@
+use regex::{Regex, RegexBuilder};
+use std::path::Path;
+use tauri::command;
+
+// ============================================================================
+// Web Clipper
+// ============================================================================
+//
+// Pages are reduced to their main content with a few readability-style
+// heuristics: scripts, navigation and other boilerplate are cut out of the
+// HTML before it is converted to Markdown with html2md.
+
+/// Some sites refuse requests that don't look like they come from a browser
+const BROWSER_USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/124.0 Safari/537.36";
+
+/// Length of the excerpt kept when the full content isn't requested
+const EXCERPT_CHARS: usize = 2000;
+
+/// Elements that never hold article content
+const NON_CONTENT_TAGS: [&str; 10] = [
+    "script", "style", "noscript", "iframe", "svg", "form", "nav", "footer", "header", "aside",
+];
+
+/// Class or id words that mark navigation, ads and other page furniture
+const BOILERPLATE_PATTERN: &str = r#"<([a-z][a-z0-9]*)\b[^>]*\b(?:class|id)\s*=\s*["'][^"']*\b(?:nav|navbar|menu|footer|sidebar|ad|ads|advert|advertisement|sponsored|promo|banner|cookie|consent|share|social|related|newsletter|subscribe|comments?|popup|modal|breadcrumbs?)\b[^"']*["'][^>]*>"#;
+
+fn case_insensitive(pattern: &str) -> Regex {
+    RegexBuilder::new(pattern)
+        .case_insensitive(true)
+        .dot_matches_new_line(true)
+        .build()
+        .unwrap()
+}
+
+/// Offset just past the closing tag of the element whose opening tag ends at
+/// `open_end`. Nested elements with the same name are skipped over.
+fn element_end(html: &str, tag: &str, open_end: usize) -> Option<usize> {
+    let tag_regex = case_insensitive(&format!(r"<(/?){}\b[^>]*>", regex::escape(tag)));
+    let mut depth = 1;
+
+    for caps in tag_regex.captures_iter(&html[open_end..]) {
+        let whole = caps.get(0).unwrap();
+        if &caps[1] == "/" {
+            depth -= 1;
+            if depth == 0 {
+                return Some(open_end + whole.end());
+            }
+        } else if !whole.as_str().ends_with("/>") {
+            depth += 1;
+        }
+    }
+
+    None
+}
+
+/// Remove every element whose opening tag matches `opening` (group 1 is the tag name)
+/// Elements named in `keep` are left alone
+fn remove_elements(html: &str, opening: &Regex, keep: &[&str]) -> String {
+    let mut result = html.to_string();
+    let mut search_from = 0;
+
+    while let Some(caps) = opening.captures(&result[search_from..]) {
+        let open = caps.get(0).unwrap();
+        let tag = caps[1].to_lowercase();
+        let start = search_from + open.start();
+        let open_end = search_from + open.end();
+
+        if keep.contains(&tag.as_str()) {
+            search_from = open_end;
+            continue;
+        }
+
+        // Unclosed and self-closing elements lose just their tag
+        let end = if open.as_str().ends_with("/>") {
+            open_end
+        } else {
+            element_end(&result, &tag, open_end).unwrap_or(open_end)
+        };
+        result.replace_range(start..end, "");
+        search_from = start;
+    }
+
+    result
+}
+
+/// Inner HTML of the first `<tag>` element, if the page has one
+fn element_inner<'a>(html: &'a str, tag: &str) -> Option<&'a str> {
+    let open = case_insensitive(&format!(r"<{}\b[^>]*>", regex::escape(tag))).find(html)?;
+    let end = element_end(html, tag, open.end())?;
+    let close_start = html[..end].rfind("</")?;
+    Some(&html[open.end()..close_start])
+}
+
+/// Decode the handful of entities common in page titles
+fn decode_entities(text: &str) -> String {
+    text.replace("&amp;", "&")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&nbsp;", " ")
+}
+
+/// Page title from `og:title`, falling back to `<title>`
+fn extract_page_title(html: &str) -> Option<String> {
+    let og_title = case_insensitive(
+        r#"<meta[^>]+property\s*=\s*["']og:title["'][^>]*content\s*=\s*["']([^"']*)["']"#,
+    );
+    let title = case_insensitive(r"<title[^>]*>(.*?)</title>");
+
+    og_title
+        .captures(html)
+        .or_else(|| title.captures(html))
+        .map(|caps| decode_entities(caps[1].trim()))
+        .map(|t| t.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|t| !t.is_empty())
+}
+
+/// Reduce a page to its main content, as HTML
+fn readable_html(html: &str) -> String {
+    let comments = case_insensitive(r"<!--.*?-->");
+    let mut cleaned = comments.replace_all(html, "").to_string();
+
+    for tag in NON_CONTENT_TAGS {
+        let opening = case_insensitive(&format!(r"<({})\b[^>]*>", tag));
+        cleaned = remove_elements(&cleaned, &opening, &[]);
+    }
+
+    // Prefer the page's own idea of where the content is
+    let content = element_inner(&cleaned, "article")
+        .or_else(|| element_inner(&cleaned, "main"))
+        .or_else(|| element_inner(&cleaned, "body"))
+        .unwrap_or(&cleaned)
+        .to_string();
+
+    remove_elements(
+        &content,
+        &case_insensitive(BOILERPLATE_PATTERN),
+        &["html", "body", "main", "article"],
+    )
+}
+
+/// Clip a web page into a new note with its source in the frontmatter
+///
+/// Only the first 2000 characters are kept unless `include_full_content` is
+/// set. Returns the relative path of the created note.
+#[command]
+pub async fn clip_url_to_note(
+    vault_path: String,
+    url: String,
+    target_folder: Option<String>,
+    include_full_content: bool,
+) -> Result<String, String> {
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let parsed_url = reqwest::Url::parse(&url).map_err(|e| format!("Invalid URL: {}", e))?;
+    if !matches!(parsed_url.scheme(), "http" | "https") {
+        return Err("Only http and https URLs can be clipped".to_string());
+    }
+
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(15))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let response = client
+        .get(parsed_url.clone())
+        .header("User-Agent", BROWSER_USER_AGENT)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch '{}': {}", url, e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Failed to fetch '{}': {}", url, response.status()));
+    }
+
+    let html = response
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read page: {}", e))?;
+
+    let title = extract_page_title(&html)
+        .or_else(|| parsed_url.host_str().map(str::to_string))
+        .unwrap_or_else(|| "Clipped page".to_string());
+
+    let blank_lines = Regex::new(r"\n{3,}").unwrap();
+    let markdown = html2md::parse_html(&readable_html(&html));
+    let mut markdown = blank_lines.replace_all(markdown.trim(), "\n\n").to_string();
+
+    if !include_full_content && markdown.chars().count() > EXCERPT_CHARS {
+        let excerpt: String = markdown.chars().take(EXCERPT_CHARS).collect();
+        markdown = format!("{}…\n\n[Read the full page]({})", excerpt.trim_end(), url);
+    }
+
+    let mut mapping = serde_yaml::Mapping::new();
+    mapping.insert("source".into(), url.clone().into());
+    mapping.insert(
+        "clipped_at".into(),
+        chrono::Local::now()
+            .to_rfc3339_opts(chrono::SecondsFormat::Secs, false)
+            .into(),
+    );
+    mapping.insert("title".into(), title.clone().into());
+
+    let content = crate::frontmatter::compose(&mapping, &format!("# {}\n\n{}\n", title, markdown))?;
+    let note_path =
+        crate::import::write_imported_note(vault, target_folder.as_deref(), &title, &content)?;
+
+    // Auto-commit if Git repository
+    if let Some(repo) = crate::git_manager::open_repository(vault) {
+        let _ = crate::git_manager::auto_commit_mosaic_changes(
+            &repo,
+            &format!("Clipped: {}", title),
+            &[&note_path],
+        ); // Silently fail if commit fails
+    }
+
+    Ok(note_path
+        .strip_prefix(vault)
+        .unwrap_or(&note_path)
+        .to_string_lossy()
+        .to_string())
+}
+
+// ============================================================================
+// YouTube Transcripts
+// ============================================================================
+
+const YOUTUBE_TIMEDTEXT_URL: &str = "https://www.youtube.com/api/timedtext";
+const YOUTUBE_OEMBED_URL: &str = "https://www.youtube.com/oembed";
+
+/// Silence between captions, in milliseconds, that starts a new paragraph
+const TRANSCRIPT_PAUSE_MS: i64 = 2000;
+
+#[derive(Debug, serde::Deserialize)]
+struct TimedText {
+    #[serde(default)]
+    events: Vec<TimedTextEvent>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TimedTextEvent {
+    #[serde(default)]
+    t_start_ms: i64,
+    #[serde(default)]
+    d_duration_ms: i64,
+    #[serde(default)]
+    segs: Vec<TimedTextSegment>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct TimedTextSegment {
+    #[serde(default)]
+    utf8: String,
+}
+
+/// Video id from a watch, short, embed or youtu.be URL (or a bare id)
+fn youtube_video_id(video_url: &str) -> Option<String> {
+    let is_id = |id: &str| {
+        id.len() == 11
+            && id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    };
+
+    let trimmed = video_url.trim();
+    if is_id(trimmed) {
+        return Some(trimmed.to_string());
+    }
+
+    let url = reqwest::Url::parse(trimmed).ok()?;
+    let host = url
+        .host_str()?
+        .trim_start_matches("www.")
+        .trim_start_matches("m.");
+    let id = match host {
+        "youtu.be" => url.path_segments()?.next()?.to_string(),
+        "youtube.com" | "music.youtube.com" | "youtube-nocookie.com" => {
+            match url.query_pairs().find(|(key, _)| key == "v") {
+                Some((_, id)) => id.to_string(),
+                None => {
+                    let mut segments = url.path_segments()?;
+                    match segments.next()? {
+                        "embed" | "shorts" | "live" | "v" => segments.next()?.to_string(),
+                        _ => return None,
+                    }
+                }
+            }
+        }
+        _ => return None,
+    };
+
+    is_id(&id).then_some(id)
+}
+
+/// Join caption segments into paragraphs, breaking wherever the speaker pauses
+fn transcript_text(timed_text: &TimedText) -> String {
+    let mut transcript = String::new();
+    let mut previous_end: Option<i64> = None;
+
+    for event in &timed_text.events {
+        let text: String = event.segs.iter().map(|seg| seg.utf8.as_str()).collect();
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if text.is_empty() {
+            continue;
+        }
+
+        if let Some(end) = previous_end {
+            if event.t_start_ms - end > TRANSCRIPT_PAUSE_MS {
+                transcript.push_str("\n\n");
+            } else {
+                transcript.push(' ');
+            }
+        }
+        transcript.push_str(&text);
+        previous_end = Some(event.t_start_ms + event.d_duration_ms);
+    }
+
+    transcript
+}
+
+/// Video title from YouTube's oEmbed endpoint
+async fn youtube_video_title(client: &reqwest::Client, watch_url: &str) -> Option<String> {
+    let data: serde_json::Value = client
+        .get(YOUTUBE_OEMBED_URL)
+        .query(&[("url", watch_url), ("format", "json")])
+        .send()
+        .await
+        .ok()?
+        .json()
+        .await
+        .ok()?;
+    data["title"]
+        .as_str()
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(str::to_string)
+}
+
+/// Save a YouTube video's English captions as a transcript note
+/// Returns the relative path of the created note
+#[command]
+pub async fn fetch_youtube_transcript(
+    vault_path: String,
+    video_url: String,
+    target_folder: Option<String>,
+) -> Result<String, String> {
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let video_id = youtube_video_id(&video_url)
+        .ok_or_else(|| format!("'{}' is not a YouTube video URL", video_url))?;
+    let watch_url = format!("https://www.youtube.com/watch?v={}", video_id);
+
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(15))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let response = client
+        .get(YOUTUBE_TIMEDTEXT_URL)
+        .header("User-Agent", BROWSER_USER_AGENT)
+        .query(&[("lang", "en"), ("v", &video_id), ("fmt", "json3")])
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch transcript: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Failed to fetch transcript: {}", response.status()));
+    }
+
+    // Videos without captions return an empty body
+    let body = response
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read transcript: {}", e))?;
+    if body.trim().is_empty() {
+        return Err("No captions are available for this video".to_string());
+    }
+    let timed_text: TimedText =
+        serde_json::from_str(&body).map_err(|e| format!("Failed to parse transcript: {}", e))?;
+
+    let transcript = transcript_text(&timed_text);
+    if transcript.is_empty() {
+        return Err("No captions are available for this video".to_string());
+    }
+
+    let title = youtube_video_title(&client, &watch_url)
+        .await
+        .unwrap_or_else(|| format!("YouTube {}", video_id));
+
+    let mut mapping = serde_yaml::Mapping::new();
+    mapping.insert("source".into(), watch_url.clone().into());
+    mapping.insert("video_id".into(), video_id.clone().into());
+    mapping.insert(
+        "tags".into(),
+        serde_yaml::Value::Sequence(vec!["youtube".into(), "transcript".into()]),
+    );
+
+    let content = crate::frontmatter::compose(
+        &mapping,
+        &format!("[Watch video]({})\n\n{}\n", watch_url, transcript),
+    )?;
+    let note_path =
+        crate::import::write_imported_note(vault, target_folder.as_deref(), &title, &content)?;
+
+    // Auto-commit if Git repository
+    if let Some(repo) = crate::git_manager::open_repository(vault) {
+        let _ = crate::git_manager::auto_commit_mosaic_changes(
+            &repo,
+            &format!("Imported YouTube transcript: {}", title),
+            &[&note_path],
+        ); // Silently fail if commit fails
+    }
+
+    Ok(note_path
+        .strip_prefix(vault)
+        .unwrap_or(&note_path)
+        .to_string_lossy()
+        .to_string())
+}
@@ -0,0 +1,211 @@
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Nonce};
+use rand::rngs::OsRng;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::command;
+
+// ============================================================================
+// Per-Note Encryption
+// ============================================================================
+
+const KEYS_FILE_NAME: &str = ".moss/encryption_keys.json";
+const ENCRYPTED_EXTENSION: &str = ".enc";
+const SALT_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+
+/// Salt and verification token for one encrypted note
+/// The derived key itself is never written to disk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncryptionKeyEntry {
+    pub salt: String,     // Hex encoded
+    pub verifier: String, // Hex encoded sha256 of the derived key
+}
+
+fn keys_path(vault: &Path) -> PathBuf {
+    vault.join(KEYS_FILE_NAME)
+}
+
+fn load_keys(path: &Path) -> Result<HashMap<String, EncryptionKeyEntry>, String> {
+    if !path.exists() {
+        return Ok(HashMap::new());
+    }
+
+    let content =
+        fs::read_to_string(path).map_err(|e| format!("Failed to read encryption keys: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse encryption keys: {}", e))
+}
+
+fn save_keys(path: &Path, keys: &HashMap<String, EncryptionKeyEntry>) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create .moss dir: {}", e))?;
+    }
+
+    let json = serde_json::to_string_pretty(keys)
+        .map_err(|e| format!("Failed to serialize encryption keys: {}", e))?;
+    fs::write(path, json).map_err(|e| format!("Failed to write encryption keys: {}", e))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Derive a 256-bit key from the password with Argon2id
+fn derive_key(password: &str, salt: &[u8]) -> Result<[u8; 32], String> {
+    let mut key = [0u8; 32];
+    argon2::Argon2::default()
+        .hash_password_into(password.as_bytes(), salt, &mut key)
+        .map_err(|e| format!("Failed to derive key: {}", e))?;
+    Ok(key)
+}
+
+fn verifier_for(key: &[u8]) -> String {
+    to_hex(&Sha256::digest(key))
+}
+
+fn relative_path(vault: &Path, path: &Path) -> String {
+    path.strip_prefix(vault)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .to_string()
+}
+
+/// Encrypt a note in place, replacing `note.md` with `note.md.enc`
+/// The file layout is `[salt(32)][nonce(12)][ciphertext]`
+#[command]
+pub async fn encrypt_note(
+    vault_path: String,
+    note_path: String,
+    password: String,
+) -> Result<(), String> {
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+    if password.is_empty() {
+        return Err("Password cannot be empty".to_string());
+    }
+    if note_path.ends_with(ENCRYPTED_EXTENSION) {
+        return Err(format!("Note '{}' is already encrypted", note_path));
+    }
+
+    let full_path = crate::tools::resolve_note_path(vault, &note_path)
+        .ok_or_else(|| format!("Note '{}' not found", note_path))?;
+    let encrypted_path = PathBuf::from(format!(
+        "{}{}",
+        full_path.to_string_lossy(),
+        ENCRYPTED_EXTENSION
+    ));
+    if encrypted_path.exists() {
+        return Err(format!(
+            "Encrypted file '{}' already exists",
+            relative_path(vault, &encrypted_path)
+        ));
+    }
+
+    let plaintext = fs::read(&full_path).map_err(|e| format!("Failed to read note: {}", e))?;
+
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut salt);
+    OsRng.fill_bytes(&mut nonce);
+
+    let key = derive_key(&password, &salt)?;
+    let cipher = Aes256Gcm::new_from_slice(&key).map_err(|e| e.to_string())?;
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce), plaintext.as_ref())
+        .map_err(|_| "Failed to encrypt note".to_string())?;
+
+    let mut output = Vec::with_capacity(SALT_LEN + NONCE_LEN + ciphertext.len());
+    output.extend_from_slice(&salt);
+    output.extend_from_slice(&nonce);
+    output.extend_from_slice(&ciphertext);
+    fs::write(&encrypted_path, output)
+        .map_err(|e| format!("Failed to write encrypted note: {}", e))?;
+    fs::remove_file(&full_path).map_err(|e| format!("Failed to remove plaintext note: {}", e))?;
+
+    let keys_file = keys_path(vault);
+    let mut keys = load_keys(&keys_file)?;
+    keys.insert(
+        relative_path(vault, &encrypted_path),
+        EncryptionKeyEntry {
+            salt: to_hex(&salt),
+            verifier: verifier_for(&key),
+        },
+    );
+    save_keys(&keys_file, &keys)?;
+
+    // Auto-commit the swap from plaintext to ciphertext
+    if let Some(repo) = crate::git_manager::open_repository(vault) {
+        let name = full_path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let _ = crate::git_manager::auto_commit_mosaic_changes(
+            &repo,
+            &format!("Encrypted {}", name),
+            &[encrypted_path.as_path(), full_path.as_path()],
+        ); // Silently fail if commit fails
+    }
+
+    Ok(())
+}
+
+/// Decrypt an encrypted note and return its content
+/// The file on disk stays encrypted
+#[command]
+pub async fn decrypt_note(
+    vault_path: String,
+    note_path: String,
+    password: String,
+) -> Result<String, String> {
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let note_path = if note_path.ends_with(ENCRYPTED_EXTENSION) {
+        note_path
+    } else if note_path.ends_with(".md") {
+        format!("{}{}", note_path, ENCRYPTED_EXTENSION)
+    } else {
+        format!("{}.md{}", note_path, ENCRYPTED_EXTENSION)
+    };
+    let path = Path::new(&note_path);
+    let full_path = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        vault.join(path)
+    };
+    if !full_path.exists() {
+        return Err(format!("Encrypted note '{}' not found", note_path));
+    }
+
+    let data = fs::read(&full_path).map_err(|e| format!("Failed to read note: {}", e))?;
+    if data.len() < SALT_LEN + NONCE_LEN {
+        return Err("Encrypted note is corrupted".to_string());
+    }
+    let (salt, rest) = data.split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+
+    let key = derive_key(&password, salt)?;
+
+    // Reject wrong passwords before attempting decryption
+    let keys = load_keys(&keys_path(vault))?;
+    if let Some(entry) = keys.get(&relative_path(vault, &full_path)) {
+        if entry.verifier != verifier_for(&key) {
+            return Err("Incorrect password".to_string());
+        }
+    }
+
+    let cipher = Aes256Gcm::new_from_slice(&key).map_err(|e| e.to_string())?;
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "Incorrect password or corrupted note".to_string())?;
+
+    String::from_utf8(plaintext).map_err(|e| format!("Decrypted note is not valid UTF-8: {}", e))
+}
@@ -0,0 +1,404 @@
+use pulldown_cmark::{html, Options, Parser};
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{Cursor, Write};
+use std::path::Path;
+use tauri::command;
+use zip::write::SimpleFileOptions;
+
+// ============================================================================
+// HTML Export
+// ============================================================================
+
+const EXPORT_CSS: &str = r#"
+body { max-width: 46rem; margin: 2rem auto; padding: 0 1rem; font-family: -apple-system, BlinkMacSystemFont, "Segoe UI", Helvetica, Arial, sans-serif; font-size: 16px; line-height: 1.65; color: #24292f; background: #fff; }
+h1, h2, h3, h4 { line-height: 1.25; margin-top: 1.8em; }
+h1 { border-bottom: 1px solid #d0d7de; padding-bottom: .3em; }
+a { color: #0969da; text-decoration: none; }
+a:hover { text-decoration: underline; }
+code { font-family: ui-monospace, SFMono-Regular, Menlo, Consolas, monospace; font-size: .9em; background: #f6f8fa; padding: .15em .35em; border-radius: 4px; }
+pre { background: #f6f8fa; padding: 1em; border-radius: 6px; overflow-x: auto; line-height: 1.45; }
+pre code { background: none; padding: 0; }
+blockquote { margin: 0; padding: 0 1em; color: #57606a; border-left: 4px solid #d0d7de; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #d0d7de; padding: .4em .8em; }
+img { max-width: 100%; }
+ul.index { list-style: none; padding: 0; }
+ul.index li { padding: .3em 0; border-bottom: 1px solid #eaeef2; }
+"#;
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Wrap rendered HTML in a standalone document with inline styles
+fn wrap_html(title: &str, body_html: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+        escape_html(title),
+        EXPORT_CSS,
+        body_html
+    )
+}
+
+/// Render a note's markdown to HTML
+/// Wikilinks become links when `resolve` finds a target, plain text otherwise
+fn render_markdown(content: &str, resolve: &dyn Fn(&str) -> Option<String>) -> String {
+    let wikilink_regex = Regex::new(r"!?\[\[([^|\]]+)(?:\|([^\]]+))?\]\]").unwrap();
+    let markdown = wikilink_regex.replace_all(
+        crate::frontmatter::strip(content),
+        |caps: &regex::Captures| {
+            let target = caps[1].trim();
+            let label = caps.get(2).map_or(target, |m| m.as_str().trim());
+            let note_name = target.split('#').next().unwrap_or(target).trim();
+            match resolve(note_name) {
+                Some(href) => format!("[{}]({})", label, href.replace(' ', "%20")),
+                None => label.to_string(),
+            }
+        },
+    );
+
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+    options.insert(Options::ENABLE_FOOTNOTES);
+
+    let mut output = String::new();
+    html::push_html(&mut output, Parser::new_ext(&markdown, options));
+    output
+}
+
+/// Export a single note as a standalone HTML document
+#[command]
+pub async fn export_note_html(vault_path: String, note_path: String) -> Result<String, String> {
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let full_path = crate::tools::resolve_note_path(vault, &note_path)
+        .ok_or_else(|| format!("Note '{}' not found", note_path))?;
+    let content = fs::read_to_string(&full_path)
+        .map_err(|e| format!("Failed to read note '{}': {}", note_path, e))?;
+
+    let title = full_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    Ok(wrap_html(&title, &render_markdown(&content, &|_| None)))
+}
+
+/// Export every note in a folder to a ZIP of HTML pages plus an `index.html`
+/// Wikilinks between exported notes become relative links
+#[command]
+pub async fn export_folder_html(
+    vault_path: String,
+    folder_path: String,
+    output_zip_path: String,
+) -> Result<String, String> {
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let folder = vault.join(&folder_path);
+    if !folder.is_dir() {
+        return Err(format!("Folder '{}' does not exist", folder_path));
+    }
+
+    let mut notes = Vec::new();
+    crate::tools::collect_notes(&folder, &mut notes, vault)?;
+    notes.retain(|note| !note.is_encrypted);
+    notes.sort_by(|a, b| a.path.cmp(&b.path));
+
+    // Page path inside the ZIP (relative to the folder, forward slashes)
+    let page_path = |note_path: &str| -> String {
+        let relative = vault
+            .join(note_path)
+            .strip_prefix(&folder)
+            .map(|p| p.with_extension("html"))
+            .unwrap_or_default();
+        relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().to_string())
+            .collect::<Vec<_>>()
+            .join("/")
+    };
+
+    // Wikilinks resolve by note title, case-insensitively
+    let pages_by_title: HashMap<String, String> = notes
+        .iter()
+        .map(|note| (note.title.to_lowercase(), page_path(&note.path)))
+        .collect();
+
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+    let mut index_items = Vec::new();
+
+    for note in &notes {
+        let content = match fs::read_to_string(vault.join(&note.path)) {
+            Ok(content) => content,
+            Err(_) => continue,
+        };
+
+        let page = page_path(&note.path);
+        let depth = page.matches('/').count();
+        let resolve = |name: &str| {
+            let name = name
+                .rsplit('/')
+                .next()
+                .unwrap_or(name)
+                .trim_end_matches(".md");
+            pages_by_title
+                .get(&name.to_lowercase())
+                .map(|target| format!("{}{}", "../".repeat(depth), target))
+        };
+
+        let html = wrap_html(&note.title, &render_markdown(&content, &resolve));
+        writer
+            .start_file(page.as_str(), options)
+            .and_then(|_| writer.write_all(html.as_bytes()).map_err(Into::into))
+            .map_err(|e| format!("Failed to add '{}': {}", page, e))?;
+
+        index_items.push(format!(
+            "<li><a href=\"{}\">{}</a></li>",
+            escape_html(&page.replace(' ', "%20")),
+            escape_html(&note.title)
+        ));
+    }
+
+    let folder_title = folder
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "Notes".to_string());
+    let index = wrap_html(
+        &folder_title,
+        &format!(
+            "<h1>{}</h1>\n<ul class=\"index\">\n{}\n</ul>\n",
+            escape_html(&folder_title),
+            index_items.join("\n")
+        ),
+    );
+    writer
+        .start_file("index.html", options)
+        .and_then(|_| writer.write_all(index.as_bytes()).map_err(Into::into))
+        .map_err(|e| format!("Failed to add index: {}", e))?;
+
+    let buffer = writer
+        .finish()
+        .map_err(|e| format!("Failed to finish archive: {}", e))?
+        .into_inner();
+    fs::write(&output_zip_path, buffer).map_err(|e| format!("Failed to write export: {}", e))?;
+
+    Ok(output_zip_path)
+}
+
+// ============================================================================
+// EPUB Export
+// ============================================================================
+
+/// Media type for an image bundled into an EPUB
+fn epub_image_media_type(path: &Path) -> Option<&'static str> {
+    let ext = path.extension()?.to_string_lossy().to_lowercase();
+    match ext.as_str() {
+        "png" => Some("image/png"),
+        "jpg" | "jpeg" => Some("image/jpeg"),
+        "gif" => Some("image/gif"),
+        "svg" => Some("image/svg+xml"),
+        "webp" => Some("image/webp"),
+        _ => None,
+    }
+}
+
+fn xhtml_page(title: &str, body: &str) -> String {
+    format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<!DOCTYPE html>\n<html xmlns=\"http://www.w3.org/1999/xhtml\" xmlns:epub=\"http://www.idpf.org/2007/ops\">\n<head>\n<title>{}</title>\n</head>\n<body>\n{}</body>\n</html>\n",
+        escape_html(title),
+        body
+    )
+}
+
+/// Compile notes into an EPUB 3 book, one chapter per note in the given order
+/// Local images referenced by the notes are bundled into the book
+#[command]
+pub async fn export_epub(
+    vault_path: String,
+    note_paths: Vec<String>,
+    title: String,
+    author: String,
+    output_path: String,
+) -> Result<(), String> {
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+    if note_paths.is_empty() {
+        return Err("Select at least one note to export".to_string());
+    }
+
+    let src_regex = Regex::new(r#"src="([^"]+)""#).unwrap();
+
+    // (chapter file, chapter title, xhtml)
+    let mut chapters: Vec<(String, String, String)> = Vec::new();
+    // Zip path inside OEBPS -> (source file, media type)
+    let mut images: Vec<(String, std::path::PathBuf, &'static str)> = Vec::new();
+
+    for (index, note_path) in note_paths.iter().enumerate() {
+        let full_path = crate::tools::resolve_note_path(vault, note_path)
+            .ok_or_else(|| format!("Note '{}' not found", note_path))?;
+        let content = fs::read_to_string(&full_path)
+            .map_err(|e| format!("Failed to read note '{}': {}", note_path, e))?;
+        let chapter_title = full_path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let note_dir = full_path.parent().unwrap_or(vault).to_path_buf();
+
+        let body = render_markdown(&content, &|_| None);
+
+        // Point local images at copies bundled in the book
+        let body = src_regex.replace_all(&body, |caps: &regex::Captures| {
+            let src = &caps[1];
+            if src.contains("://") {
+                return caps[0].to_string();
+            }
+
+            let decoded = src.replace("%20", " ");
+            let source = [note_dir.join(&decoded), vault.join(&decoded)]
+                .into_iter()
+                .find(|candidate| candidate.is_file());
+            let Some(source) = source else {
+                return caps[0].to_string();
+            };
+            let Some(media_type) = epub_image_media_type(&source) else {
+                return caps[0].to_string();
+            };
+
+            let existing = images.iter().find(|(_, path, _)| *path == source);
+            let zip_name = match existing {
+                Some((name, _, _)) => name.clone(),
+                None => {
+                    let file_name = source
+                        .file_name()
+                        .map(|n| n.to_string_lossy().replace(' ', "_"))
+                        .unwrap_or_default();
+                    let name = format!("images/{}-{}", images.len() + 1, file_name);
+                    images.push((name.clone(), source, media_type));
+                    name
+                }
+            };
+            format!("src=\"{}\"", zip_name)
+        });
+
+        chapters.push((
+            format!("chapter-{}.xhtml", index + 1),
+            chapter_title.clone(),
+            xhtml_page(&chapter_title, &body),
+        ));
+    }
+
+    let book_id = format!("urn:uuid:{}", uuid::Uuid::new_v4());
+    let modified = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ");
+
+    let container = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<container version=\"1.0\" xmlns=\"urn:oasis:names:tc:opendocument:xmlns:container\">\n<rootfiles>\n<rootfile full-path=\"OEBPS/content.opf\" media-type=\"application/oebps-package+xml\"/>\n</rootfiles>\n</container>\n";
+
+    let mut manifest = vec![
+        "<item id=\"nav\" href=\"nav.xhtml\" media-type=\"application/xhtml+xml\" properties=\"nav\"/>".to_string(),
+        "<item id=\"ncx\" href=\"toc.ncx\" media-type=\"application/x-dtbncx+xml\"/>".to_string(),
+    ];
+    let mut spine = Vec::new();
+    let mut nav_items = Vec::new();
+    let mut nav_points = Vec::new();
+
+    for (index, (file, chapter_title, _)) in chapters.iter().enumerate() {
+        let id = format!("chapter-{}", index + 1);
+        manifest.push(format!(
+            "<item id=\"{}\" href=\"{}\" media-type=\"application/xhtml+xml\"/>",
+            id, file
+        ));
+        spine.push(format!("<itemref idref=\"{}\"/>", id));
+        nav_items.push(format!(
+            "<li><a href=\"{}\">{}</a></li>",
+            file,
+            escape_html(chapter_title)
+        ));
+        nav_points.push(format!(
+            "<navPoint id=\"nav-{0}\" playOrder=\"{0}\">\n<navLabel><text>{1}</text></navLabel>\n<content src=\"{2}\"/>\n</navPoint>",
+            index + 1,
+            escape_html(chapter_title),
+            file
+        ));
+    }
+    for (index, (name, _, media_type)) in images.iter().enumerate() {
+        manifest.push(format!(
+            "<item id=\"image-{}\" href=\"{}\" media-type=\"{}\"/>",
+            index + 1,
+            name,
+            media_type
+        ));
+    }
+
+    let opf = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<package xmlns=\"http://www.idpf.org/2007/opf\" version=\"3.0\" unique-identifier=\"book-id\">\n<metadata xmlns:dc=\"http://purl.org/dc/elements/1.1/\">\n<dc:identifier id=\"book-id\">{}</dc:identifier>\n<dc:title>{}</dc:title>\n<dc:creator>{}</dc:creator>\n<dc:language>en</dc:language>\n<meta property=\"dcterms:modified\">{}</meta>\n</metadata>\n<manifest>\n{}\n</manifest>\n<spine toc=\"ncx\">\n{}\n</spine>\n</package>\n",
+        book_id,
+        escape_html(&title),
+        escape_html(&author),
+        modified,
+        manifest.join("\n"),
+        spine.join("\n")
+    );
+
+    let ncx = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<ncx xmlns=\"http://www.daisy.org/z3986/2005/ncx/\" version=\"2005-1\">\n<head>\n<meta name=\"dtb:uid\" content=\"{}\"/>\n</head>\n<docTitle><text>{}</text></docTitle>\n<navMap>\n{}\n</navMap>\n</ncx>\n",
+        book_id,
+        escape_html(&title),
+        nav_points.join("\n")
+    );
+
+    let nav = xhtml_page(
+        &title,
+        &format!(
+            "<nav epub:type=\"toc\" id=\"toc\">\n<h1>{}</h1>\n<ol>\n{}\n</ol>\n</nav>\n",
+            escape_html(&title),
+            nav_items.join("\n")
+        ),
+    );
+
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let deflated =
+        SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+    let stored = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+
+    let mut add_file = |name: &str, data: &[u8], options: SimpleFileOptions| {
+        writer
+            .start_file(name, options)
+            .and_then(|_| writer.write_all(data).map_err(Into::into))
+            .map_err(|e| format!("Failed to add '{}': {}", name, e))
+    };
+
+    // The mimetype entry must come first and be uncompressed
+    add_file("mimetype", b"application/epub+zip", stored)?;
+    add_file("META-INF/container.xml", container.as_bytes(), deflated)?;
+    add_file("OEBPS/content.opf", opf.as_bytes(), deflated)?;
+    add_file("OEBPS/toc.ncx", ncx.as_bytes(), deflated)?;
+    add_file("OEBPS/nav.xhtml", nav.as_bytes(), deflated)?;
+    for (file, _, xhtml) in &chapters {
+        add_file(&format!("OEBPS/{}", file), xhtml.as_bytes(), deflated)?;
+    }
+    for (name, source, _) in &images {
+        let data = fs::read(source)
+            .map_err(|e| format!("Failed to read image '{}': {}", source.display(), e))?;
+        add_file(&format!("OEBPS/{}", name), &data, deflated)?;
+    }
+
+    let buffer = writer
+        .finish()
+        .map_err(|e| format!("Failed to finish EPUB: {}", e))?
+        .into_inner();
+    fs::write(&output_path, buffer).map_err(|e| format!("Failed to write EPUB: {}", e))
+}
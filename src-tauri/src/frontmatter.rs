@@ -0,0 +1,101 @@
+use serde_yaml::{Mapping, Value};
+
+// ============================================================================
+// YAML Frontmatter Helpers
+// ============================================================================
+//
+// Frontmatter is the block between a leading `---` line and the next `---`
+// (or `...`) line. Everything after the closing delimiter is the note body.
+
+/// Split content into (frontmatter YAML, body)
+/// Returns `None` for the YAML part if the note has no frontmatter block
+pub fn split(content: &str) -> (Option<&str>, &str) {
+    let first_line_end = match content.find('\n') {
+        Some(pos) => pos,
+        None => return (None, content),
+    };
+
+    if content[..first_line_end].trim_end() != "---" {
+        return (None, content);
+    }
+
+    let yaml_start = first_line_end + 1;
+    let mut offset = yaml_start;
+
+    for line in content[yaml_start..].split_inclusive('\n') {
+        let trimmed = line.trim_end();
+        if trimmed == "---" || trimmed == "..." {
+            let body_start = offset + line.len();
+            return (Some(&content[yaml_start..offset]), &content[body_start..]);
+        }
+        offset += line.len();
+    }
+
+    // Unterminated block, treat the whole thing as body
+    (None, content)
+}
+
+/// Strip the frontmatter block (if any) and return the body
+pub fn strip(content: &str) -> &str {
+    split(content).1
+}
+
+/// Parse the frontmatter block into a YAML mapping
+/// Notes without frontmatter yield an empty mapping
+pub fn parse(content: &str) -> Result<Mapping, String> {
+    match split(content).0 {
+        Some(yaml) if !yaml.trim().is_empty() => match serde_yaml::from_str::<Value>(yaml) {
+            Ok(Value::Mapping(mapping)) => Ok(mapping),
+            Ok(Value::Null) => Ok(Mapping::new()),
+            Ok(_) => Err("Frontmatter is not a key/value mapping".to_string()),
+            Err(e) => Err(format!("Invalid frontmatter: {}", e)),
+        },
+        _ => Ok(Mapping::new()),
+    }
+}
+
+/// Rebuild note content from a frontmatter mapping and a body
+/// An empty mapping drops the frontmatter block entirely
+pub fn compose(mapping: &Mapping, body: &str) -> Result<String, String> {
+    if mapping.is_empty() {
+        return Ok(body.to_string());
+    }
+
+    let yaml = serde_yaml::to_string(mapping)
+        .map_err(|e| format!("Failed to serialize frontmatter: {}", e))?;
+
+    Ok(format!("---\n{}---\n{}", yaml, body))
+}
+
+/// Read the `tags` key as a list of strings
+/// Supports both `tags: [a, b]` / block lists and `tags: a, b` strings
+pub fn tags(mapping: &Mapping) -> Vec<String> {
+    match mapping.get("tags") {
+        Some(Value::Sequence(items)) => items
+            .iter()
+            .filter_map(|item| match item {
+                Value::String(s) => Some(s.clone()),
+                Value::Number(n) => Some(n.to_string()),
+                _ => None,
+            })
+            .map(|tag| tag.trim().trim_start_matches('#').to_string())
+            .filter(|tag| !tag.is_empty())
+            .collect(),
+        Some(Value::String(s)) => s
+            .split([',', ' '])
+            .map(|tag| tag.trim().trim_start_matches('#').to_string())
+            .filter(|tag| !tag.is_empty())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Overwrite the `tags` key (removing it when the list is empty)
+pub fn set_tags(mapping: &mut Mapping, tags: &[String]) {
+    if tags.is_empty() {
+        mapping.remove("tags");
+    } else {
+        let values = tags.iter().map(|tag| Value::String(tag.clone())).collect();
+        mapping.insert(Value::String("tags".to_string()), Value::Sequence(values));
+    }
+}
@@ -1,6 +1,7 @@
 use regex::Regex;
+use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tauri::command;
 
 #[command]
@@ -21,41 +22,60 @@ pub async fn rename_note(
         return Err(format!("Destination file '{}' already exists", new_path));
     }
 
+    rename_note_and_links(vault_p, old_p, new_p)?;
+
+    Ok(())
+}
+
+/// Rename a note and rewrite wikilinks pointing at it
+/// Returns the notes whose links were updated
+pub(crate) fn rename_note_and_links(
+    vault_p: &Path,
+    old_p: &Path,
+    new_p: &Path,
+) -> Result<Vec<PathBuf>, String> {
     // 1. Rename the file itself
     fs::rename(old_p, new_p).map_err(|e| format!("Failed to rename file: {}", e))?;
 
     // 2. Calculate relative paths and filenames
     let old_rel_path = old_p
         .strip_prefix(vault_p)
-        .map_err(|_| "Failed to calculate relative path")?
-        .to_string_lossy()
-        .to_string();
+        .map_err(|_| "Failed to calculate relative path")?;
 
     let new_rel_path = new_p
         .strip_prefix(vault_p)
-        .map_err(|_| "Failed to calculate relative path")?
-        .to_string_lossy()
-        .to_string();
+        .map_err(|_| "Failed to calculate relative path")?;
 
-    // Remove .md extension for linking
-    let old_link_path = old_rel_path.trim_end_matches(".md");
-    let new_link_path = new_rel_path.trim_end_matches(".md");
-
-    let old_name = old_p.file_stem().and_then(|s| s.to_str()).unwrap_or("");
-    let new_name = new_p.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+    // Remove .md (or .md.enc) extension for linking
+    let old_name = note_link_name(old_p);
+    let new_name = note_link_name(new_p);
+    let old_link_path = old_rel_path.with_file_name(&old_name);
+    let new_link_path = new_rel_path.with_file_name(&new_name);
+    crate::pins::repoint(vault_p, old_rel_path, new_rel_path);
 
     // 3. Update links in all other files
-    update_links_in_vault(vault_p, old_name, new_name, old_link_path, new_link_path)?;
-
-    Ok(())
+    let mut updated = Vec::new();
+    update_links_in_vault(
+        vault_p,
+        &old_name,
+        &new_name,
+        &old_link_path.to_string_lossy(),
+        &new_link_path.to_string_lossy(),
+        &mut updated,
+    )?;
+
+    Ok(updated)
 }
 
+/// Rewrite wikilinks to a renamed note in every note under `dir`
+/// Notes whose content changed are added to `updated`
 fn update_links_in_vault(
     dir: &Path,
     old_name: &str,
     new_name: &str,
     old_link_path: &str,
     new_link_path: &str,
+    updated: &mut Vec<PathBuf>,
 ) -> Result<(), String> {
     let entries = fs::read_dir(dir).map_err(|e| format!("Failed to read dir: {}", e))?;
 
@@ -73,10 +93,19 @@ fn update_links_in_vault(
             {
                 continue;
             }
-            update_links_in_vault(&path, old_name, new_name, old_link_path, new_link_path)?;
+            update_links_in_vault(
+                &path,
+                old_name,
+                new_name,
+                old_link_path,
+                new_link_path,
+                updated,
+            )?;
         } else if path.is_file() {
-            if path.extension().and_then(|s| s.to_str()) == Some("md") {
-                process_file(&path, old_name, new_name, old_link_path, new_link_path)?;
+            if path.extension().and_then(|s| s.to_str()) == Some("md")
+                && process_file(&path, old_name, new_name, old_link_path, new_link_path)?
+            {
+                updated.push(path);
             }
         }
     }
@@ -90,7 +119,7 @@ fn process_file(
     new_name: &str,
     old_link_path: &str,
     new_link_path: &str,
-) -> Result<(), String> {
+) -> Result<bool, String> {
     let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
     let mut new_content = content.clone();
     let mut changed = false;
@@ -138,7 +167,7 @@ fn process_file(
         fs::write(path, new_content).map_err(|e| e.to_string())?;
     }
 
-    Ok(())
+    Ok(changed)
 }
 
 #[command]
@@ -152,38 +181,54 @@ pub async fn save_image(
     vault_path: String,
     file_name: String,
     image_data: Vec<u8>,
+) -> Result<String, String> {
+    save_attachment(vault_path, "assets".to_string(), file_name, image_data).await
+}
+
+// ============================================================================
+// Attachments
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttachmentInfo {
+    pub path: String, // Relative path
+    pub size: u64,
+    pub mime_type: String,
+    pub referenced_by: Vec<String>, // Relative paths of notes mentioning the file
+}
+
+/// Save any binary file into a vault folder (created if missing)
+/// Returns the vault-relative path to use in a Markdown link
+#[command]
+pub async fn save_attachment(
+    vault_path: String,
+    relative_dest_folder: String,
+    file_name: String,
+    data: Vec<u8>,
 ) -> Result<String, String> {
     let vault_p = Path::new(&vault_path);
-    println!(
-        "DEBUG: save_image called. Vault: {}, File: {}, Data size: {}",
-        vault_path,
-        file_name,
-        image_data.len()
-    );
 
     if !vault_p.exists() {
-        println!("DEBUG: Vault path does not exist!");
         return Err("Vault path does not exist".to_string());
     }
 
-    // 1. Ensure assets directory exists
-    let assets_dir = vault_p.join("assets");
-    if !assets_dir.exists() {
-        println!("DEBUG: Creating assets directory at {:?}", assets_dir);
-        fs::create_dir(&assets_dir)
-            .map_err(|e| format!("Failed to create assets directory: {}", e))?;
+    // 1. Ensure destination directory exists
+    let relative_dest_folder = relative_dest_folder.trim_matches('/').to_string();
+    if relative_dest_folder.split('/').any(|part| part == "..") {
+        return Err("Destination folder must be inside the vault".to_string());
     }
 
-    // 2. Handle filename collisions
-    let mut safe_name = file_name.clone();
-    let mut file_path = assets_dir.join(&safe_name);
+    let dest_dir = vault_p.join(&relative_dest_folder);
+    if !dest_dir.exists() {
+        fs::create_dir_all(&dest_dir)
+            .map_err(|e| format!("Failed to create attachment directory: {}", e))?;
+    }
 
-    // Simple sanitization
-    safe_name = safe_name
-        .replace(" ", "_")
-        .replace("/", "_")
-        .replace("\\", "_");
+    // 2. Simple sanitization
+    let mut safe_name = file_name.replace([' ', '/', '\\'], "_");
+    let mut file_path = dest_dir.join(&safe_name);
 
+    // 3. Handle filename collisions
     if file_path.exists() {
         // Append timestamp if file exists
         let timestamp = std::time::SystemTime::now()
@@ -195,23 +240,551 @@ pub async fn save_image(
         let stem = path_obj
             .file_stem()
             .and_then(|s| s.to_str())
-            .unwrap_or("image");
-        let ext = path_obj
-            .extension()
-            .and_then(|s| s.to_str())
-            .unwrap_or("png");
+            .unwrap_or("attachment");
+
+        safe_name = match path_obj.extension().and_then(|s| s.to_str()) {
+            Some(ext) => format!("{}_{}.{}", stem, timestamp, ext),
+            None => format!("{}_{}", stem, timestamp),
+        };
+        file_path = dest_dir.join(&safe_name);
+    }
+
+    // 4. Write file
+    fs::write(&file_path, data).map_err(|e| format!("Failed to write attachment: {}", e))?;
+
+    // 5. Return relative path for Markdown link
+    if relative_dest_folder.is_empty() {
+        Ok(safe_name)
+    } else {
+        Ok(format!("{}/{}", relative_dest_folder, safe_name))
+    }
+}
+
+/// Guess a MIME type from a file extension
+fn mime_type_for(path: &Path) -> String {
+    let ext = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+
+    match ext.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "bmp" => "image/bmp",
+        "pdf" => "application/pdf",
+        "zip" => "application/zip",
+        "json" => "application/json",
+        "txt" => "text/plain",
+        "csv" => "text/csv",
+        "html" | "htm" => "text/html",
+        "mp3" => "audio/mpeg",
+        "wav" => "audio/wav",
+        "ogg" => "audio/ogg",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        "mov" => "video/quicktime",
+        "doc" => "application/msword",
+        "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        "pptx" => "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        _ => "application/octet-stream",
+    }
+    .to_string()
+}
+
+/// Walk the vault (respecting .gitignore and skipping hidden files) and
+/// split files into notes and attachments
+pub(crate) fn collect_vault_files(vault: &Path) -> (Vec<PathBuf>, Vec<PathBuf>) {
+    let mut notes = Vec::new();
+    let mut attachments = Vec::new();
+
+    let walker = ignore::WalkBuilder::new(vault)
+        .hidden(true)
+        .git_ignore(true)
+        .require_git(false)
+        .build();
+
+    for entry in walker.flatten() {
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+
+        // Encrypted notes are still notes, even though their content is opaque
+        if path.extension().is_some_and(|ext| ext == "md")
+            || path.to_string_lossy().ends_with(".md.enc")
+        {
+            notes.push(path.to_path_buf());
+        } else {
+            attachments.push(path.to_path_buf());
+        }
+    }
+
+    (notes, attachments)
+}
+
+/// List every non-markdown file in the vault with the notes that reference it
+#[command]
+pub async fn list_attachments(vault_path: String) -> Result<Vec<AttachmentInfo>, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let (note_paths, attachment_paths) = collect_vault_files(vault);
+
+    let relative = |path: &Path| {
+        path.strip_prefix(vault)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .to_string()
+    };
+
+    let notes: Vec<(String, String)> = note_paths
+        .iter()
+        .filter_map(|path| {
+            fs::read_to_string(path)
+                .ok()
+                .map(|content| (relative(path), content))
+        })
+        .collect();
+
+    let mut attachments = Vec::new();
+    for path in attachment_paths {
+        let file_name = match path.file_name() {
+            Some(name) => name.to_string_lossy().to_string(),
+            None => continue,
+        };
+        // Links may also use the URL-encoded form of the name
+        let encoded_name = file_name.replace(' ', "%20");
+
+        let mut referenced_by: Vec<String> = notes
+            .iter()
+            .filter(|(_, content)| content.contains(&file_name) || content.contains(&encoded_name))
+            .map(|(note_path, _)| note_path.clone())
+            .collect();
+        referenced_by.sort();
+
+        attachments.push(AttachmentInfo {
+            path: relative(&path),
+            size: fs::metadata(&path).map(|m| m.len()).unwrap_or(0),
+            mime_type: mime_type_for(&path),
+            referenced_by,
+        });
+    }
+
+    attachments.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Ok(attachments)
+}
+
+/// List attachments that no note references
+#[command]
+pub async fn find_orphaned_attachments(vault_path: String) -> Result<Vec<AttachmentInfo>, String> {
+    let attachments = list_attachments(vault_path).await?;
+    Ok(attachments
+        .into_iter()
+        .filter(|attachment| attachment.referenced_by.is_empty())
+        .collect())
+}
+
+/// Name a note is linked by: its file name without `.md` or `.md.enc`
+fn note_link_name(path: &Path) -> String {
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    file_name
+        .strip_suffix(".md.enc")
+        .or_else(|| file_name.strip_suffix(".md"))
+        .unwrap_or(&file_name)
+        .to_string()
+}
+
+// ============================================================================
+// Archival
+// ============================================================================
+
+/// Notes with more backlinks than this are still in use and never archived
+const MAX_ARCHIVABLE_BACKLINKS: usize = 3;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ArchiveReport {
+    pub archived: Vec<String>, // Relative paths before the move
+    pub skipped_pinned: Vec<String>,
+    pub skipped_linked: Vec<String>,
+    pub skipped_collisions: Vec<String>, // A file with the same name is already archived
+    pub total_moved: usize,              // Always 0 for dry runs
+}
+
+/// Move notes untouched for `inactive_days` into `archive_folder`
+/// Pinned and frequently linked notes stay put; wikilinks to moved notes are updated
+#[command]
+pub async fn archive_inactive_notes(
+    vault_path: String,
+    inactive_days: usize,
+    archive_folder: String,
+    dry_run: bool,
+) -> Result<ArchiveReport, String> {
+    use std::collections::HashMap;
+
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let archive_folder = archive_folder.trim().trim_matches('/').to_string();
+    let archive_relative = Path::new(&archive_folder);
+    if archive_folder.is_empty()
+        || archive_relative.is_absolute()
+        || archive_relative
+            .components()
+            .any(|c| matches!(c, std::path::Component::ParentDir))
+    {
+        return Err("Archive folder must be inside the vault".to_string());
+    }
+    let archive_dir = vault.join(archive_relative);
+
+    let cutoff = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_err(|e| format!("Time error: {}", e))?
+        .as_secs()
+        .saturating_sub(inactive_days as u64 * 24 * 60 * 60);
+
+    let mut notes = Vec::new();
+    crate::tools::collect_notes(vault, &mut notes, vault)?;
+    notes
+        .retain(|note| note.modified < cutoff && !vault.join(&note.path).starts_with(&archive_dir));
+    notes.sort_by(|a, b| a.path.cmp(&b.path));
+
+    // Distinct linking notes per target, keyed by absolute path like the graph
+    let graph_data = crate::graph::get_graph_data_with_cache(vault, None)?;
+    let mut backlinks: HashMap<&str, std::collections::HashSet<&str>> = HashMap::new();
+    for link in &graph_data.links {
+        if link.source != link.target {
+            backlinks
+                .entry(link.target.as_str())
+                .or_default()
+                .insert(link.source.as_str());
+        }
+    }
+
+    let pinned = crate::pins::pinned_set(vault);
+    let mut report = ArchiveReport::default();
+    let mut to_move = Vec::new();
+    let mut targets = std::collections::HashSet::new();
+    for note in notes {
+        let absolute = vault.join(&note.path);
+        let backlink_count = backlinks
+            .get(absolute.to_string_lossy().as_ref())
+            .map_or(0, |sources| sources.len());
+
+        // Notes keep their full file name (including `.md.enc`); renaming one
+        // to dodge a collision would repoint every link to its name
+        let Some(file_name) = absolute.file_name() else {
+            continue;
+        };
+        let new_path = archive_dir.join(file_name);
+
+        if pinned.contains(&note.path) {
+            report.skipped_pinned.push(note.path);
+        } else if backlink_count > MAX_ARCHIVABLE_BACKLINKS {
+            report.skipped_linked.push(note.path);
+        } else if new_path.exists() || !targets.insert(new_path.clone()) {
+            report.skipped_collisions.push(note.path);
+        } else {
+            report.archived.push(note.path.clone());
+            to_move.push((absolute, new_path));
+        }
+    }
+
+    if dry_run || to_move.is_empty() {
+        return Ok(report);
+    }
+
+    fs::create_dir_all(&archive_dir)
+        .map_err(|e| format!("Failed to create archive folder: {}", e))?;
+
+    let mut changed_files: Vec<PathBuf> = Vec::new();
+    for (old_path, new_path) in to_move {
+        fs::rename(&old_path, &new_path).map_err(|e| format!("Failed to move note: {}", e))?;
+
+        // The name is unchanged, so only path-qualified links need updating
+        let name = note_link_name(&old_path);
+        let old_relative = old_path.strip_prefix(vault).unwrap_or(&old_path);
+        let new_relative = new_path.strip_prefix(vault).unwrap_or(&new_path);
+        let old_link_path = old_relative.with_file_name(&name);
+        let new_link_path = new_relative.with_file_name(&name);
+        crate::pins::repoint(vault, old_relative, new_relative);
+
+        update_links_in_vault(
+            vault,
+            &name,
+            &name,
+            &old_link_path.to_string_lossy(),
+            &new_link_path.to_string_lossy(),
+            &mut changed_files,
+        )?;
+
+        changed_files.push(old_path);
+        changed_files.push(new_path);
+        report.total_moved += 1;
+    }
+
+    // Auto-commit if Git repository
+    if let Some(repo) = crate::git_manager::open_repository(vault) {
+        changed_files.sort();
+        changed_files.dedup();
+        let file_refs: Vec<&Path> = changed_files.iter().map(|p| p.as_path()).collect();
+        let _ = crate::git_manager::auto_commit_mosaic_changes(
+            &repo,
+            &format!(
+                "Archived {} inactive note{}",
+                report.total_moved,
+                if report.total_moved == 1 { "" } else { "s" }
+            ),
+            &file_refs,
+        ); // Silently fail if commit fails
+    }
+
+    Ok(report)
+}
+
+// ============================================================================
+// Bulk Rename
+// ============================================================================
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BulkRenameReport {
+    pub renamed: Vec<(String, String)>, // (old, new) relative paths
+    pub skipped_collisions: Vec<String>,
+    pub errors: Vec<String>, // "path: error"
+}
+
+/// Rename every note whose file name matches a regex
+/// `pattern` is matched against the file stem and `replacement` may use `$1`-style
+/// capture groups; wikilinks to renamed notes are updated
+#[command]
+pub async fn bulk_rename_notes(
+    vault_path: String,
+    pattern: String,
+    replacement: String,
+    folder: Option<String>,
+    dry_run: bool,
+) -> Result<BulkRenameReport, String> {
+    use std::collections::HashSet;
+
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
 
-        safe_name = format!("{}_{}.{}", stem, timestamp, ext);
-        file_path = assets_dir.join(&safe_name);
+    let regex = Regex::new(&pattern).map_err(|e| format!("Invalid pattern: {}", e))?;
+
+    let root = match folder.as_deref().filter(|f| !f.trim().is_empty()) {
+        Some(folder) => vault.join(folder),
+        None => vault.to_path_buf(),
+    };
+    if !root.is_dir() {
+        return Err(format!(
+            "Folder '{}' does not exist",
+            folder.unwrap_or_default()
+        ));
     }
 
-    println!("DEBUG: Writing to file: {:?}", file_path);
+    let mut notes = Vec::new();
+    crate::tools::collect_notes(&root, &mut notes, vault)?;
+    notes.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let mut report = BulkRenameReport::default();
+    let mut planned = Vec::new();
+    let mut targets = HashSet::new();
+    for note in notes {
+        let old_path = vault.join(&note.path);
+        let stem = note_link_name(&old_path);
+        if !regex.is_match(&stem) {
+            continue;
+        }
+
+        let new_stem = regex
+            .replace_all(&stem, replacement.as_str())
+            .trim()
+            .to_string();
+        if new_stem == stem {
+            continue;
+        }
+        if new_stem.is_empty() || new_stem.contains(['/', '\\']) {
+            report
+                .errors
+                .push(format!("{}: invalid new name '{}'", note.path, new_stem));
+            continue;
+        }
+
+        // Encrypted notes keep their `.md.enc` extension
+        let extension = if note.is_encrypted { "md.enc" } else { "md" };
+        let new_path = old_path.with_file_name(format!("{}.{}", new_stem, extension));
+        if new_path.exists() || !targets.insert(new_path.clone()) {
+            report.skipped_collisions.push(note.path);
+            continue;
+        }
+        planned.push((note.path, old_path, new_path));
+    }
 
-    // 3. Write file
-    fs::write(&file_path, image_data).map_err(|e| format!("Failed to write image file: {}", e))?;
+    let mut changed_files: Vec<PathBuf> = Vec::new();
+    for (relative, old_path, new_path) in planned {
+        let new_relative = new_path
+            .strip_prefix(vault)
+            .unwrap_or(&new_path)
+            .to_string_lossy()
+            .to_string();
+
+        if dry_run {
+            report.renamed.push((relative, new_relative));
+            continue;
+        }
 
-    println!("DEBUG: Write success!");
+        match rename_note_and_links(vault, &old_path, &new_path) {
+            Ok(updated) => {
+                changed_files.extend(updated);
+                changed_files.push(old_path);
+                changed_files.push(new_path);
+                report.renamed.push((relative, new_relative));
+            }
+            Err(e) => report.errors.push(format!("{}: {}", relative, e)),
+        }
+    }
+
+    // Auto-commit if Git repository
+    if !changed_files.is_empty() {
+        if let Some(repo) = crate::git_manager::open_repository(vault) {
+            changed_files.sort();
+            changed_files.dedup();
+            let file_refs: Vec<&Path> = changed_files.iter().map(|p| p.as_path()).collect();
+            let _ = crate::git_manager::auto_commit_mosaic_changes(
+                &repo,
+                &format!("Bulk rename: {} → {}", pattern, replacement),
+                &file_refs,
+            ); // Silently fail if commit fails
+        }
+    }
+
+    Ok(report)
+}
+
+// ============================================================================
+// Folder Moves
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoveFolderResult {
+    pub notes_moved: usize,
+    pub links_updated: usize, // Wikilinks rewritten across the vault
+    pub new_path: String,     // Relative path of the moved folder
+}
+
+/// A vault-relative folder path without leading/trailing slashes
+fn relative_folder(folder: &str) -> Result<String, String> {
+    let folder = folder.trim().trim_matches('/').to_string();
+    let path = Path::new(&folder);
+    if folder.is_empty()
+        || path.is_absolute()
+        || path
+            .components()
+            .any(|c| matches!(c, std::path::Component::ParentDir))
+    {
+        return Err(format!("Folder '{}' must be inside the vault", folder));
+    }
+    Ok(folder)
+}
+
+/// Move a folder (with everything in it) and rewrite path-style wikilinks into it
+/// Links by bare note name keep working since note names don't change
+#[command]
+pub async fn move_folder(
+    vault_path: String,
+    source_folder: String,
+    destination_folder: String,
+) -> Result<MoveFolderResult, String> {
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let source = relative_folder(&source_folder)?;
+    let destination = relative_folder(&destination_folder)?;
+    let source_dir = vault.join(&source);
+    let destination_dir = vault.join(&destination);
+
+    if !source_dir.is_dir() {
+        return Err(format!("Folder '{}' does not exist", source));
+    }
+    if destination_dir.exists() {
+        return Err(format!("Destination '{}' already exists", destination));
+    }
+    if destination_dir.starts_with(&source_dir) {
+        return Err("Can't move a folder into itself".to_string());
+    }
+
+    // Files to commit, recorded before they move
+    let (notes, attachments) = collect_vault_files(&source_dir);
+    let notes_moved = notes.len();
+
+    if let Some(parent) = destination_dir.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create destination folder: {}", e))?;
+    }
+    fs::rename(&source_dir, &destination_dir)
+        .map_err(|e| format!("Failed to move folder: {}", e))?;
+    crate::pins::repoint(vault, Path::new(&source), Path::new(&destination));
+
+    // [[source/...]] -> [[destination/...]]
+    let link_regex =
+        Regex::new(&format!(r"\[\[\s*{}/", regex::escape(&source))).map_err(|e| e.to_string())?;
+    let replacement = format!("[[{}/", destination);
+
+    let mut changed_files: Vec<PathBuf> = Vec::new();
+    let mut links_updated = 0;
+    for note in collect_vault_files(vault).0 {
+        let Ok(content) = fs::read_to_string(&note) else {
+            continue;
+        };
+        let count = link_regex.find_iter(&content).count();
+        if count == 0 {
+            continue;
+        }
+
+        let updated = link_regex.replace_all(&content, regex::NoExpand(&replacement));
+        fs::write(&note, updated.as_ref()).map_err(|e| format!("Failed to update links: {}", e))?;
+        links_updated += count;
+        changed_files.push(note);
+    }
+
+    for old_path in notes.into_iter().chain(attachments) {
+        if let Ok(relative) = old_path.strip_prefix(&source_dir) {
+            changed_files.push(destination_dir.join(relative));
+        }
+        changed_files.push(old_path);
+    }
+
+    // Auto-commit if Git repository
+    if let Some(repo) = crate::git_manager::open_repository(vault) {
+        changed_files.sort();
+        changed_files.dedup();
+        let file_refs: Vec<&Path> = changed_files.iter().map(|p| p.as_path()).collect();
+        let _ = crate::git_manager::auto_commit_mosaic_changes(
+            &repo,
+            &format!("Moved folder {} → {}", source, destination),
+            &file_refs,
+        ); // Silently fail if commit fails
+    }
 
-    // 4. Return relative path for Markdown link
-    Ok(format!("assets/{}", safe_name))
+    Ok(MoveFolderResult {
+        notes_moved,
+        links_updated,
+        new_path: destination,
+    })
 }
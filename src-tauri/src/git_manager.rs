@@ -1,4 +1,5 @@
 use git2::{Error as GitError, Oid, Repository, Signature};
+use serde::{Deserialize, Serialize};
 use std::fs::OpenOptions;
 use std::io::Write;
 use std::path::Path;
@@ -104,6 +105,126 @@ fn create_commit_internal(
     }
 }
 
+// ============================================================================
+// Commit Message Config
+// ============================================================================
+
+const COMMIT_CONFIG_FILE_NAME: &str = ".moss/commit_config.json";
+
+/// User-defined commit message conventions for a vault
+///
+/// Templates support `{{action}}`, `{{filename}}`, `{{datetime}}` and
+/// `{{files_count}}`. Empty author fields fall back to the built-in
+/// Mosaic/User identities.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CommitMessageConfig {
+    pub auto_commit_template: String,
+    pub manual_commit_format: String,
+    pub include_timestamp: bool, // Append "(YYYY-MM-DD HH:MM)" to auto-commits
+    pub author_name: String,
+    pub author_email: String,
+}
+
+impl Default for CommitMessageConfig {
+    fn default() -> Self {
+        Self {
+            auto_commit_template: "Mosaic: {{action}}".to_string(),
+            manual_commit_format: "{{action}}".to_string(),
+            include_timestamp: true,
+            author_name: String::new(),
+            author_email: String::new(),
+        }
+    }
+}
+
+/// Load the commit config for a vault, using defaults when none is saved
+pub fn load_commit_config(vault_path: &Path) -> Result<CommitMessageConfig, String> {
+    let path = vault_path.join(COMMIT_CONFIG_FILE_NAME);
+    if !path.exists() {
+        return Ok(CommitMessageConfig::default());
+    }
+
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read commit config: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse commit config: {}", e))
+}
+
+/// Save the commit config for a vault
+pub fn save_commit_config(vault_path: &Path, config: &CommitMessageConfig) -> Result<(), String> {
+    let path = vault_path.join(COMMIT_CONFIG_FILE_NAME);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create .moss dir: {}", e))?;
+    }
+
+    let json = serde_json::to_string_pretty(config)
+        .map_err(|e| format!("Failed to serialize commit config: {}", e))?;
+    std::fs::write(&path, json).map_err(|e| format!("Failed to write commit config: {}", e))
+}
+
+/// Commit config of the repository's working directory (defaults if unreadable)
+fn commit_config_for(repo: &Repository) -> CommitMessageConfig {
+    repo.workdir()
+        .and_then(|dir| load_commit_config(dir).ok())
+        .unwrap_or_default()
+}
+
+/// Fill in commit template variables
+fn render_commit_message(template: &str, action: &str, files: &[&Path]) -> String {
+    let filename = files
+        .first()
+        .and_then(|f| f.file_name())
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let datetime = chrono::Local::now().format("%Y-%m-%d %H:%M").to_string();
+
+    template
+        .replace("{{action}}", action)
+        .replace("{{filename}}", &filename)
+        .replace("{{datetime}}", &datetime)
+        .replace("{{files_count}}", &files.len().to_string())
+}
+
+/// Trailer marking commits made by Mosaic, whatever the message template
+const MOSAIC_TRAILER: &str = "Mosaic-Commit: true";
+
+/// Whether a commit message belongs to a Mosaic auto-commit
+/// Commits from before the trailer was added are recognised by their prefix
+pub(crate) fn is_mosaic_commit(message: &str) -> bool {
+    message.lines().any(|line| line.trim() == MOSAIC_TRAILER) || message.starts_with("Mosaic:")
+}
+
+/// Commit message without the Mosaic trailer, for display
+fn strip_mosaic_trailer(message: &str) -> String {
+    message
+        .lines()
+        .filter(|line| line.trim() != MOSAIC_TRAILER)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_end()
+        .to_string()
+}
+
+/// Pick the configured author, or the given default when unset
+fn commit_author<'a>(
+    config: &'a CommitMessageConfig,
+    default_name: &'a str,
+    default_email: &'a str,
+) -> (&'a str, &'a str) {
+    let name = if config.author_name.trim().is_empty() {
+        default_name
+    } else {
+        config.author_name.trim()
+    };
+    let email = if config.author_email.trim().is_empty() {
+        default_email
+    } else {
+        config.author_email.trim()
+    };
+    (name, email)
+}
+
 // ============================================================================
 // Commit Operations
 // ============================================================================
@@ -111,7 +232,8 @@ fn create_commit_internal(
 /// Auto-commit changes made by Mosaic
 ///
 /// Creates a commit with all changes in the specified files.
-/// Commit message format: "Mosaic: {action}"
+/// Commit message format comes from the vault's `CommitMessageConfig`
+/// (default: "Mosaic: {action} ({timestamp})"), followed by the Mosaic trailer
 pub fn auto_commit_mosaic_changes(
     repo: &Repository,
     message: &str,
@@ -124,7 +246,12 @@ pub fn auto_commit_mosaic_changes(
         let repo_path = repo.path().parent().unwrap();
         let relative_path = file_path.strip_prefix(repo_path).unwrap_or(file_path);
 
-        index.add_path(relative_path)?;
+        // Files removed from disk are staged as deletions
+        if file_path.exists() {
+            index.add_path(relative_path)?;
+        } else {
+            index.remove_path(relative_path)?;
+        }
     }
     index.write()?;
 
@@ -132,17 +259,17 @@ pub fn auto_commit_mosaic_changes(
     let tree_id = index.write_tree()?;
     let tree = repo.find_tree(tree_id)?;
 
-    // Commit message with Mosaic prefix and timestamp
-    let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M").to_string();
-    let full_message = format!("Mosaic: {} ({})", message, timestamp);
+    // Commit message from the configured template, optionally timestamped
+    let config = commit_config_for(repo);
+    let mut full_message = render_commit_message(&config.auto_commit_template, message, files);
+    if config.include_timestamp {
+        let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M").to_string();
+        full_message = format!("{} ({})", full_message, timestamp);
+    }
+    full_message = format!("{}\n\n{}", full_message, MOSAIC_TRAILER);
 
-    create_commit_internal(
-        repo,
-        &full_message,
-        &tree,
-        "Mosaic",
-        "mosaic@amber-app.local",
-    )
+    let (author_name, author_email) = commit_author(&config, "Mosaic", "mosaic@amber-app.local");
+    create_commit_internal(repo, &full_message, &tree, author_name, author_email)
 }
 
 /// Create a manual commit for specific files
@@ -161,7 +288,10 @@ pub fn commit_file(repo: &Repository, message: &str, file_path: &Path) -> Result
     let tree_id = index.write_tree()?;
     let tree = repo.find_tree(tree_id)?;
 
-    create_commit_internal(repo, message, &tree, "User", "user@amber-app.local")
+    let config = commit_config_for(repo);
+    let full_message = render_commit_message(&config.manual_commit_format, message, &[file_path]);
+    let (author_name, author_email) = commit_author(&config, "User", "user@amber-app.local");
+    create_commit_internal(repo, &full_message, &tree, author_name, author_email)
 }
 
 /// Create a manual commit for ALL changes in the vault
@@ -246,7 +376,7 @@ pub fn restore_vault_to_commit(repo: &Repository, commit_oid: &str) -> Result<Oi
 /// Revert the last commit made by Mosaic
 ///
 /// Uses `git revert` (safe, creates new commit) instead of `git reset` (destructive).
-/// Only reverts commits with "Mosaic:" prefix for safety.
+/// Only reverts commits carrying the Mosaic trailer for safety.
 pub fn undo_last_mosaic_commit(repo: &Repository) -> Result<Oid, GitError> {
     // Get HEAD commit
     let head = repo.head()?;
@@ -254,7 +384,7 @@ pub fn undo_last_mosaic_commit(repo: &Repository) -> Result<Oid, GitError> {
 
     // Check if this is an Mosaic commit
     let message = head_commit.message().unwrap_or("");
-    if !message.starts_with("Mosaic:") {
+    if !is_mosaic_commit(message) {
         return Err(GitError::from_str(
             "Last commit was not made by Mosaic. Cannot undo.",
         ));
@@ -275,7 +405,7 @@ pub fn undo_last_mosaic_commit(repo: &Repository) -> Result<Oid, GitError> {
     let tree = repo.find_tree(tree_id)?;
 
     let signature = Signature::now("Mosaic", "mosaic@amber-app.local")?;
-    let revert_message = format!("Revert: {}", message);
+    let revert_message = format!("Revert: {}", strip_mosaic_trailer(message));
 
     let commit_oid = repo.commit(
         Some("HEAD"),
@@ -338,8 +468,9 @@ pub fn get_commit_history(
 
         let oid = oid?;
         let commit = repo.find_commit(oid)?;
-        let message = commit.message().unwrap_or("").to_string();
-        let is_mosaic = message.starts_with("Mosaic:");
+        let raw_message = commit.message().unwrap_or("");
+        let is_mosaic = is_mosaic_commit(raw_message);
+        let message = strip_mosaic_trailer(raw_message);
 
         if mosaic_only && !is_mosaic {
             continue;
@@ -538,6 +669,383 @@ pub fn get_commit_changes(
     Ok(changes)
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CommitHistoryStats {
+    pub total_commits: usize,
+    pub mosaic_commits: usize,
+    pub user_commits: usize,
+    pub active_days: Vec<String>, // YYYY-MM-DD, oldest first
+    pub notes_created: usize,
+    pub notes_modified: usize,
+    pub notes_deleted: usize,
+    pub most_edited_files: Vec<(String, usize)>, // Top 10 by commits touching them
+}
+
+/// Aggregate commit activity between two Unix timestamps (inclusive)
+pub fn get_commit_statistics(
+    repo: &Repository,
+    since: i64,
+    until: i64,
+) -> Result<CommitHistoryStats, String> {
+    use chrono::TimeZone;
+    use std::collections::{BTreeSet, HashMap};
+
+    let mut stats = CommitHistoryStats {
+        total_commits: 0,
+        mosaic_commits: 0,
+        user_commits: 0,
+        active_days: Vec::new(),
+        notes_created: 0,
+        notes_modified: 0,
+        notes_deleted: 0,
+        most_edited_files: Vec::new(),
+    };
+
+    let mut revwalk = repo.revwalk().map_err(|e| e.to_string())?;
+    if revwalk.push_head().is_err() {
+        return Ok(stats); // No commits yet
+    }
+    revwalk
+        .set_sorting(git2::Sort::TIME)
+        .map_err(|e| e.to_string())?;
+
+    let mut active_days = BTreeSet::new();
+    let mut edit_counts: HashMap<String, usize> = HashMap::new();
+
+    for oid in revwalk {
+        let oid = oid.map_err(|e| e.to_string())?;
+        let commit = repo.find_commit(oid).map_err(|e| e.to_string())?;
+        let time = commit.time().seconds();
+
+        if time > until {
+            continue;
+        }
+        if time < since {
+            break; // Sorted newest first, everything after is older
+        }
+
+        stats.total_commits += 1;
+        if is_mosaic_commit(commit.message().unwrap_or("")) {
+            stats.mosaic_commits += 1;
+        } else {
+            stats.user_commits += 1;
+        }
+
+        if let Some(day) = chrono::Local.timestamp_opt(time, 0).single() {
+            active_days.insert(day.format("%Y-%m-%d").to_string());
+        }
+
+        let tree = commit.tree().map_err(|e| e.to_string())?;
+        let parent_tree = match commit.parent(0) {
+            Ok(parent) => Some(parent.tree().map_err(|e| e.to_string())?),
+            Err(_) => None,
+        };
+        let diff = repo
+            .diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)
+            .map_err(|e| e.to_string())?;
+
+        for delta in diff.deltas() {
+            let Some(path) = delta
+                .new_file()
+                .path()
+                .or_else(|| delta.old_file().path())
+                .map(|p| p.to_string_lossy().to_string())
+            else {
+                continue;
+            };
+
+            if path.ends_with(".md") {
+                match delta.status() {
+                    git2::Delta::Added => stats.notes_created += 1,
+                    git2::Delta::Deleted => stats.notes_deleted += 1,
+                    git2::Delta::Modified | git2::Delta::Renamed => stats.notes_modified += 1,
+                    _ => {}
+                }
+            }
+
+            *edit_counts.entry(path).or_insert(0) += 1;
+        }
+    }
+
+    let mut most_edited: Vec<(String, usize)> = edit_counts.into_iter().collect();
+    most_edited.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    most_edited.truncate(10);
+
+    stats.active_days = active_days.into_iter().collect();
+    stats.most_edited_files = most_edited;
+    Ok(stats)
+}
+
+// ============================================================================
+// Changelog
+// ============================================================================
+
+/// Render the file changes of a range of commits as a changelog grouped by day
+///
+/// Commits reachable from `until` (default HEAD) but not from the `since_tag`
+/// tag are included, newest day first. Renames count as updates.
+pub fn generate_changelog(
+    repo: &Repository,
+    since_tag: Option<&str>,
+    until: Option<&str>,
+    markdown: bool,
+    mosaic_only: bool,
+) -> Result<String, GitError> {
+    use chrono::TimeZone;
+    use std::collections::BTreeMap;
+
+    // Walk only the requested range: from `until` (or HEAD) back to `since_tag`
+    let mut revwalk = repo.revwalk()?;
+    match until {
+        Some(until) => revwalk.push(repo.revparse_single(until)?.peel_to_commit()?.id())?,
+        None => {
+            if revwalk.push_head().is_err() {
+                return Ok(String::new()); // No commits yet
+            }
+        }
+    }
+    if let Some(tag) = since_tag {
+        let tag_commit = repo
+            .revparse_single(&format!("refs/tags/{}", tag))?
+            .peel_to_commit()?;
+        revwalk.hide(tag_commit.id())?;
+    }
+    revwalk.set_sorting(git2::Sort::TIME)?;
+
+    // Day -> change lines, days sorted newest first on output
+    let mut days: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for oid in revwalk {
+        let commit = repo.find_commit(oid?)?;
+        if mosaic_only && !is_mosaic_commit(commit.message().unwrap_or("")) {
+            continue;
+        }
+        let Some(day) = chrono::Local
+            .timestamp_opt(commit.time().seconds(), 0)
+            .single()
+        else {
+            continue;
+        };
+        let lines = days.entry(day.format("%Y-%m-%d").to_string()).or_default();
+
+        for change in get_commit_changes(repo, &commit.id().to_string())? {
+            let category = match change.status.as_str() {
+                "added" | "copied" => "Created",
+                "deleted" => "Deleted",
+                _ => "Updated",
+            };
+            let line = format!("{}: {}", category, change.path);
+            if !lines.contains(&line) {
+                lines.push(line);
+            }
+        }
+    }
+
+    let mut output = String::new();
+    for (day, lines) in days.iter().rev().filter(|(_, lines)| !lines.is_empty()) {
+        if markdown {
+            output.push_str(&format!("## {}\n", day));
+            for line in lines {
+                output.push_str(&format!("- {}\n", line));
+            }
+        } else {
+            output.push_str(&format!("{}\n", day));
+            for line in lines {
+                output.push_str(&format!("  {}\n", line));
+            }
+        }
+        output.push('\n');
+    }
+
+    Ok(output.trim_end().to_string())
+}
+
+// ============================================================================
+// Hunk Staging
+// ============================================================================
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct FileHunk {
+    pub file_path: String,
+    pub hunk_index: usize, // Position of the hunk within its file's patch
+    pub header: String,
+    pub content: String, // Hunk lines prefixed with ' ', '+' or '-'
+    pub additions: usize,
+    pub deletions: usize,
+}
+
+/// Diff between the index and the working directory, including new files
+fn unstaged_diff<'a>(
+    repo: &'a Repository,
+    file_path: Option<&str>,
+) -> Result<git2::Diff<'a>, GitError> {
+    let mut opts = git2::DiffOptions::new();
+    opts.include_untracked(true)
+        .recurse_untracked_dirs(true)
+        .show_untracked_content(true);
+    if let Some(file_path) = file_path {
+        opts.pathspec(file_path).disable_pathspec_match(true);
+    }
+    repo.diff_index_to_workdir(None, Some(&mut opts))
+}
+
+/// List every unstaged hunk in the working directory
+pub fn get_unstaged_hunks(repo: &Repository) -> Result<Vec<FileHunk>, GitError> {
+    let diff = unstaged_diff(repo, None)?;
+    let mut hunks = Vec::new();
+
+    for delta_idx in 0..diff.deltas().len() {
+        let Some(patch) = git2::Patch::from_diff(&diff, delta_idx)? else {
+            continue; // Binary files have no hunks
+        };
+        let delta = patch.delta();
+        let file_path = delta
+            .new_file()
+            .path()
+            .or_else(|| delta.old_file().path())
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        for hunk_index in 0..patch.num_hunks() {
+            let (hunk, line_count) = patch.hunk(hunk_index)?;
+            let mut content = String::new();
+            let mut additions = 0;
+            let mut deletions = 0;
+
+            for line_index in 0..line_count {
+                let line = patch.line_in_hunk(hunk_index, line_index)?;
+                match line.origin() {
+                    '+' => additions += 1,
+                    '-' => deletions += 1,
+                    _ => {}
+                }
+                if matches!(line.origin(), ' ' | '+' | '-') {
+                    content.push(line.origin());
+                }
+                content.push_str(&String::from_utf8_lossy(line.content()));
+            }
+
+            hunks.push(FileHunk {
+                file_path: file_path.clone(),
+                hunk_index,
+                header: String::from_utf8_lossy(hunk.header())
+                    .trim_end()
+                    .to_string(),
+                content,
+                additions,
+                deletions,
+            });
+        }
+    }
+
+    Ok(hunks)
+}
+
+/// Stage a single hunk of a file, leaving its other changes unstaged
+pub fn stage_hunk(repo: &Repository, file_path: &str, hunk_index: usize) -> Result<(), GitError> {
+    let diff = unstaged_diff(repo, Some(file_path))?;
+    if diff.deltas().len() == 0 {
+        return Err(GitError::from_str(&format!(
+            "No unstaged changes in '{}'",
+            file_path
+        )));
+    }
+
+    let mut patch = git2::Patch::from_diff(&diff, 0)?
+        .ok_or_else(|| GitError::from_str("Cannot stage hunks of a binary file"))?;
+    if hunk_index >= patch.num_hunks() {
+        return Err(GitError::from_str(&format!(
+            "Hunk {} does not exist in '{}'",
+            hunk_index, file_path
+        )));
+    }
+
+    // Re-parse the file's patch from a buffer and apply only the chosen hunk
+    let buffer = patch.to_buf()?;
+    let file_diff = git2::Diff::from_buffer(&buffer)?;
+
+    let mut current = 0;
+    let mut apply_opts = git2::ApplyOptions::new();
+    apply_opts.hunk_callback(|_| {
+        let selected = current == hunk_index;
+        current += 1;
+        selected
+    });
+
+    repo.apply(
+        &file_diff,
+        git2::ApplyLocation::Index,
+        Some(&mut apply_opts),
+    )
+}
+
+// ============================================================================
+// Uncommitted Changes
+// ============================================================================
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct NoteDiff {
+    pub old_content: String, // Content at HEAD (empty for new files)
+    pub new_content: String, // Content in the working directory (empty if deleted)
+    pub patch: String,       // Unified diff from HEAD to the working directory
+    pub added_lines: usize,
+    pub removed_lines: usize,
+    pub is_new_file: bool,
+}
+
+/// Diff a single file against HEAD, covering both staged and unstaged changes
+/// Returns `None` when the file has no uncommitted changes
+pub fn get_note_uncommitted_diff(
+    repo: &Repository,
+    file_path: &str,
+) -> Result<Option<NoteDiff>, GitError> {
+    let head_tree = repo.head().ok().and_then(|h| h.peel_to_tree().ok());
+
+    let mut staged_opts = git2::DiffOptions::new();
+    staged_opts.pathspec(file_path).disable_pathspec_match(true);
+    let staged = repo.diff_tree_to_index(head_tree.as_ref(), None, Some(&mut staged_opts))?;
+    let unstaged = unstaged_diff(repo, Some(file_path))?;
+
+    if staged.deltas().len() == 0 && unstaged.deltas().len() == 0 {
+        return Ok(None);
+    }
+
+    let old_blob = head_tree
+        .as_ref()
+        .and_then(|tree| tree.get_path(Path::new(file_path)).ok())
+        .and_then(|entry| entry.to_object(repo).ok())
+        .and_then(|object| object.into_blob().ok());
+    let is_new_file = old_blob.is_none();
+    let old_bytes = old_blob.as_ref().map(|b| b.content()).unwrap_or_default();
+
+    let workdir = repo
+        .workdir()
+        .ok_or_else(|| GitError::from_str("Repository has no working directory"))?;
+    let new_bytes = std::fs::read(workdir.join(file_path)).unwrap_or_default();
+
+    let mut patch = git2::Patch::from_buffers(
+        old_bytes,
+        Some(Path::new(file_path)),
+        &new_bytes,
+        Some(Path::new(file_path)),
+        None,
+    )?;
+    let (_context, added_lines, removed_lines) = patch.line_stats()?;
+    let patch_text = patch
+        .to_buf()?
+        .as_str()
+        .map(str::to_string)
+        .unwrap_or_default();
+
+    Ok(Some(NoteDiff {
+        old_content: String::from_utf8_lossy(old_bytes).to_string(),
+        new_content: String::from_utf8_lossy(&new_bytes).to_string(),
+        patch: patch_text,
+        added_lines,
+        removed_lines,
+        is_new_file,
+    }))
+}
+
 // ============================================================================
 // Remote Operations (GitHub Sync)
 // ============================================================================
@@ -971,22 +1479,316 @@ pub fn complete_merge(repo: &Repository) -> Result<Oid, GitError> {
 
 /// Abort an ongoing merge and clean up merge state
 /// This is called when user cancels conflict resolution
+///
+/// A cherry-pick only restores the files it touched, so uncommitted edits to
+/// other files survive.
 pub fn abort_merge(repo: &Repository) -> Result<(), GitError> {
-    // Check if we're in a merge state
-    if repo.state() != git2::RepositoryState::Merge {
+    match repo.state() {
+        git2::RepositoryState::Merge => {
+            // Reset to HEAD to discard merge
+            let head = repo.head()?;
+            let head_commit = head.peel_to_commit()?;
+
+            // Reset working directory and index to HEAD
+            repo.reset(head_commit.as_object(), git2::ResetType::Hard, None)?;
+        }
+        git2::RepositoryState::CherryPick => restore_cherry_picked_paths(repo)?,
         // Not in merge state, nothing to abort
-        return Ok(());
+        _ => return Ok(()),
     }
 
-    // Reset to HEAD to discard merge
-    let head = repo.head()?;
-    let head_commit = head.peel_to_commit()?;
+    // Clean up merge state files
+    repo.cleanup_state()?;
 
-    // Reset working directory and index to HEAD
-    repo.reset(head_commit.as_object(), git2::ResetType::Hard, None)?;
+    Ok(())
+}
+
+/// Restore the files a cherry-pick touched (conflicted or staged by it) to HEAD
+fn restore_cherry_picked_paths(repo: &Repository) -> Result<(), GitError> {
+    let head_commit = repo.head()?.peel_to_commit()?;
+    let head_tree = head_commit.tree()?;
+
+    // Paths in conflict, plus paths the cherry-pick staged
+    let index = repo.index()?;
+    let mut paths: Vec<String> = index
+        .conflicts()?
+        .flatten()
+        .filter_map(|conflict| conflict.our.or(conflict.their).or(conflict.ancestor))
+        .map(|entry| String::from_utf8_lossy(&entry.path).to_string())
+        .collect();
+    let staged = repo.diff_tree_to_index(Some(&head_tree), Some(&index), None)?;
+    for delta in staged.deltas() {
+        for file in [delta.old_file(), delta.new_file()] {
+            if let Some(path) = file.path() {
+                paths.push(path.to_string_lossy().to_string());
+            }
+        }
+    }
+    paths.sort();
+    paths.dedup();
+
+    if !paths.is_empty() {
+        // Working files first, while the index still tracks files the
+        // cherry-pick added (so they get removed), then the index
+        let mut checkout = git2::build::CheckoutBuilder::new();
+        checkout.force().remove_untracked(false);
+        for path in &paths {
+            checkout.path(path);
+        }
+        repo.checkout_tree(head_tree.as_object(), Some(&mut checkout))?;
+        repo.reset_default(Some(head_commit.as_object()), paths.iter())?;
+    }
+
+    Ok(())
+}
+
+/// Apply the changes of a single commit on top of HEAD
+/// On conflict the cherry-pick is aborted and the conflicting files are reported
+pub fn cherry_pick_commit(repo: &Repository, commit_oid: &str) -> Result<Oid, GitError> {
+    let commit = repo.find_commit(Oid::from_str(commit_oid)?)?;
+    if commit.parent_count() > 1 {
+        return Err(GitError::from_str("Cannot cherry-pick a merge commit"));
+    }
+
+    // Aborting restores the touched files to HEAD, which must not take
+    // unrelated uncommitted edits with it
+    let mut status_options = git2::StatusOptions::new();
+    status_options
+        .include_untracked(false)
+        .include_ignored(false);
+    if !repo.statuses(Some(&mut status_options))?.is_empty() {
+        return Err(GitError::from_str(
+            "Commit or discard uncommitted changes before cherry-picking",
+        ));
+    }
+
+    let head_commit = repo.head()?.peel_to_commit()?;
+    repo.cherrypick(&commit, None)?;
+
+    let mut index = repo.index()?;
+    if index.has_conflicts() {
+        let conflicted: Vec<String> = index
+            .conflicts()?
+            .flatten()
+            .filter_map(|conflict| conflict.our.or(conflict.their).or(conflict.ancestor))
+            .map(|entry| String::from_utf8_lossy(&entry.path).to_string())
+            .collect();
+        abort_merge(repo)?;
+        return Err(GitError::from_str(&format!(
+            "Cherry-pick aborted, conflicts in: {}",
+            conflicted.join(", ")
+        )));
+    }
+
+    let tree_id = index.write_tree()?;
+    if tree_id == head_commit.tree_id() {
+        abort_merge(repo)?;
+        return Err(GitError::from_str(
+            "Nothing to cherry-pick: changes are already on the current branch",
+        ));
+    }
+    let tree = repo.find_tree(tree_id)?;
+
+    // Keep the original author and message, commit as the current user
+    let committer = repo
+        .signature()
+        .or_else(|_| Signature::now("User", "user@amber-app.local"))?;
+    let oid = repo.commit(
+        Some("HEAD"),
+        &commit.author(),
+        &committer,
+        commit.message().unwrap_or(""),
+        &tree,
+        &[&head_commit],
+    )?;
 
-    // Clean up merge state files
     repo.cleanup_state()?;
+    Ok(oid)
+}
+
+// ============================================================================
+// Tags
+// ============================================================================
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TagEntry {
+    pub name: String,
+    pub commit_oid: String,
+    pub message: Option<String>, // None for lightweight tags
+    pub created_at: i64,         // Tagger time, or commit time for lightweight tags
+}
+
+/// Tag a commit (HEAD when `commit_oid` is None)
+/// Creates an annotated tag when a message is given, otherwise a lightweight one
+pub fn create_git_tag(
+    repo: &Repository,
+    name: &str,
+    message: Option<&str>,
+    commit_oid: Option<&str>,
+) -> Result<(), GitError> {
+    let commit = match commit_oid {
+        Some(oid) => repo.find_commit(Oid::from_str(oid)?)?,
+        None => repo.head()?.peel_to_commit()?,
+    };
+
+    match message {
+        Some(message) => {
+            let tagger = repo
+                .signature()
+                .or_else(|_| Signature::now("User", "user@amber-app.local"))?;
+            repo.tag(name, commit.as_object(), &tagger, message, false)?;
+        }
+        None => {
+            repo.tag_lightweight(name, commit.as_object(), false)?;
+        }
+    }
 
     Ok(())
 }
+
+/// List all tags, newest first
+pub fn list_git_tags(repo: &Repository) -> Result<Vec<TagEntry>, GitError> {
+    let mut tags = Vec::new();
+
+    for name in repo.tag_names(None)?.iter().flatten() {
+        let reference = repo.find_reference(&format!("refs/tags/{}", name))?;
+        let commit = match reference.peel_to_commit() {
+            Ok(commit) => commit,
+            Err(_) => continue, // Tags pointing at trees/blobs aren't vault snapshots
+        };
+
+        // Annotated tags point at a tag object carrying message and tagger
+        let annotated = reference.peel_to_tag().ok();
+        let message = annotated
+            .as_ref()
+            .and_then(|tag| tag.message().map(|m| m.trim_end().to_string()));
+        let created_at = annotated
+            .as_ref()
+            .and_then(|tag| tag.tagger().map(|t| t.when().seconds()))
+            .unwrap_or_else(|| commit.time().seconds());
+
+        tags.push(TagEntry {
+            name: name.to_string(),
+            commit_oid: commit.id().to_string(),
+            message,
+            created_at,
+        });
+    }
+
+    tags.sort_by_key(|tag| std::cmp::Reverse(tag.created_at));
+    Ok(tags)
+}
+
+/// Delete a tag by name
+pub fn delete_git_tag(repo: &Repository, name: &str) -> Result<(), GitError> {
+    repo.tag_delete(name)
+}
+
+// ============================================================================
+// Worktrees
+// ============================================================================
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct WorktreeInfo {
+    pub path: String,
+    pub branch: String,      // Empty when the worktree can't be opened
+    pub head_commit: String, // Empty when the worktree can't be opened
+    pub is_prunable: bool,   // Working tree is gone and the entry can be cleaned up
+}
+
+/// Check out a branch into a new linked worktree at `worktree_path`
+/// The branch is created from HEAD if it doesn't exist yet
+pub fn create_worktree(
+    repo: &Repository,
+    worktree_path: &Path,
+    branch_name: &str,
+) -> Result<(), GitError> {
+    let name = worktree_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .filter(|n| !n.is_empty())
+        .ok_or_else(|| GitError::from_str("Worktree path has no folder name"))?;
+    if repo.worktrees()?.iter().flatten().any(|w| w == name) {
+        return Err(GitError::from_str(&format!(
+            "A worktree named '{}' already exists",
+            name
+        )));
+    }
+
+    let branch = match repo.find_branch(branch_name, git2::BranchType::Local) {
+        Ok(branch) => branch,
+        Err(_) => {
+            let head = repo.head()?.peel_to_commit()?;
+            repo.branch(branch_name, &head, false)?
+        }
+    };
+
+    let mut options = git2::WorktreeAddOptions::new();
+    options.reference(Some(branch.get()));
+    repo.worktree(&name, worktree_path, Some(&options))?;
+
+    Ok(())
+}
+
+/// Linked worktrees of a repository (the main working tree isn't included)
+pub fn list_worktrees(repo: &Repository) -> Result<Vec<WorktreeInfo>, GitError> {
+    let mut worktrees = Vec::new();
+
+    for name in repo.worktrees()?.iter().flatten() {
+        let worktree = repo.find_worktree(name)?;
+        let is_prunable = worktree.is_prunable(None).unwrap_or(false);
+
+        let (branch, head_commit) = Repository::open_from_worktree(&worktree)
+            .ok()
+            .and_then(|wt_repo| {
+                let head = wt_repo.head().ok()?;
+                let branch = head.shorthand().unwrap_or("HEAD").to_string();
+                let commit = head.peel_to_commit().ok()?.id().to_string();
+                Some((branch, commit))
+            })
+            .unwrap_or_default();
+
+        worktrees.push(WorktreeInfo {
+            path: worktree.path().to_string_lossy().to_string(),
+            branch,
+            head_commit,
+            is_prunable,
+        });
+    }
+
+    worktrees.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(worktrees)
+}
+
+/// Delete a linked worktree's files and its entry in the repository
+/// Refuses worktrees with uncommitted changes
+pub fn remove_worktree(repo: &Repository, worktree_path: &Path) -> Result<(), GitError> {
+    let same_path = |a: &Path, b: &Path| match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a.components().eq(b.components()),
+    };
+
+    let worktree = repo
+        .worktrees()?
+        .iter()
+        .flatten()
+        .filter_map(|name| repo.find_worktree(name).ok())
+        .find(|w| same_path(w.path(), worktree_path))
+        .ok_or_else(|| GitError::from_str("No worktree at that path"))?;
+
+    if let Ok(wt_repo) = Repository::open_from_worktree(&worktree) {
+        let mut status_options = git2::StatusOptions::new();
+        status_options
+            .include_untracked(true)
+            .include_ignored(false);
+        if !wt_repo.statuses(Some(&mut status_options))?.is_empty() {
+            return Err(GitError::from_str(
+                "Worktree has uncommitted changes, commit or discard them first",
+            ));
+        }
+    }
+
+    let mut prune_options = git2::WorktreePruneOptions::new();
+    prune_options.valid(true).working_tree(true);
+    worktree.prune(Some(&mut prune_options))
+}
@@ -279,3 +279,177 @@ pub async fn create_repository(
 
     Ok(repo)
 }
+
+// ============================================================================
+// Issues
+// ============================================================================
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct GitHubIssue {
+    pub number: u64,
+    pub title: String,
+    pub body: String,
+    pub state: String,
+    pub labels: Vec<String>,
+    pub author: String,
+    pub created_at: String,
+    pub url: String,
+}
+
+#[derive(Debug, Serialize)]
+struct CreateIssueRequest {
+    title: String,
+    body: String,
+    labels: Vec<String>,
+}
+
+/// Repository names must look like "owner/name"
+fn validate_repo_full_name(repo_full_name: &str) -> Result<(), String> {
+    let mut parts = repo_full_name.split('/');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(owner), Some(name), None) if !owner.is_empty() && !name.is_empty() => Ok(()),
+        _ => Err(format!(
+            "Invalid repository '{}', expected owner/name",
+            repo_full_name
+        )),
+    }
+}
+
+/// Map an issue from the REST API into our flattened shape
+fn parse_issue(value: &serde_json::Value) -> Option<GitHubIssue> {
+    Some(GitHubIssue {
+        number: value["number"].as_u64()?,
+        title: value["title"].as_str()?.to_string(),
+        body: value["body"].as_str().unwrap_or("").to_string(),
+        state: value["state"].as_str().unwrap_or("open").to_string(),
+        labels: value["labels"]
+            .as_array()
+            .map(|labels| {
+                labels
+                    .iter()
+                    .filter_map(|l| l["name"].as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        author: value["user"]["login"].as_str().unwrap_or("").to_string(),
+        created_at: value["created_at"].as_str().unwrap_or("").to_string(),
+        url: value["html_url"].as_str().unwrap_or("").to_string(),
+    })
+}
+
+/// List issues of a repository (pull requests are excluded)
+/// `state` is "open", "closed" or "all"
+pub async fn list_issues(
+    access_token: &str,
+    repo_full_name: &str,
+    state: &str,
+    labels: &[String],
+    limit: usize,
+) -> Result<Vec<GitHubIssue>, String> {
+    validate_repo_full_name(repo_full_name)?;
+    if !matches!(state, "open" | "closed" | "all") {
+        return Err(format!("Invalid issue state '{}'", state));
+    }
+
+    let client = reqwest::Client::new();
+    let label_filter = labels.join(",");
+    let mut issues = Vec::new();
+    let mut page = 1;
+
+    while issues.len() < limit {
+        let mut query = vec![
+            ("state", state.to_string()),
+            ("per_page", "100".to_string()),
+            ("page", page.to_string()),
+        ];
+        if !label_filter.is_empty() {
+            query.push(("labels", label_filter.clone()));
+        }
+
+        let response = client
+            .get(format!(
+                "{}/repos/{}/issues",
+                GITHUB_API_URL, repo_full_name
+            ))
+            .header("Accept", "application/vnd.github+json")
+            .header("Authorization", format!("Bearer {}", access_token))
+            .header("User-Agent", "Amber-App")
+            .header("X-GitHub-Api-Version", "2022-11-28")
+            .query(&query)
+            .send()
+            .await
+            .map_err(|e| format!("Failed to list issues: {}", e))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(format!("GitHub API error {}: {}", status, body));
+        }
+
+        let items: Vec<serde_json::Value> = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse issues: {}", e))?;
+        let page_len = items.len();
+
+        issues.extend(
+            items
+                .iter()
+                .filter(|item| item.get("pull_request").is_none())
+                .filter_map(parse_issue),
+        );
+
+        if page_len < 100 {
+            break; // Last page
+        }
+        page += 1;
+    }
+
+    issues.truncate(limit);
+    Ok(issues)
+}
+
+/// Open a new issue in a repository
+pub async fn create_issue(
+    access_token: &str,
+    repo_full_name: &str,
+    title: &str,
+    body: &str,
+    labels: Vec<String>,
+) -> Result<GitHubIssue, String> {
+    validate_repo_full_name(repo_full_name)?;
+
+    let client = reqwest::Client::new();
+    let request_body = CreateIssueRequest {
+        title: title.to_string(),
+        body: body.to_string(),
+        labels,
+    };
+
+    let response = client
+        .post(format!(
+            "{}/repos/{}/issues",
+            GITHUB_API_URL, repo_full_name
+        ))
+        .header("Accept", "application/vnd.github+json")
+        .header("Authorization", format!("Bearer {}", access_token))
+        .header("User-Agent", "Amber-App")
+        .header("X-GitHub-Api-Version", "2022-11-28")
+        .json(&request_body)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to create issue: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("GitHub API error {}: {}", status, body));
+    }
+
+    let value: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse created issue: {}", e))?;
+
+    parse_issue(&value).ok_or_else(|| "Failed to parse created issue".to_string())
+}
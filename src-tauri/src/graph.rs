@@ -24,6 +24,21 @@ pub struct GraphData {
     pub links: Vec<GraphLink>,
 }
 
+/// Graph node with its tags, serialized flat so it is a drop-in `GraphNode`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TaggedGraphNode {
+    #[serde(flatten)]
+    pub node: GraphNode,
+    pub tags: Vec<String>,
+    pub primary_tag: Option<String>, // The note's tag with the highest vault-wide count
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaggedGraphData {
+    pub nodes: Vec<TaggedGraphNode>,
+    pub links: Vec<GraphLink>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct CachedNode {
     id: String,
@@ -41,7 +56,11 @@ struct GraphCache {
 const CACHE_VERSION: u32 = 1;
 const CACHE_FILE_NAME: &str = ".moss/graph_cache.json";
 
-pub fn get_graph_data_with_cache(vault_path: &Path) -> Result<GraphData, String> {
+/// Graph data for a vault, optionally filtered for display
+pub fn get_graph_data_with_cache(
+    vault_path: &Path,
+    filter: Option<&GraphLayoutConfig>,
+) -> Result<GraphData, String> {
     let cache_path = vault_path.join(CACHE_FILE_NAME);
     let mut cache: GraphCache = if cache_path.exists() {
         match fs::read_to_string(&cache_path) {
@@ -235,8 +254,128 @@ pub fn get_graph_data_with_cache(vault_path: &Path) -> Result<GraphData, String>
         }
     }
 
-    Ok(GraphData {
+    let data = GraphData {
         nodes: nodes_map.into_values().collect(),
         links: final_links,
+    };
+
+    Ok(match filter {
+        Some(config) => filter_graph(data, config),
+        None => data,
     })
 }
+
+// ============================================================================
+// Layout Config
+// ============================================================================
+
+const LAYOUT_CONFIG_FILE_NAME: &str = ".moss/graph_layout.json";
+
+/// Force simulation parameters and display filters for the graph view
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphLayoutConfig {
+    pub node_repulsion: f32,
+    pub link_distance: f32,
+    pub link_strength: f32,
+    pub gravity: f32,
+    pub alpha_decay: f32,
+    pub show_orphans: bool,
+    pub min_link_count_for_display: usize, // Incoming plus outgoing links
+    pub max_nodes_displayed: usize,        // 0 means no limit
+}
+
+impl Default for GraphLayoutConfig {
+    fn default() -> Self {
+        Self {
+            node_repulsion: -30.0,
+            link_distance: 30.0,
+            link_strength: 1.0,
+            gravity: 0.1,
+            alpha_decay: 0.0228,
+            show_orphans: true,
+            min_link_count_for_display: 0,
+            max_nodes_displayed: 0,
+        }
+    }
+}
+
+/// Load the vault's graph layout config, or the defaults if none is saved
+pub fn load_layout_config(vault_path: &Path) -> Result<GraphLayoutConfig, String> {
+    let path = vault_path.join(LAYOUT_CONFIG_FILE_NAME);
+    if !path.exists() {
+        return Ok(GraphLayoutConfig::default());
+    }
+
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read graph layout: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse graph layout: {}", e))
+}
+
+pub fn save_layout_config(vault_path: &Path, config: &GraphLayoutConfig) -> Result<(), String> {
+    let numbers = [
+        config.node_repulsion,
+        config.link_distance,
+        config.link_strength,
+        config.gravity,
+        config.alpha_decay,
+    ];
+    if numbers.iter().any(|n| !n.is_finite()) {
+        return Err("Graph layout values must be finite numbers".to_string());
+    }
+    if config.link_distance <= 0.0 {
+        return Err("Link distance must be positive".to_string());
+    }
+    if !(0.0..=1.0).contains(&config.alpha_decay) {
+        return Err("Alpha decay must be between 0 and 1".to_string());
+    }
+
+    let path = vault_path.join(LAYOUT_CONFIG_FILE_NAME);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create .moss dir: {}", e))?;
+    }
+
+    let json = serde_json::to_string_pretty(config)
+        .map_err(|e| format!("Failed to serialize graph layout: {}", e))?;
+    fs::write(&path, json).map_err(|e| format!("Failed to write graph layout: {}", e))
+}
+
+/// Drop nodes the config hides, then any links left without both ends
+/// When over `max_nodes_displayed`, the most linked nodes are kept
+fn filter_graph(data: GraphData, config: &GraphLayoutConfig) -> GraphData {
+    let mut link_counts: HashMap<&str, usize> = HashMap::new();
+    for link in &data.links {
+        if link.source == link.target {
+            continue;
+        }
+        *link_counts.entry(link.source.as_str()).or_default() += 1;
+        *link_counts.entry(link.target.as_str()).or_default() += 1;
+    }
+
+    let mut nodes: Vec<(usize, GraphNode)> = data
+        .nodes
+        .into_iter()
+        .map(|node| {
+            let count = link_counts.get(node.id.as_str()).copied().unwrap_or(0);
+            (count, node)
+        })
+        .filter(|(count, _)| *count >= config.min_link_count_for_display)
+        .filter(|(count, _)| config.show_orphans || *count > 0)
+        .collect();
+
+    if config.max_nodes_displayed > 0 && nodes.len() > config.max_nodes_displayed {
+        nodes.sort_by(|(a_count, a), (b_count, b)| {
+            b_count.cmp(a_count).then_with(|| a.name.cmp(&b.name))
+        });
+        nodes.truncate(config.max_nodes_displayed);
+    }
+
+    let nodes: Vec<GraphNode> = nodes.into_iter().map(|(_, node)| node).collect();
+    let kept: std::collections::HashSet<&str> = nodes.iter().map(|n| n.id.as_str()).collect();
+    let links = data
+        .links
+        .into_iter()
+        .filter(|link| kept.contains(link.source.as_str()) && kept.contains(link.target.as_str()))
+        .collect();
+
+    GraphData { nodes, links }
+}
@@ -0,0 +1,1258 @@
+use crate::graph::{get_graph_data_with_cache, GraphData};
+use crate::tools::{create_note_metadata, NoteMetadata};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use tauri::command;
+
+// ============================================================================
+// Orphaned Notes
+// ============================================================================
+
+/// Find notes with no incoming and no outgoing links
+///
+/// Index/readme notes are excluded by default since they are usually
+/// entry points rather than forgotten notes.
+#[command]
+pub async fn find_orphaned_notes(
+    vault_path: String,
+    include_index_notes: Option<bool>,
+) -> Result<Vec<NoteMetadata>, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let graph_data = get_graph_data_with_cache(vault, None)?;
+    let include_index_notes = include_index_notes.unwrap_or(false);
+
+    // Every node that appears on either end of a link is connected
+    let mut connected: HashSet<&str> = HashSet::new();
+    for link in &graph_data.links {
+        connected.insert(link.source.as_str());
+        connected.insert(link.target.as_str());
+    }
+
+    let mut orphans = Vec::new();
+    for node in &graph_data.nodes {
+        if connected.contains(node.id.as_str()) {
+            continue;
+        }
+
+        if !include_index_notes {
+            let name_lower = node.name.to_lowercase();
+            if name_lower.starts_with("index") || name_lower.starts_with("readme") {
+                continue;
+            }
+        }
+
+        if let Some(metadata) = create_note_metadata(Path::new(&node.id), vault) {
+            orphans.push(metadata);
+        }
+    }
+
+    // Most recently modified first
+    orphans.sort_by_key(|note| std::cmp::Reverse(note.modified));
+
+    Ok(orphans)
+}
+
+// ============================================================================
+// Link Lists
+// ============================================================================
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NoteLinks {
+    pub outbound: Vec<String>, // Graph node IDs this note links to
+    pub inbound: Vec<String>,  // Graph node IDs linking to this note
+}
+
+/// Resolved outbound links and inbound backlinks for every note in one call
+///
+/// Built from the cached graph so exporters don't need a backlinks lookup
+/// per note.
+#[command]
+pub async fn get_all_links(vault_path: String) -> Result<HashMap<String, NoteLinks>, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let graph_data = get_graph_data_with_cache(vault, None)?;
+
+    let mut links: HashMap<String, NoteLinks> = graph_data
+        .nodes
+        .iter()
+        .map(|node| (node.id.clone(), NoteLinks::default()))
+        .collect();
+
+    for link in &graph_data.links {
+        if link.source == link.target {
+            continue;
+        }
+        if let Some(entry) = links.get_mut(&link.source) {
+            entry.outbound.push(link.target.clone());
+        }
+        if let Some(entry) = links.get_mut(&link.target) {
+            entry.inbound.push(link.source.clone());
+        }
+    }
+
+    for entry in links.values_mut() {
+        entry.outbound.sort();
+        entry.outbound.dedup();
+        entry.inbound.sort();
+        entry.inbound.dedup();
+    }
+
+    Ok(links)
+}
+
+// ============================================================================
+// Centrality (PageRank)
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoteScore {
+    pub path: String, // Graph node ID
+    pub score: f64,
+}
+
+/// Build a deduplicated directed adjacency list (node index -> target indices)
+/// Self-links are ignored
+fn directed_adjacency(graph_data: &GraphData) -> (Vec<String>, Vec<Vec<usize>>) {
+    let mut ids: Vec<String> = graph_data.nodes.iter().map(|n| n.id.clone()).collect();
+    ids.sort();
+
+    let index: HashMap<&str, usize> = ids
+        .iter()
+        .enumerate()
+        .map(|(i, id)| (id.as_str(), i))
+        .collect();
+
+    let mut adjacency: Vec<Vec<usize>> = vec![Vec::new(); ids.len()];
+    for link in &graph_data.links {
+        if let (Some(&source), Some(&target)) = (
+            index.get(link.source.as_str()),
+            index.get(link.target.as_str()),
+        ) {
+            if source != target && !adjacency[source].contains(&target) {
+                adjacency[source].push(target);
+            }
+        }
+    }
+
+    (ids, adjacency)
+}
+
+/// Rank notes by PageRank over the directed link graph
+///
+/// Notes linked from other important notes rank higher than notes that are
+/// merely linked from many unimportant ones. Rank held by notes without
+/// outgoing links is spread evenly across the vault.
+#[command]
+pub async fn compute_pagerank(
+    vault_path: String,
+    iterations: Option<usize>,
+    damping: Option<f64>,
+) -> Result<Vec<NoteScore>, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let iterations = iterations.unwrap_or(20);
+    let damping = damping.unwrap_or(0.85);
+    if !(0.0..=1.0).contains(&damping) {
+        return Err("Damping factor must be between 0 and 1".to_string());
+    }
+
+    let graph_data = get_graph_data_with_cache(vault, None)?;
+    let (ids, adjacency) = directed_adjacency(&graph_data);
+
+    let n = ids.len();
+    if n == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut ranks = vec![1.0 / n as f64; n];
+
+    for _ in 0..iterations {
+        let dangling_rank: f64 = adjacency
+            .iter()
+            .zip(&ranks)
+            .filter(|(targets, _)| targets.is_empty())
+            .map(|(_, rank)| rank)
+            .sum();
+
+        let base = (1.0 - damping) / n as f64 + damping * dangling_rank / n as f64;
+        let mut next = vec![base; n];
+
+        for (source, targets) in adjacency.iter().enumerate() {
+            if targets.is_empty() {
+                continue;
+            }
+            let share = damping * ranks[source] / targets.len() as f64;
+            for &target in targets {
+                next[target] += share;
+            }
+        }
+
+        ranks = next;
+    }
+
+    let mut scores: Vec<NoteScore> = ids
+        .into_iter()
+        .zip(ranks)
+        .map(|(path, score)| NoteScore { path, score })
+        .collect();
+
+    scores.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    Ok(scores)
+}
+
+// ============================================================================
+// Clusters
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterInfo {
+    pub cluster_id: usize,
+    pub node_ids: Vec<String>,
+    pub representative_name: String, // Name of the best-connected note
+}
+
+/// Build an undirected adjacency list from the directed one
+fn undirected_adjacency(adjacency: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let mut undirected: Vec<Vec<usize>> = vec![Vec::new(); adjacency.len()];
+    for (source, targets) in adjacency.iter().enumerate() {
+        for &target in targets {
+            if !undirected[source].contains(&target) {
+                undirected[source].push(target);
+            }
+            if !undirected[target].contains(&source) {
+                undirected[target].push(source);
+            }
+        }
+    }
+    for neighbors in &mut undirected {
+        neighbors.sort_unstable();
+    }
+    undirected
+}
+
+/// Label every node with the smallest node index in its connected component
+fn connected_component_labels(neighbors: &[Vec<usize>]) -> Vec<usize> {
+    let mut labels = vec![usize::MAX; neighbors.len()];
+
+    for start in 0..neighbors.len() {
+        if labels[start] != usize::MAX {
+            continue;
+        }
+
+        labels[start] = start;
+        let mut stack = vec![start];
+        while let Some(node) = stack.pop() {
+            for &neighbor in &neighbors[node] {
+                if labels[neighbor] == usize::MAX {
+                    labels[neighbor] = start;
+                    stack.push(neighbor);
+                }
+            }
+        }
+    }
+
+    labels
+}
+
+/// Community labels via iterative label propagation
+/// Each node adopts the most common label among its neighbours (ties go to
+/// the smallest label) until nothing changes
+fn label_propagation(neighbors: &[Vec<usize>]) -> Vec<usize> {
+    const MAX_ITERATIONS: usize = 50;
+
+    let mut labels: Vec<usize> = (0..neighbors.len()).collect();
+
+    for _ in 0..MAX_ITERATIONS {
+        let mut changed = false;
+
+        for node in 0..neighbors.len() {
+            if neighbors[node].is_empty() {
+                continue;
+            }
+
+            let mut counts: HashMap<usize, usize> = HashMap::new();
+            for &neighbor in &neighbors[node] {
+                *counts.entry(labels[neighbor]).or_insert(0) += 1;
+            }
+
+            let best = counts
+                .into_iter()
+                .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(&a.0)))
+                .map(|(label, _)| label)
+                .unwrap_or(labels[node]);
+
+            if best != labels[node] {
+                labels[node] = best;
+                changed = true;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    labels
+}
+
+/// Group notes into clusters so the graph view can colour them
+///
+/// `algorithm` is `"louvain"` for modularity-style communities (computed by
+/// label propagation) or `"components"` for plain connected components.
+#[command]
+pub async fn get_graph_clusters(
+    vault_path: String,
+    algorithm: String,
+) -> Result<Vec<ClusterInfo>, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let graph_data = get_graph_data_with_cache(vault, None)?;
+    let (ids, adjacency) = directed_adjacency(&graph_data);
+    let neighbors = undirected_adjacency(&adjacency);
+
+    let labels = match algorithm.as_str() {
+        "louvain" => label_propagation(&neighbors),
+        "components" => connected_component_labels(&neighbors),
+        _ => {
+            return Err(format!(
+                "Unknown clustering algorithm '{}', expected 'louvain' or 'components'",
+                algorithm
+            ))
+        }
+    };
+
+    let names: HashMap<&str, &str> = graph_data
+        .nodes
+        .iter()
+        .map(|node| (node.id.as_str(), node.name.as_str()))
+        .collect();
+
+    let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (node, label) in labels.into_iter().enumerate() {
+        groups.entry(label).or_default().push(node);
+    }
+
+    // Largest clusters first, ties broken by first member for stable IDs
+    let mut groups: Vec<Vec<usize>> = groups.into_values().collect();
+    groups.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a[0].cmp(&b[0])));
+
+    let clusters = groups
+        .into_iter()
+        .enumerate()
+        .map(|(cluster_id, members)| {
+            let representative = members
+                .iter()
+                .copied()
+                .max_by(|a, b| {
+                    neighbors[*a]
+                        .len()
+                        .cmp(&neighbors[*b].len())
+                        .then_with(|| b.cmp(a))
+                })
+                .unwrap_or(members[0]);
+
+            ClusterInfo {
+                cluster_id,
+                representative_name: names
+                    .get(ids[representative].as_str())
+                    .map(|name| name.to_string())
+                    .unwrap_or_default(),
+                node_ids: members.into_iter().map(|i| ids[i].clone()).collect(),
+            }
+        })
+        .collect();
+
+    Ok(clusters)
+}
+
+// ============================================================================
+// Shortest Paths
+// ============================================================================
+
+/// Breadth-first search over the undirected link graph
+/// Returns the node indices from `from` to `to` (inclusive), if connected
+fn shortest_path(neighbors: &[Vec<usize>], from: usize, to: usize) -> Option<Vec<usize>> {
+    let mut previous: Vec<Option<usize>> = vec![None; neighbors.len()];
+    let mut visited = vec![false; neighbors.len()];
+    let mut queue = std::collections::VecDeque::new();
+
+    visited[from] = true;
+    queue.push_back(from);
+
+    while let Some(node) = queue.pop_front() {
+        if node == to {
+            let mut path = vec![to];
+            let mut current = to;
+            while let Some(prev) = previous[current] {
+                path.push(prev);
+                current = prev;
+            }
+            path.reverse();
+            return Some(path);
+        }
+
+        for &neighbor in &neighbors[node] {
+            if !visited[neighbor] {
+                visited[neighbor] = true;
+                previous[neighbor] = Some(node);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    None
+}
+
+/// Resolve both endpoints and run BFS, following links in either direction
+fn find_path_ids(
+    vault_path: &str,
+    from_note_id: &str,
+    to_note_id: &str,
+) -> Result<Option<Vec<String>>, String> {
+    let vault = Path::new(vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let graph_data = get_graph_data_with_cache(vault, None)?;
+    let (ids, adjacency) = directed_adjacency(&graph_data);
+    let neighbors = undirected_adjacency(&adjacency);
+
+    let from = ids
+        .binary_search_by(|id| id.as_str().cmp(from_note_id))
+        .map_err(|_| format!("Note '{}' not found in graph", from_note_id))?;
+    let to = ids
+        .binary_search_by(|id| id.as_str().cmp(to_note_id))
+        .map_err(|_| format!("Note '{}' not found in graph", to_note_id))?;
+
+    Ok(shortest_path(&neighbors, from, to)
+        .map(|path| path.into_iter().map(|i| ids[i].clone()).collect()))
+}
+
+/// Find the shortest chain of links between two notes
+/// Returns an empty list when the notes aren't connected
+#[command]
+pub async fn find_note_path(
+    vault_path: String,
+    from_note_id: String,
+    to_note_id: String,
+) -> Result<Vec<String>, String> {
+    Ok(find_path_ids(&vault_path, &from_note_id, &to_note_id)?.unwrap_or_default())
+}
+
+/// Number of link hops between two notes, or `None` when they aren't connected
+#[command]
+pub async fn get_note_distance(
+    vault_path: String,
+    from_note_id: String,
+    to_note_id: String,
+) -> Result<Option<usize>, String> {
+    Ok(find_path_ids(&vault_path, &from_note_id, &to_note_id)?.map(|path| path.len() - 1))
+}
+
+// ============================================================================
+// Graph Statistics
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphStatistics {
+    pub node_count: usize,
+    pub link_count: usize, // Distinct directed links, self-links excluded
+    pub density: f64,
+    pub average_degree: f64,
+    pub max_degree: usize,
+    pub most_connected_node: Option<String>,
+    pub isolated_node_count: usize,
+    pub strongly_connected_components: usize,
+    pub average_clustering_coefficient: f64,
+}
+
+/// Strongly connected components with Tarjan's algorithm
+/// Iterative so deep link chains can't overflow the stack
+fn strongly_connected_components(adjacency: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let n = adjacency.len();
+    let mut index = vec![usize::MAX; n];
+    let mut low_link = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut next_index = 0;
+    let mut components = Vec::new();
+
+    for root in 0..n {
+        if index[root] != usize::MAX {
+            continue;
+        }
+
+        // (node, position of the next neighbour to visit)
+        let mut call_stack = vec![(root, 0)];
+        index[root] = next_index;
+        low_link[root] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+
+        while let Some(&mut (node, ref mut next)) = call_stack.last_mut() {
+            if let Some(&neighbor) = adjacency[node].get(*next) {
+                *next += 1;
+                if index[neighbor] == usize::MAX {
+                    index[neighbor] = next_index;
+                    low_link[neighbor] = next_index;
+                    next_index += 1;
+                    stack.push(neighbor);
+                    on_stack[neighbor] = true;
+                    call_stack.push((neighbor, 0));
+                } else if on_stack[neighbor] {
+                    low_link[node] = low_link[node].min(index[neighbor]);
+                }
+                continue;
+            }
+
+            // All neighbours visited: close the component if this is its root
+            call_stack.pop();
+            if low_link[node] == index[node] {
+                let mut component = Vec::new();
+                while let Some(member) = stack.pop() {
+                    on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+            if let Some(&(parent, _)) = call_stack.last() {
+                low_link[parent] = low_link[parent].min(low_link[node]);
+            }
+        }
+    }
+
+    components
+}
+
+/// Fraction of a node's neighbour pairs that are linked to each other
+/// Nodes with fewer than two neighbours score 0
+fn clustering_coefficient(neighbors: &[Vec<usize>], node: usize) -> f64 {
+    let node_neighbors = &neighbors[node];
+    let k = node_neighbors.len();
+    if k < 2 {
+        return 0.0;
+    }
+
+    let neighbor_set: HashSet<usize> = node_neighbors.iter().copied().collect();
+    let mut links_between = 0;
+    for &a in node_neighbors {
+        links_between += neighbors[a]
+            .iter()
+            .filter(|b| **b > a && neighbor_set.contains(b))
+            .count();
+    }
+
+    links_between as f64 / (k * (k - 1) / 2) as f64
+}
+
+/// Size, density, degree, connectivity and clustering figures for the link graph
+///
+/// Density, degrees and clustering treat links as undirected; strongly
+/// connected components follow link direction.
+#[command]
+pub async fn get_graph_statistics(vault_path: String) -> Result<GraphStatistics, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let graph_data = get_graph_data_with_cache(vault, None)?;
+    let (ids, adjacency) = directed_adjacency(&graph_data);
+    let neighbors = undirected_adjacency(&adjacency);
+
+    let node_count = ids.len();
+    let link_count: usize = adjacency.iter().map(Vec::len).sum();
+    let undirected_edges: usize = neighbors.iter().map(Vec::len).sum::<usize>() / 2;
+
+    let density = if node_count > 1 {
+        2.0 * undirected_edges as f64 / (node_count * (node_count - 1)) as f64
+    } else {
+        0.0
+    };
+    let average_degree = if node_count > 0 {
+        2.0 * undirected_edges as f64 / node_count as f64
+    } else {
+        0.0
+    };
+
+    // Ids are sorted, so ties go to the alphabetically first note
+    let most_connected = (0..node_count)
+        .filter(|&i| !neighbors[i].is_empty())
+        .max_by(|&a, &b| neighbors[a].len().cmp(&neighbors[b].len()).then(b.cmp(&a)));
+
+    let average_clustering_coefficient = if node_count > 0 {
+        (0..node_count)
+            .map(|i| clustering_coefficient(&neighbors, i))
+            .sum::<f64>()
+            / node_count as f64
+    } else {
+        0.0
+    };
+
+    Ok(GraphStatistics {
+        node_count,
+        link_count,
+        density,
+        average_degree,
+        max_degree: most_connected.map(|i| neighbors[i].len()).unwrap_or(0),
+        most_connected_node: most_connected.map(|i| ids[i].clone()),
+        isolated_node_count: neighbors.iter().filter(|n| n.is_empty()).count(),
+        strongly_connected_components: strongly_connected_components(&adjacency).len(),
+        average_clustering_coefficient,
+    })
+}
+
+// ============================================================================
+// Link Cycles
+// ============================================================================
+
+const MAX_LINK_CYCLES: usize = 50;
+
+/// Enumerate simple cycles with Johnson's algorithm, stopping after `limit`
+///
+/// Cycles can't span components, so each non-trivial strongly connected
+/// component is searched on its own. Each cycle starts at its lowest node.
+fn simple_cycles(adjacency: &[Vec<usize>], limit: usize) -> Vec<Vec<usize>> {
+    let mut components = strongly_connected_components(adjacency);
+    for members in &mut components {
+        members.sort_unstable();
+    }
+    components.sort();
+
+    let mut cycles = Vec::new();
+    for members in components.iter().filter(|members| members.len() > 1) {
+        // Local indices keep each search proportional to the component size
+        let local: HashMap<usize, usize> = members
+            .iter()
+            .enumerate()
+            .map(|(i, &node)| (node, i))
+            .collect();
+        let local_adjacency: Vec<Vec<usize>> = members
+            .iter()
+            .map(|&node| {
+                adjacency[node]
+                    .iter()
+                    .filter_map(|target| local.get(target).copied())
+                    .collect()
+            })
+            .collect();
+
+        for cycle in component_cycles(&local_adjacency, limit - cycles.len()) {
+            cycles.push(cycle.into_iter().map(|i| members[i]).collect());
+        }
+        if cycles.len() >= limit {
+            break;
+        }
+    }
+
+    cycles
+}
+
+/// Johnson's circuit search within one strongly connected component
+/// Iterative so long cycles can't overflow the stack
+fn component_cycles(adjacency: &[Vec<usize>], limit: usize) -> Vec<Vec<usize>> {
+    let n = adjacency.len();
+    let mut cycles = Vec::new();
+    let mut blocked = vec![false; n];
+    let mut blocked_by: Vec<HashSet<usize>> = vec![HashSet::new(); n];
+
+    for start in 0..n {
+        // Cycles through lower nodes were all found in earlier rounds
+        let sub_adjacency: Vec<Vec<usize>> = (0..n)
+            .map(|v| {
+                if v < start {
+                    return Vec::new();
+                }
+                adjacency[v]
+                    .iter()
+                    .copied()
+                    .filter(|&w| w >= start)
+                    .collect()
+            })
+            .collect();
+        if sub_adjacency[start].is_empty() {
+            continue;
+        }
+
+        let Some(component) = strongly_connected_components(&sub_adjacency)
+            .into_iter()
+            .find(|members| members.contains(&start))
+        else {
+            continue;
+        };
+        if component.len() < 2 {
+            continue;
+        }
+        let mut in_component = vec![false; n];
+        for &member in &component {
+            in_component[member] = true;
+            blocked[member] = false;
+            blocked_by[member].clear();
+        }
+
+        // (node, position of the next neighbour to visit, found a cycle)
+        let mut path = vec![start];
+        let mut call_stack = vec![(start, 0, false)];
+        blocked[start] = true;
+
+        while let Some(&mut (node, ref mut next, ref mut found)) = call_stack.last_mut() {
+            let neighbors = &sub_adjacency[node];
+            if let Some(&neighbor) = neighbors.get(*next) {
+                *next += 1;
+                if !in_component[neighbor] {
+                    continue;
+                }
+                if neighbor == start {
+                    *found = true;
+                    cycles.push(path.clone());
+                    if cycles.len() >= limit {
+                        return cycles;
+                    }
+                } else if !blocked[neighbor] {
+                    blocked[neighbor] = true;
+                    path.push(neighbor);
+                    call_stack.push((neighbor, 0, false));
+                }
+                continue;
+            }
+
+            let found = *found;
+            if found {
+                // Unblock this node and everything waiting on it
+                let mut to_unblock = vec![node];
+                while let Some(u) = to_unblock.pop() {
+                    if blocked[u] {
+                        blocked[u] = false;
+                        to_unblock.extend(blocked_by[u].drain());
+                    }
+                }
+            } else {
+                for &neighbor in neighbors {
+                    if in_component[neighbor] {
+                        blocked_by[neighbor].insert(node);
+                    }
+                }
+            }
+
+            path.pop();
+            call_stack.pop();
+            if let Some(parent) = call_stack.last_mut() {
+                parent.2 |= found;
+            }
+        }
+    }
+
+    cycles
+}
+
+/// Find chains of wikilinks that lead back to where they started (A → B → C → A)
+/// Returns at most 50 cycles, each as an ordered list of note ids
+#[command]
+pub async fn find_link_cycles(vault_path: String) -> Result<Vec<Vec<String>>, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let graph_data = get_graph_data_with_cache(vault, None)?;
+    let (ids, adjacency) = directed_adjacency(&graph_data);
+
+    Ok(simple_cycles(&adjacency, MAX_LINK_CYCLES)
+        .into_iter()
+        .map(|cycle| cycle.into_iter().map(|i| ids[i].clone()).collect())
+        .collect())
+}
+
+// ============================================================================
+// Graph Export
+// ============================================================================
+
+fn escape_dot(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn graph_to_dot(graph_data: &GraphData) -> String {
+    // Nodes are keyed by id since names aren't unique across folders
+    let ids: HashSet<&str> = graph_data
+        .nodes
+        .iter()
+        .map(|node| node.id.as_str())
+        .collect();
+
+    let mut output = String::from("digraph {\n");
+
+    for node in &graph_data.nodes {
+        output.push_str(&format!(
+            "  \"{}\" [label=\"{}\"];\n",
+            escape_dot(&node.id),
+            escape_dot(&node.name)
+        ));
+    }
+
+    for link in &graph_data.links {
+        if ids.contains(link.source.as_str()) && ids.contains(link.target.as_str()) {
+            output.push_str(&format!(
+                "  \"{}\" -> \"{}\";\n",
+                escape_dot(&link.source),
+                escape_dot(&link.target)
+            ));
+        }
+    }
+
+    output.push_str("}\n");
+    output
+}
+
+fn graph_to_graphml(graph_data: &GraphData) -> String {
+    let mut output = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n  \
+         <key id=\"name\" for=\"node\" attr.name=\"name\" attr.type=\"string\"/>\n  \
+         <graph id=\"vault\" edgedefault=\"directed\">\n",
+    );
+
+    for node in &graph_data.nodes {
+        output.push_str(&format!(
+            "    <node id=\"{}\">\n      <data key=\"name\">{}</data>\n    </node>\n",
+            escape_xml(&node.id),
+            escape_xml(&node.name)
+        ));
+    }
+
+    for (i, link) in graph_data.links.iter().enumerate() {
+        output.push_str(&format!(
+            "    <edge id=\"e{}\" source=\"{}\" target=\"{}\"/>\n",
+            i,
+            escape_xml(&link.source),
+            escape_xml(&link.target)
+        ));
+    }
+
+    output.push_str("  </graph>\n</graphml>\n");
+    output
+}
+
+/// Export the link graph as Graphviz DOT, GraphML or D3-style JSON
+/// Returns the file content; the caller decides where to save it
+#[command]
+pub async fn export_graph(vault_path: String, format: String) -> Result<String, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let graph_data = get_graph_data_with_cache(vault, None)?;
+
+    match format.as_str() {
+        "dot" => Ok(graph_to_dot(&graph_data)),
+        "graphml" => Ok(graph_to_graphml(&graph_data)),
+        "json" => serde_json::to_string_pretty(&graph_data)
+            .map_err(|e| format!("Failed to serialize graph: {}", e)),
+        _ => Err(format!(
+            "Unknown export format '{}', expected 'dot', 'graphml' or 'json'",
+            format
+        )),
+    }
+}
+
+// ============================================================================
+// Broken Links
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrokenLink {
+    pub source_note: String, // Relative path of the note containing the link
+    pub link_text: String,
+    pub found_in_vault: Option<String>, // Name of another open vault that has the target
+    pub found_note_path: Option<String>,
+}
+
+/// List wikilinks whose target doesn't exist in the vault
+///
+/// Each broken link also reports whether another open vault has a matching
+/// note. `[[VaultName::Note]]` links only count as broken when the named
+/// vault doesn't contain the note. Missing blocks in notes that exist are
+/// reported by `transclusion::scan_broken_block_references`.
+#[command]
+pub async fn scan_broken_links(
+    registry: tauri::State<'_, crate::vault_registry::VaultRegistry>,
+    vault_path: String,
+) -> Result<Vec<BrokenLink>, String> {
+    use crate::tools::FuzzyIndex;
+    use crate::vault_registry::{registered_vaults, resolve_across_with, split_vault_link};
+
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let vaults = registered_vaults(&registry)?;
+    let wikilink_regex = regex::Regex::new(r"\[\[([^|\]]+)(?:\|([^\]]+))?\]\]").unwrap();
+
+    let mut notes = Vec::new();
+    crate::tools::collect_notes(vault, &mut notes, vault)?;
+
+    // Walk each vault once up front rather than once per unresolved link
+    let mut indexes: HashMap<&Path, FuzzyIndex> = HashMap::new();
+    for path in std::iter::once(vault).chain(vaults.iter().map(|(_, path)| path.as_path())) {
+        indexes
+            .entry(path)
+            .or_insert_with(|| FuzzyIndex::build(path));
+    }
+    let find = |path: &Path, note: &str| match indexes.get(path) {
+        Some(index) => index.find(note),
+        None => crate::tools::find_file_fuzzy(path, note),
+    };
+
+    let mut broken = Vec::new();
+    for note in &notes {
+        let Ok(content) = std::fs::read_to_string(vault.join(&note.path)) else {
+            continue;
+        };
+
+        for caps in wikilink_regex.captures_iter(&content) {
+            let link_text = caps[1].trim();
+            // Heading and block anchors don't affect which note is targeted
+            let target = link_text
+                .split(['#', '^'])
+                .next()
+                .unwrap_or(link_text)
+                .trim();
+            if target.is_empty() {
+                continue;
+            }
+
+            // The source vault is searched first, so a match elsewhere means
+            // the note only exists in another vault
+            let explicit = split_vault_link(target).0.is_some();
+            let resolved = resolve_across_with(&vaults, vault, target, &find);
+            match &resolved {
+                Some(_) if explicit => continue,
+                Some(found) if found.vault_path == vault_path => continue,
+                _ => {}
+            }
+
+            broken.push(BrokenLink {
+                source_note: note.path.clone(),
+                link_text: link_text.to_string(),
+                found_in_vault: resolved.as_ref().map(|r| r.vault_name.clone()),
+                found_note_path: resolved.map(|r| r.note_path),
+            });
+        }
+    }
+
+    broken.sort_by(|a, b| {
+        a.source_note
+            .cmp(&b.source_note)
+            .then_with(|| a.link_text.cmp(&b.link_text))
+    });
+
+    Ok(broken)
+}
+
+// ============================================================================
+// Graph Snapshots
+// ============================================================================
+
+const SNAPSHOTS_DIR: &str = ".moss/graph_snapshots";
+
+/// Running auto-snapshot tasks, keyed by vault path
+pub struct SnapshotScheduleState {
+    pub handles: std::sync::Mutex<HashMap<String, tokio::task::JoinHandle<()>>>,
+}
+
+impl SnapshotScheduleState {
+    pub fn new() -> Self {
+        Self {
+            handles: std::sync::Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Abort every snapshot schedule (called on app shutdown)
+    pub fn abort_all(&self) {
+        if let Ok(mut handles) = self.handles.lock() {
+            for (_, handle) in handles.drain() {
+                handle.abort();
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotMeta {
+    pub timestamp: u64,
+    pub node_count: usize,
+    pub link_count: usize,
+    pub path: String, // Relative to the vault
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphDiff {
+    pub added_nodes: Vec<String>,
+    pub removed_nodes: Vec<String>,
+    pub added_links: usize,
+    pub removed_links: usize,
+}
+
+/// Write the current graph to `.moss/graph_snapshots/{timestamp}.json`
+/// Node IDs are stored relative to the vault so snapshots survive moving it
+fn write_graph_snapshot(vault: &Path) -> Result<String, String> {
+    let mut graph_data = get_graph_data_with_cache(vault, None)?;
+    let relative = |id: &str| {
+        Path::new(id)
+            .strip_prefix(vault)
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_else(|_| id.to_string())
+    };
+    for node in &mut graph_data.nodes {
+        node.id = relative(&node.id);
+    }
+    for link in &mut graph_data.links {
+        link.source = relative(&link.source);
+        link.target = relative(&link.target);
+    }
+
+    let mut timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    // Names have one-second resolution; step past any snapshot taken in the
+    // same second rather than overwriting it
+    while vault
+        .join(SNAPSHOTS_DIR)
+        .join(format!("{}.json", timestamp))
+        .exists()
+    {
+        timestamp += 1;
+    }
+    let relative_path = format!("{}/{}.json", SNAPSHOTS_DIR, timestamp);
+    let path = vault.join(&relative_path);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create snapshots folder: {}", e))?;
+    }
+
+    let json = serde_json::to_string(&graph_data)
+        .map_err(|e| format!("Failed to serialize graph: {}", e))?;
+    std::fs::write(&path, json).map_err(|e| format!("Failed to write snapshot: {}", e))?;
+
+    Ok(relative_path)
+}
+
+fn load_graph_snapshot(vault: &Path, timestamp: u64) -> Result<GraphData, String> {
+    let path = vault
+        .join(SNAPSHOTS_DIR)
+        .join(format!("{}.json", timestamp));
+    let content =
+        std::fs::read_to_string(&path).map_err(|_| format!("Snapshot {} not found", timestamp))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse snapshot: {}", e))
+}
+
+/// Timestamps of saved snapshots, oldest first
+fn snapshot_timestamps(vault: &Path) -> Vec<u64> {
+    let mut timestamps: Vec<u64> = std::fs::read_dir(vault.join(SNAPSHOTS_DIR))
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|entry| {
+                    entry
+                        .file_name()
+                        .to_string_lossy()
+                        .strip_suffix(".json")?
+                        .parse()
+                        .ok()
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    timestamps.sort();
+    timestamps
+}
+
+/// Save a snapshot of the link graph for growth analytics
+/// Returns the snapshot's path relative to the vault
+#[command]
+pub async fn save_graph_snapshot(vault_path: String) -> Result<String, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    write_graph_snapshot(vault)
+}
+
+/// List saved graph snapshots, oldest first
+#[command]
+pub async fn list_graph_snapshots(vault_path: String) -> Result<Vec<SnapshotMeta>, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let mut snapshots = Vec::new();
+    for timestamp in snapshot_timestamps(vault) {
+        // Skip unreadable snapshots rather than failing the whole list
+        let Ok(graph_data) = load_graph_snapshot(vault, timestamp) else {
+            continue;
+        };
+        snapshots.push(SnapshotMeta {
+            timestamp,
+            node_count: graph_data.nodes.len(),
+            link_count: graph_data.links.len(),
+            path: format!("{}/{}.json", SNAPSHOTS_DIR, timestamp),
+        });
+    }
+
+    Ok(snapshots)
+}
+
+/// Nodes and links that changed between two snapshots (a -> b)
+#[command]
+pub async fn compare_graph_snapshots(
+    vault_path: String,
+    timestamp_a: u64,
+    timestamp_b: u64,
+) -> Result<GraphDiff, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let a = load_graph_snapshot(vault, timestamp_a)?;
+    let b = load_graph_snapshot(vault, timestamp_b)?;
+
+    let nodes_a: HashSet<&str> = a.nodes.iter().map(|n| n.id.as_str()).collect();
+    let nodes_b: HashSet<&str> = b.nodes.iter().map(|n| n.id.as_str()).collect();
+    let links_a: HashSet<(&str, &str)> = a
+        .links
+        .iter()
+        .map(|l| (l.source.as_str(), l.target.as_str()))
+        .collect();
+    let links_b: HashSet<(&str, &str)> = b
+        .links
+        .iter()
+        .map(|l| (l.source.as_str(), l.target.as_str()))
+        .collect();
+
+    let mut added_nodes: Vec<String> = nodes_b
+        .difference(&nodes_a)
+        .map(|id| id.to_string())
+        .collect();
+    let mut removed_nodes: Vec<String> = nodes_a
+        .difference(&nodes_b)
+        .map(|id| id.to_string())
+        .collect();
+    added_nodes.sort();
+    removed_nodes.sort();
+
+    Ok(GraphDiff {
+        added_nodes,
+        removed_nodes,
+        added_links: links_b.difference(&links_a).count(),
+        removed_links: links_a.difference(&links_b).count(),
+    })
+}
+
+/// Take a snapshot if the newest one is older than the configured interval
+fn snapshot_if_due(vault: &Path) {
+    let Ok(config) = crate::vault_config::load_config(vault) else {
+        return;
+    };
+    if config.graph_snapshot_interval_hours == 0 {
+        return;
+    }
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let interval = config.graph_snapshot_interval_hours as u64 * 60 * 60;
+    let due = snapshot_timestamps(vault)
+        .last()
+        .is_none_or(|latest| now.saturating_sub(*latest) >= interval);
+
+    if due {
+        if let Err(e) = write_graph_snapshot(vault) {
+            eprintln!("Scheduled graph snapshot failed: {}", e);
+        }
+    }
+}
+
+/// Start taking graph snapshots on the interval set in the vault config
+/// (`graph_snapshot_interval_hours`, 0 disables). The config is re-read on
+/// every check, so changing it takes effect without restarting the schedule.
+#[command]
+pub async fn start_graph_snapshot_schedule(
+    state: tauri::State<'_, SnapshotScheduleState>,
+    vault_path: String,
+) -> Result<(), String> {
+    let vault = std::path::PathBuf::from(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let handle = tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(60 * 60));
+        loop {
+            interval.tick().await;
+            // Building the graph reads every note, keep it off the async workers
+            let vault = vault.clone();
+            if let Err(e) = tokio::task::spawn_blocking(move || snapshot_if_due(&vault)).await {
+                eprintln!("Scheduled graph snapshot task failed: {}", e);
+            }
+        }
+    });
+
+    let mut schedules = state.handles.lock().map_err(|e| e.to_string())?;
+    if let Some(previous) = schedules.insert(vault_path, handle) {
+        previous.abort();
+    }
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+use crate::graph_analysis::BrokenLink;
+use crate::transclusion::BrokenBlockRef;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use tauri::command;
+
+// ============================================================================
+// Vault Health Check
+// ============================================================================
+
+const MIN_CONTENT_CHARS: usize = 10;
+const LARGE_FILE_BYTES: u64 = 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VaultHealthReport {
+    pub broken_links: Vec<BrokenLink>,
+    pub broken_block_refs: Vec<BrokenBlockRef>,
+    pub orphaned_notes: Vec<String>, // Notes with no links in or out
+    pub orphaned_attachments: Vec<String>, // Files no note references
+    pub empty_notes: Vec<String>,    // Less than 10 characters outside frontmatter
+    pub notes_missing_title: Vec<String>, // No `# Heading` matching the file name
+    pub duplicate_filenames: Vec<Vec<String>>, // Names differing only by case, per folder
+    pub large_files: Vec<(String, u64)>, // Files over 1 MB with their size in bytes
+    pub issues_count: usize,
+}
+
+/// H1 headings in a note, outside frontmatter and code blocks
+fn h1_headings(content: &str) -> Vec<String> {
+    let mut headings = Vec::new();
+    let mut in_code_block = false;
+
+    for line in crate::frontmatter::strip(content).lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+
+        if let Some(heading) = line.strip_prefix("# ") {
+            headings.push(heading.trim().trim_end_matches('#').trim_end().to_string());
+        }
+    }
+
+    headings
+}
+
+/// Run every vault check and collect the results into one report
+#[command]
+pub async fn check_vault_health(
+    registry: tauri::State<'_, crate::vault_registry::VaultRegistry>,
+    vault_path: String,
+) -> Result<VaultHealthReport, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let broken_links =
+        crate::graph_analysis::scan_broken_links(registry, vault_path.clone()).await?;
+    let broken_block_refs =
+        crate::transclusion::scan_broken_block_references(vault_path.clone()).await?;
+    let orphaned_notes: Vec<String> =
+        crate::graph_analysis::find_orphaned_notes(vault_path.clone(), None)
+            .await?
+            .into_iter()
+            .map(|note| note.path)
+            .collect();
+    let orphaned_attachments: Vec<String> =
+        crate::fs_extra::find_orphaned_attachments(vault_path.clone())
+            .await?
+            .into_iter()
+            .map(|attachment| attachment.path)
+            .collect();
+
+    let relative = |path: &Path| {
+        path.strip_prefix(vault)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .to_string()
+    };
+
+    let (note_paths, attachment_paths) = crate::fs_extra::collect_vault_files(vault);
+
+    let mut empty_notes = Vec::new();
+    let mut notes_missing_title = Vec::new();
+    for path in &note_paths {
+        // Encrypted notes can't be inspected without the password
+        if path.to_string_lossy().ends_with(".md.enc") {
+            continue;
+        }
+        let Ok(content) = fs::read_to_string(path) else {
+            continue;
+        };
+
+        if crate::frontmatter::strip(&content).trim().chars().count() < MIN_CONTENT_CHARS {
+            empty_notes.push(relative(path));
+        }
+
+        let stem = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        if !h1_headings(&content)
+            .iter()
+            .any(|heading| heading.eq_ignore_ascii_case(&stem))
+        {
+            notes_missing_title.push(relative(path));
+        }
+    }
+
+    // Group by folder and lowercased name; groups of two or more collide on
+    // case-insensitive file systems
+    let mut by_folded_name: HashMap<(String, String), Vec<String>> = HashMap::new();
+    let mut large_files = Vec::new();
+    for path in note_paths.iter().chain(attachment_paths.iter()) {
+        let folder = path
+            .parent()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        by_folded_name
+            .entry((folder, name))
+            .or_default()
+            .push(relative(path));
+
+        let size = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+        if size > LARGE_FILE_BYTES {
+            large_files.push((relative(path), size));
+        }
+    }
+
+    let mut duplicate_filenames: Vec<Vec<String>> = by_folded_name
+        .into_values()
+        .filter(|group| group.len() > 1)
+        .map(|mut group| {
+            group.sort();
+            group
+        })
+        .collect();
+    duplicate_filenames.sort();
+
+    empty_notes.sort();
+    notes_missing_title.sort();
+    // Largest first
+    large_files.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    let issues_count = broken_links.len()
+        + broken_block_refs.len()
+        + orphaned_notes.len()
+        + orphaned_attachments.len()
+        + empty_notes.len()
+        + notes_missing_title.len()
+        + duplicate_filenames.len()
+        + large_files.len();
+
+    Ok(VaultHealthReport {
+        broken_links,
+        broken_block_refs,
+        orphaned_notes,
+        orphaned_attachments,
+        empty_notes,
+        notes_missing_title,
+        duplicate_filenames,
+        large_files,
+        issues_count,
+    })
+}
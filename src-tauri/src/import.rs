@@ -0,0 +1,599 @@
+use ignore::WalkBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::command;
+
+// ============================================================================
+// Directory Import
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ImportReport {
+    pub imported: Vec<String>, // Vault-relative paths of the new notes
+    pub skipped: Vec<String>,  // Source-relative paths that already existed
+    pub failed: Vec<String>,   // "path: error"
+}
+
+/// Resolve (and create) the folder imported notes are written to
+/// Falls back to the vault's default note folder
+fn import_target_dir(vault: &Path, target_subfolder: Option<&str>) -> Result<PathBuf, String> {
+    let target_subfolder = target_subfolder
+        .map(str::to_string)
+        .or_else(|| crate::vault_config::default_note_folder(vault));
+    let target = match target_subfolder
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+    {
+        Some(subfolder) => {
+            let subfolder = Path::new(subfolder);
+            if subfolder.is_absolute()
+                || subfolder
+                    .components()
+                    .any(|c| matches!(c, std::path::Component::ParentDir))
+            {
+                return Err("Target folder must be inside the vault".to_string());
+            }
+            vault.join(subfolder)
+        }
+        None => vault.to_path_buf(),
+    };
+
+    fs::create_dir_all(&target).map_err(|e| format!("Failed to create target folder: {}", e))?;
+    Ok(target)
+}
+
+/// Write a new note named after `title` into the import target folder
+/// A number is appended when a note with that name already exists
+pub(crate) fn write_imported_note(
+    vault: &Path,
+    target_subfolder: Option<&str>,
+    title: &str,
+    content: &str,
+) -> Result<PathBuf, String> {
+    let target = import_target_dir(vault, target_subfolder)?;
+
+    let file_stem = match crate::tools::sanitize_file_stem(title) {
+        stem if stem.is_empty() => "Untitled".to_string(),
+        stem => stem,
+    };
+    let mut note_path = target.join(format!("{}.md", file_stem));
+    let mut counter = 1;
+    while note_path.exists() {
+        note_path = target.join(format!("{} {}.md", file_stem, counter));
+        counter += 1;
+    }
+
+    fs::write(&note_path, content).map_err(|e| format!("Failed to write note: {}", e))?;
+    Ok(note_path)
+}
+
+/// Commit every imported file in a single Mosaic commit
+fn commit_imported(vault: &Path, imported: &[String], message: &str) {
+    if imported.is_empty() {
+        return;
+    }
+
+    if let Some(repo) = crate::git_manager::open_repository(vault) {
+        let paths: Vec<PathBuf> = imported.iter().map(|p| vault.join(p)).collect();
+        let file_refs: Vec<&Path> = paths.iter().map(|p| p.as_path()).collect();
+        let _ = crate::git_manager::auto_commit_mosaic_changes(&repo, message, &file_refs);
+        // Silently fail if commit fails
+    }
+}
+
+/// Copy `.md` and `.txt` files from an external folder into the vault
+/// Folder structure is preserved and `.txt` files become `.md` notes
+#[command]
+pub async fn import_notes_from_directory(
+    vault_path: String,
+    source_dir: String,
+    target_subfolder: Option<String>,
+    overwrite: bool,
+) -> Result<ImportReport, String> {
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let source = Path::new(&source_dir);
+    if !source.exists() || !source.is_dir() {
+        return Err(format!("Source folder '{}' does not exist", source_dir));
+    }
+
+    let target = import_target_dir(vault, target_subfolder.as_deref())?;
+    let mut report = ImportReport::default();
+    // Destinations already written by this import
+    let mut claimed: HashSet<PathBuf> = HashSet::new();
+
+    for entry in WalkBuilder::new(source).git_ignore(false).build().flatten() {
+        let path = entry.path();
+        let is_importable = path
+            .extension()
+            .is_some_and(|ext| ext == "md" || ext == "txt");
+        if !path.is_file() || !is_importable {
+            continue;
+        }
+
+        let Ok(relative) = path.strip_prefix(source) else {
+            continue;
+        };
+        let mut destination = target.join(relative).with_extension("md");
+
+        // `foo.md` and `foo.txt` both become `foo.md`, so number the later one
+        if claimed.contains(&destination) {
+            let stem = destination
+                .file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let parent = destination.parent().unwrap_or(&target).to_path_buf();
+            let mut counter = 1;
+            while claimed.contains(&destination) || destination.exists() {
+                destination = parent.join(format!("{} {}.md", stem, counter));
+                counter += 1;
+            }
+        }
+
+        if destination.exists() && !overwrite {
+            report.skipped.push(relative.to_string_lossy().to_string());
+            continue;
+        }
+
+        let result = destination
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::copy(path, &destination));
+
+        match result {
+            Ok(_) => {
+                report.imported.push(
+                    destination
+                        .strip_prefix(vault)
+                        .unwrap_or(&destination)
+                        .to_string_lossy()
+                        .to_string(),
+                );
+                claimed.insert(destination);
+            }
+            Err(e) => report
+                .failed
+                .push(format!("{}: {}", relative.to_string_lossy(), e)),
+        }
+    }
+
+    let source_name = source
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| source_dir.clone());
+    commit_imported(
+        vault,
+        &report.imported,
+        &format!(
+            "Imported {} notes from {}",
+            report.imported.len(),
+            source_name
+        ),
+    );
+
+    Ok(report)
+}
+
+// ============================================================================
+// Evernote (ENEX) Import
+// ============================================================================
+
+#[derive(Debug, Default)]
+struct EnexResource {
+    data: String, // Base64, decoded on save
+    mime: String,
+    file_name: Option<String>,
+}
+
+#[derive(Debug, Default)]
+struct EnexNote {
+    title: String,
+    content: String, // ENML
+    tags: Vec<String>,
+    created: Option<String>, // e.g. 20230115T103000Z
+    resources: Vec<EnexResource>,
+}
+
+/// Parse the notes out of an ENEX export
+fn parse_enex(xml: &str) -> Result<Vec<EnexNote>, String> {
+    use quick_xml::events::Event;
+
+    let mut reader = quick_xml::Reader::from_str(xml);
+    let mut notes = Vec::new();
+    let mut stack: Vec<String> = Vec::new();
+    let mut note: Option<EnexNote> = None;
+    let mut resource: Option<EnexResource> = None;
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| format!("Invalid ENEX file: {}", e))?;
+
+        let text = match event {
+            Event::Start(ref e) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                match name.as_str() {
+                    "note" => note = Some(EnexNote::default()),
+                    "resource" => resource = Some(EnexResource::default()),
+                    _ => {}
+                }
+                stack.push(name);
+                continue;
+            }
+            Event::End(ref e) => {
+                stack.pop();
+                match e.name().as_ref() {
+                    b"note" => notes.extend(note.take()),
+                    b"resource" => {
+                        if let (Some(note), Some(resource)) = (note.as_mut(), resource.take()) {
+                            note.resources.push(resource);
+                        }
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+            Event::Text(e) => e
+                .unescape()
+                .map_err(|e| format!("Invalid ENEX file: {}", e))?
+                .to_string(),
+            Event::CData(e) => String::from_utf8_lossy(&e.into_inner()).to_string(),
+            Event::Eof => break,
+            _ => continue,
+        };
+
+        let Some(note) = note.as_mut() else {
+            continue;
+        };
+        match (stack.last().map(String::as_str), resource.as_mut()) {
+            (Some("data"), Some(resource)) => resource.data.push_str(&text),
+            (Some("mime"), Some(resource)) => resource.mime = text.trim().to_string(),
+            (Some("file-name"), Some(resource)) => resource.file_name = Some(text.trim().into()),
+            (_, Some(_)) => {}
+            (Some("title"), None) => note.title.push_str(&text),
+            (Some("content"), None) => note.content.push_str(&text),
+            (Some("tag"), None) => note.tags.push(text.trim().to_string()),
+            (Some("created"), None) => note.created = Some(text.trim().to_string()),
+            _ => {}
+        }
+    }
+
+    Ok(notes)
+}
+
+/// Convert an ENML body to Markdown
+/// `media_links` maps resource md5 hashes to the Markdown that embeds them
+fn enml_to_markdown(enml: &str, media_links: &std::collections::HashMap<String, String>) -> String {
+    let body_regex = regex::Regex::new(r"(?s)<en-note[^>]*>(.*)</en-note>").unwrap();
+    let body = body_regex
+        .captures(enml)
+        .and_then(|caps| caps.get(1))
+        .map_or(enml, |m| m.as_str());
+
+    // Evernote-specific elements become placeholders html2md leaves alone
+    let done_regex =
+        regex::Regex::new(r#"<en-todo[^>]*checked="true"[^>]*>(?:</en-todo>)?"#).unwrap();
+    let todo_regex = regex::Regex::new(r"<en-todo[^>]*>(?:</en-todo>)?").unwrap();
+    let media_regex =
+        regex::Regex::new(r#"<en-media[^>]*hash="([0-9a-fA-F]+)"[^>]*>(?:</en-media>)?"#).unwrap();
+
+    let body = done_regex.replace_all(body, "MOSSTODODONE");
+    let body = todo_regex.replace_all(&body, "MOSSTODOOPEN");
+    let body = media_regex.replace_all(&body, "MOSSMEDIA${1}MOSSMEDIAEND");
+
+    let markdown = html2md::parse_html(&body)
+        .replace("MOSSTODODONE", "- [x] ")
+        .replace("MOSSTODOOPEN", "- [ ] ");
+
+    let placeholder_regex = regex::Regex::new(r"MOSSMEDIA([0-9a-fA-F]+)MOSSMEDIAEND").unwrap();
+    placeholder_regex
+        .replace_all(&markdown, |caps: &regex::Captures| {
+            media_links
+                .get(&caps[1].to_lowercase())
+                .cloned()
+                .unwrap_or_default()
+        })
+        .trim()
+        .to_string()
+}
+
+/// Default file extension for an attachment without a file name
+fn extension_for_mime(mime: &str) -> &str {
+    match mime {
+        "image/png" => "png",
+        "image/jpeg" => "jpg",
+        "image/gif" => "gif",
+        "image/svg+xml" => "svg",
+        "application/pdf" => "pdf",
+        "audio/mpeg" => "mp3",
+        "audio/wav" => "wav",
+        _ => "bin",
+    }
+}
+
+/// Import every note from an Evernote export
+/// Attachments are saved to `assets/` and embedded where the note referenced them
+#[command]
+pub async fn import_enex(
+    vault_path: String,
+    enex_path: String,
+    target_folder: Option<String>,
+) -> Result<ImportReport, String> {
+    use base64::Engine;
+    use std::collections::HashMap;
+
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let xml = fs::read_to_string(&enex_path)
+        .map_err(|e| format!("Failed to read '{}': {}", enex_path, e))?;
+    let notes = parse_enex(&xml)?;
+    // Fail on a bad target folder before saving any attachments
+    import_target_dir(vault, target_folder.as_deref())?;
+
+    let mut report = ImportReport::default();
+    let mut created_files = Vec::new();
+
+    for note in notes {
+        let title = match note.title.trim() {
+            "" => "Untitled".to_string(),
+            title => title.to_string(),
+        };
+
+        // Save attachments first so the body can link to them
+        let mut media_links = HashMap::new();
+        let mut unreferenced = Vec::new();
+        for (index, resource) in note.resources.iter().enumerate() {
+            let cleaned: String = resource.data.split_whitespace().collect();
+            let data = match base64::engine::general_purpose::STANDARD.decode(cleaned) {
+                Ok(data) => data,
+                Err(e) => {
+                    report
+                        .failed
+                        .push(format!("{} (attachment {}): {}", title, index + 1, e));
+                    continue;
+                }
+            };
+
+            let hash = format!("{:x}", md5::compute(&data));
+            let file_name = resource.file_name.clone().unwrap_or_else(|| {
+                format!("{}.{}", &hash[..8], extension_for_mime(&resource.mime))
+            });
+
+            match crate::fs_extra::save_attachment(
+                vault_path.clone(),
+                "assets".to_string(),
+                file_name.clone(),
+                data,
+            )
+            .await
+            {
+                Ok(relative) => {
+                    let link = if resource.mime.starts_with("image/") {
+                        format!("![{}]({})", file_name, relative)
+                    } else {
+                        format!("[{}]({})", file_name, relative)
+                    };
+                    created_files.push(relative);
+                    unreferenced.push((hash.clone(), link.clone()));
+                    media_links.insert(hash, link);
+                }
+                Err(e) => report
+                    .failed
+                    .push(format!("{} ({}): {}", title, file_name, e)),
+            }
+        }
+
+        let mut body = enml_to_markdown(&note.content, &media_links);
+
+        // Attachments the body never pointed to still get linked at the end
+        unreferenced.retain(|(hash, _)| !note.content.to_lowercase().contains(hash.as_str()));
+        if !unreferenced.is_empty() {
+            body.push_str("\n\n");
+            let links: Vec<&str> = unreferenced.iter().map(|(_, l)| l.as_str()).collect();
+            body.push_str(&links.join("\n"));
+        }
+
+        let mut mapping = serde_yaml::Mapping::new();
+        if !note.tags.is_empty() {
+            mapping.insert(
+                "tags".into(),
+                serde_yaml::Value::Sequence(note.tags.iter().map(|t| t.as_str().into()).collect()),
+            );
+        }
+        if let Some(created) = note
+            .created
+            .as_deref()
+            .and_then(|c| chrono::NaiveDateTime::parse_from_str(c, "%Y%m%dT%H%M%SZ").ok())
+        {
+            mapping.insert(
+                "created".into(),
+                created.format("%Y-%m-%d").to_string().into(),
+            );
+        }
+
+        let content = crate::frontmatter::compose(&mapping, &format!("# {}\n\n{}\n", title, body))?;
+
+        match write_imported_note(vault, target_folder.as_deref(), &title, &content) {
+            Ok(note_path) => {
+                let relative = note_path
+                    .strip_prefix(vault)
+                    .unwrap_or(&note_path)
+                    .to_string_lossy()
+                    .to_string();
+                created_files.push(relative.clone());
+                report.imported.push(relative);
+            }
+            Err(e) => report.failed.push(format!("{}: {}", title, e)),
+        }
+    }
+
+    commit_imported(vault, &created_files, "Imported from Evernote");
+
+    Ok(report)
+}
+
+// ============================================================================
+// PDF Import
+// ============================================================================
+
+/// Extracted text beyond this many characters is dropped
+const MAX_PDF_TEXT_CHARS: usize = 50_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PdfMetadata {
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub subject: Option<String>,
+    pub creator: Option<String>,
+    pub page_count: usize,
+}
+
+fn load_pdf(pdf_path: &str) -> Result<(lopdf::Document, Vec<u8>), String> {
+    let data = fs::read(pdf_path).map_err(|e| format!("Failed to read '{}': {}", pdf_path, e))?;
+    let document =
+        lopdf::Document::load_mem(&data).map_err(|e| format!("Invalid PDF file: {}", e))?;
+    Ok((document, data))
+}
+
+/// A non-empty text entry from the document's Info dictionary
+fn pdf_info_field(document: &lopdf::Document, key: &[u8]) -> Option<String> {
+    let info = document
+        .trailer
+        .get(b"Info")
+        .and_then(|info| match info {
+            lopdf::Object::Reference(id) => document.get_dictionary(*id),
+            other => other.as_dict(),
+        })
+        .ok()?;
+    let value = lopdf::decode_text_string(info.get(key).ok()?).ok()?;
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+fn pdf_metadata(document: &lopdf::Document) -> PdfMetadata {
+    PdfMetadata {
+        title: pdf_info_field(document, b"Title"),
+        author: pdf_info_field(document, b"Author"),
+        subject: pdf_info_field(document, b"Subject"),
+        creator: pdf_info_field(document, b"Creator"),
+        page_count: document.get_pages().len(),
+    }
+}
+
+/// Metadata, extracted text (capped at `MAX_PDF_TEXT_CHARS`) and raw bytes of a PDF
+/// Parsing is CPU-bound, so callers run this on the blocking pool
+fn read_pdf(pdf_path: &str) -> Result<(PdfMetadata, String, Vec<u8>), String> {
+    let (document, data) = load_pdf(pdf_path)?;
+    let metadata = pdf_metadata(&document);
+
+    // Pages without extractable text (e.g. scans) are left empty
+    let pages: Vec<String> = document
+        .get_pages()
+        .keys()
+        .map(|&page| {
+            document
+                .extract_text(&[page])
+                .map(|text| text.trim().to_string())
+                .unwrap_or_default()
+        })
+        .collect();
+    let mut text = pages.join("\n\n---\n\n");
+    if let Some((cut, _)) = text.char_indices().nth(MAX_PDF_TEXT_CHARS) {
+        text.truncate(cut);
+        text.push_str(&format!(
+            "\n\n*Text truncated at {} characters.*",
+            MAX_PDF_TEXT_CHARS
+        ));
+    }
+
+    Ok((metadata, text, data))
+}
+
+/// Read the title, author and page count of a PDF
+#[command]
+pub async fn extract_pdf_metadata(pdf_path: String) -> Result<PdfMetadata, String> {
+    tokio::task::spawn_blocking(move || {
+        let (document, _) = load_pdf(&pdf_path)?;
+        Ok(pdf_metadata(&document))
+    })
+    .await
+    .map_err(|e| format!("PDF parsing failed: {}", e))?
+}
+
+/// Import a PDF's text as a new note
+/// The PDF itself is saved to `assets/` and linked from the note
+#[command]
+pub async fn import_pdf_as_note(
+    vault_path: String,
+    pdf_path: String,
+    target_folder: Option<String>,
+) -> Result<String, String> {
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let read_path = pdf_path.clone();
+    let (metadata, text, data) = tokio::task::spawn_blocking(move || read_pdf(&read_path))
+        .await
+        .map_err(|e| format!("PDF parsing failed: {}", e))??;
+
+    let source_path = Path::new(&pdf_path);
+    let file_name = source_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "document.pdf".to_string());
+    let title = metadata.title.clone().unwrap_or_else(|| {
+        source_path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| "Untitled".to_string())
+    });
+
+    let attachment = crate::fs_extra::save_attachment(
+        vault_path.clone(),
+        "assets".to_string(),
+        file_name.clone(),
+        data,
+    )
+    .await?;
+
+    let mut mapping = serde_yaml::Mapping::new();
+    mapping.insert("source".into(), file_name.clone().into());
+    mapping.insert("pages".into(), (metadata.page_count as u64).into());
+    mapping.insert(
+        "tags".into(),
+        serde_yaml::Value::Sequence(vec!["pdf".into()]),
+    );
+
+    let content = crate::frontmatter::compose(
+        &mapping,
+        &format!(
+            "# {}\n\n[{}]({})\n\n{}\n",
+            title, file_name, attachment, text
+        ),
+    )?;
+    let note_path = write_imported_note(vault, target_folder.as_deref(), &title, &content)?;
+    let relative = note_path
+        .strip_prefix(vault)
+        .unwrap_or(&note_path)
+        .to_string_lossy()
+        .to_string();
+
+    commit_imported(
+        vault,
+        &[attachment, relative.clone()],
+        &format!("Imported PDF: {}", title),
+    );
+
+    Ok(relative)
+}
@@ -1,15 +1,418 @@
 use crate::ai::gemini::GeminiProvider;
-use crate::ai::AIProvider;
+use crate::ai::{AIProvider, RateLimiter};
+use crate::bm25::BM25Index;
 use crate::vector_store::{DocumentChunk, VectorStore};
 use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tauri::Emitter;
 use uuid::Uuid;
 
-const CHUNK_SIZE: usize = 1000; // Characters per chunk
 const VECTOR_STORE_PATH: &str = ".moss/vector_store.db";
-const CONCURRENCY_LIMIT: usize = 10;
+const INDEX_META_PATH: &str = ".moss/index_meta.json";
+const INDEX_CONFIG_PATH: &str = ".moss/index_config.json";
+const EMBEDDING_PROVIDER: &str = "gemini";
+
+/// A file with its chunks, or the error from reading it
+type PreparedFile = (PathBuf, Result<Vec<DocumentChunk>, String>);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexingConfig {
+    pub chunk_size: usize,        // Characters per chunk
+    pub chunk_overlap: usize,     // Characters repeated from the end of the previous chunk
+    pub min_chunk_length: usize,  // Shorter chunks are not embedded
+    pub concurrency_limit: usize, // Files embedded in parallel
+    #[serde(default)]
+    pub max_chunks: Option<usize>, // Only the most recently modified notes that fit are indexed
+}
+
+impl Default for IndexingConfig {
+    fn default() -> Self {
+        Self {
+            chunk_size: 1000,
+            chunk_overlap: 100,
+            min_chunk_length: 50,
+            concurrency_limit: 10,
+            max_chunks: None,
+        }
+    }
+}
+
+impl IndexingConfig {
+    fn validate(&self) -> Result<(), String> {
+        if self.chunk_size == 0 {
+            return Err("Chunk size must be greater than zero".to_string());
+        }
+        if self.chunk_overlap >= self.chunk_size {
+            return Err("Chunk overlap must be smaller than the chunk size".to_string());
+        }
+        if self.concurrency_limit == 0 {
+            return Err("Concurrency limit must be at least 1".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Load the vault's indexing settings, falling back to the defaults
+pub fn load_indexing_config(vault_path: &Path) -> Result<IndexingConfig, String> {
+    let path = vault_path.join(INDEX_CONFIG_PATH);
+    if !path.exists() {
+        return Ok(IndexingConfig::default());
+    }
+
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read indexing config: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse indexing config: {}", e))
+}
+
+/// Chunk, file and size statistics for the vault's vector store
+pub fn get_store_stats(vault_path: &Path) -> Result<crate::vector_store::StoreStats, String> {
+    let store = VectorStore::open(&vault_path.join(VECTOR_STORE_PATH))?;
+    crate::vector_store::get_store_stats(&store)
+}
+
+/// Cap the number of stored chunks (0 removes the limit)
+pub fn set_max_store_size(vault_path: &Path, max_chunks: usize) -> Result<(), String> {
+    let mut config = load_indexing_config(vault_path)?;
+    config.max_chunks = (max_chunks > 0).then_some(max_chunks);
+    save_indexing_config(vault_path, &config)
+}
+
+/// Keep the most recently modified files whose chunks fit in `max_chunks`,
+/// so files past the budget are never embedded. A file too large for what's
+/// left of the budget is skipped, smaller older files can still fit
+fn fit_to_budget(prepared: &mut Vec<PreparedFile>, max_chunks: usize) {
+    let modified = |path: &Path| {
+        std::fs::metadata(path)
+            .and_then(|m| m.modified())
+            .unwrap_or(std::time::UNIX_EPOCH)
+    };
+    prepared.sort_by_cached_key(|(path, _)| std::cmp::Reverse(modified(path)));
+
+    let mut total = 0;
+    prepared.retain(|(_, chunks)| {
+        let chunk_count = chunks.as_ref().map_or(0, Vec::len);
+        if total + chunk_count > max_chunks {
+            return false;
+        }
+        total += chunk_count;
+        true
+    });
+}
+
+/// Drop the least recently modified files until the store fits in `max_chunks`
+/// Returns the number of chunks removed
+fn enforce_max_chunks(
+    store: &mut VectorStore,
+    keyword_index: &BM25Index,
+    max_chunks: usize,
+) -> Result<usize, String> {
+    let files = store.files_by_recency()?;
+
+    let mut total = 0;
+    let mut keep_count = 0;
+    for (_, chunk_count) in &files {
+        if total + chunk_count > max_chunks {
+            break;
+        }
+        total += chunk_count;
+        keep_count += 1;
+    }
+
+    if keep_count == files.len() {
+        return Ok(0);
+    }
+    for (file_path, _) in &files[keep_count..] {
+        keyword_index.remove_file(file_path)?;
+    }
+    crate::vector_store::prune_oldest_files(store, keep_count)
+}
+
+pub fn save_indexing_config(vault_path: &Path, config: &IndexingConfig) -> Result<(), String> {
+    config.validate()?;
+
+    let path = vault_path.join(INDEX_CONFIG_PATH);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create .moss dir: {}", e))?;
+    }
+
+    let json = serde_json::to_string_pretty(config)
+        .map_err(|e| format!("Failed to serialize indexing config: {}", e))?;
+    std::fs::write(&path, json).map_err(|e| format!("Failed to write indexing config: {}", e))
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IndexMetadata {
+    #[serde(default)]
+    pub total_chunks: usize,
+    pub embedding_dimension: Option<usize>,
+    pub provider: Option<String>,
+    pub last_indexed: Option<u64>, // Unix timestamp
+}
+
+/// Read the metadata written by the last indexing run
+pub fn load_index_metadata(vault_path: &Path) -> Option<IndexMetadata> {
+    let content = std::fs::read_to_string(vault_path.join(INDEX_META_PATH)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Describe the vault's index, with the chunk count read live from the store
+pub fn get_index_metadata(vault_path: &Path) -> Result<IndexMetadata, String> {
+    let mut metadata = load_index_metadata(vault_path).unwrap_or_default();
+
+    let store_path = vault_path.join(VECTOR_STORE_PATH);
+    if store_path.exists() {
+        let store = VectorStore::open(&store_path)?;
+        metadata.total_chunks = store.count_chunks()?;
+        metadata.embedding_dimension = store
+            .check_vector_dimension_consistency()?
+            .or(metadata.embedding_dimension);
+    } else {
+        metadata.total_chunks = 0;
+    }
+
+    Ok(metadata)
+}
+
+// Progress event payloads
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexingStarted {
+    pub total_files: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexingProgress {
+    pub processed: usize,
+    pub total: usize,
+    pub current_file: String, // Relative path
+    pub success: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexingComplete {
+    pub indexed_chunks: usize,
+    pub failed_files: usize,
+    pub duration_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RebuildStarted {
+    pub estimated_files: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RebuildProgress {
+    pub processed: usize,
+    pub total: usize,
+    pub current_file: String, // Relative path
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RebuildComplete {
+    pub total_chunks: usize,
+    pub duration_ms: u64,
+}
+
+/// Which family of events an indexing run reports progress with
+#[derive(Debug, Clone, Copy)]
+enum ProgressEvents {
+    Indexing, // indexing-*
+    Rebuild,  // search-index-rebuild-*
+}
+
+impl ProgressEvents {
+    fn started(self, app_handle: &tauri::AppHandle, total: usize) {
+        let _ = match self {
+            Self::Indexing => {
+                app_handle.emit("indexing-started", IndexingStarted { total_files: total })
+            }
+            Self::Rebuild => app_handle.emit(
+                "search-index-rebuild-started",
+                RebuildStarted {
+                    estimated_files: total,
+                },
+            ),
+        };
+    }
+
+    fn progress(
+        self,
+        app_handle: &tauri::AppHandle,
+        processed: usize,
+        total: usize,
+        current_file: String,
+        success: bool,
+    ) {
+        let _ = match self {
+            Self::Indexing => app_handle.emit(
+                "indexing-progress",
+                IndexingProgress {
+                    processed,
+                    total,
+                    current_file,
+                    success,
+                },
+            ),
+            Self::Rebuild => app_handle.emit(
+                "search-index-rebuild-progress",
+                RebuildProgress {
+                    processed,
+                    total,
+                    current_file,
+                },
+            ),
+        };
+    }
+
+    fn complete(
+        self,
+        app_handle: &tauri::AppHandle,
+        total_chunks: usize,
+        failed_files: usize,
+        duration_ms: u64,
+    ) {
+        let _ = match self {
+            Self::Indexing => app_handle.emit(
+                "indexing-complete",
+                IndexingComplete {
+                    indexed_chunks: total_chunks,
+                    failed_files,
+                    duration_ms,
+                },
+            ),
+            Self::Rebuild => app_handle.emit(
+                "search-index-rebuild-complete",
+                RebuildComplete {
+                    total_chunks,
+                    duration_ms,
+                },
+            ),
+        };
+    }
+}
+
+/// Set while a full index rebuild runs, so only one can run at a time
+static REBUILD_RUNNING: Mutex<bool> = Mutex::new(false);
+
+/// Clears `REBUILD_RUNNING` when dropped, even if the rebuild task panics or is aborted
+struct RebuildGuard;
+
+impl Drop for RebuildGuard {
+    fn drop(&mut self) {
+        let mut running = REBUILD_RUNNING
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        *running = false;
+    }
+}
+
+pub async fn index_vault(
+    vault_path: &Path,
+    api_key: &str,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    config: &IndexingConfig,
+) -> Result<(), String> {
+    index_vault_inner(vault_path, api_key, rate_limiter, config, None).await
+}
+
+/// Same as `index_vault`, emitting `indexing-started`, `indexing-progress`
+/// and `indexing-complete` events as it goes
+pub async fn index_vault_with_progress(
+    vault_path: &Path,
+    api_key: &str,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    config: &IndexingConfig,
+    app_handle: &tauri::AppHandle,
+) -> Result<(), String> {
+    index_vault_inner(
+        vault_path,
+        api_key,
+        rate_limiter,
+        config,
+        Some((app_handle, ProgressEvents::Indexing)),
+    )
+    .await
+}
+
+/// Delete the vault's keyword and embedding indexes and build them from scratch
+/// in a background task, reporting `search-index-rebuild-*` events
+/// Fails straight away if a rebuild is already running
+pub fn rebuild_index_in_background(
+    vault_path: PathBuf,
+    api_key: String,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    config: IndexingConfig,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    config.validate()?;
+    {
+        let mut running = REBUILD_RUNNING
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if *running {
+            return Err("A search index rebuild is already running".to_string());
+        }
+        *running = true;
+    }
+    let guard = RebuildGuard;
+
+    tokio::spawn(async move {
+        let _guard = guard;
+        let result = rebuild_index(&vault_path, &api_key, rate_limiter, &config, &app_handle).await;
+        if let Err(e) = result {
+            let _ = app_handle.emit("search-index-rebuild-error", e);
+        }
+    });
+
+    Ok(())
+}
+
+async fn rebuild_index(
+    vault_path: &Path,
+    api_key: &str,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    config: &IndexingConfig,
+    app_handle: &tauri::AppHandle,
+) -> Result<(), String> {
+    // Remove the database (with its SQLite journals) so no stale tables survive
+    let store_path = vault_path.join(VECTOR_STORE_PATH);
+    for suffix in ["", "-wal", "-shm", "-journal"] {
+        let path = PathBuf::from(format!("{}{}", store_path.to_string_lossy(), suffix));
+        if path.exists() {
+            std::fs::remove_file(&path)
+                .map_err(|e| format!("Failed to delete search index: {}", e))?;
+        }
+    }
+    let meta_path = vault_path.join(INDEX_META_PATH);
+    if meta_path.exists() {
+        std::fs::remove_file(&meta_path)
+            .map_err(|e| format!("Failed to delete index metadata: {}", e))?;
+    }
+
+    index_vault_inner(
+        vault_path,
+        api_key,
+        rate_limiter,
+        config,
+        Some((app_handle, ProgressEvents::Rebuild)),
+    )
+    .await
+}
+
+async fn index_vault_inner(
+    vault_path: &Path,
+    api_key: &str,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    config: &IndexingConfig,
+    events: Option<(&tauri::AppHandle, ProgressEvents)>,
+) -> Result<(), String> {
+    config.validate()?;
+    let started = Instant::now();
 
-pub async fn index_vault(vault_path: &Path, api_key: &str) -> Result<(), String> {
     // Open SQLite store
     let store_path = vault_path.join(VECTOR_STORE_PATH);
     let mut store = VectorStore::open(&store_path)?;
@@ -17,34 +420,115 @@ pub async fn index_vault(vault_path: &Path, api_key: &str) -> Result<(), String>
     // Clear existing data (full re-index strategy for now)
     // In a future optimization, we could do incremental updates by checking file mtimes
     store.clear()?;
+    let mut keyword_index = BM25Index::open(&store_path)?;
+    keyword_index.clear()?;
 
     // Create embedding provider
-    let provider = GeminiProvider::new(api_key.to_string());
+    let provider = GeminiProvider::new(api_key.to_string()).with_rate_limiter(rate_limiter);
 
     // Collect all files first (to avoid holding open directory handles)
     let files = collect_files(vault_path).await?;
 
-    // Process files concurrently
-    let results = stream::iter(files)
-        .map(|path| {
+    // Read and chunk everything up front so keyword search works before embeddings finish
+    let mut prepared: Vec<PreparedFile> = Vec::with_capacity(files.len());
+    for path in files {
+        let chunks = prepare_file(&path, vault_path, config).await;
+        prepared.push((path, chunks));
+    }
+    if let Some(max_chunks) = config.max_chunks {
+        fit_to_budget(&mut prepared, max_chunks);
+    }
+    let total = prepared.len();
+    if let Some((app_handle, events)) = events {
+        events.started(app_handle, total);
+    }
+    let keyword_chunks: Vec<DocumentChunk> = prepared
+        .iter()
+        .filter_map(|(_, chunks)| chunks.as_ref().ok())
+        .flatten()
+        .cloned()
+        .collect();
+    keyword_index.add_batch(&keyword_chunks)?;
+
+    // Embed files concurrently
+    let mut results = stream::iter(prepared)
+        .map(|(path, chunks)| {
             let provider = &provider;
-            let vault_path = vault_path.to_path_buf(); // Clone for closure
-            async move { process_file(&path, &vault_path, provider).await }
+            async move {
+                let result = match chunks {
+                    Ok(chunks) => embed_chunks(chunks, provider).await,
+                    Err(e) => Err(e),
+                };
+                (path, result)
+            }
         })
-        .buffer_unordered(CONCURRENCY_LIMIT)
-        .collect::<Vec<Result<Vec<DocumentChunk>, String>>>()
-        .await;
+        .buffer_unordered(config.concurrency_limit);
 
     // Aggregate results and batch insert
-    for result in results {
+    let mut processed = 0;
+    let mut failed_files = 0;
+    while let Some((path, result)) = results.next().await {
+        processed += 1;
+        let success = result.is_ok();
+
         match result {
             Ok(chunks) => {
                 if !chunks.is_empty() {
                     store.add_batch(chunks)?;
                 }
+                if let Ok(relative) = path.strip_prefix(vault_path) {
+                    let mtime = std::fs::metadata(&path)
+                        .and_then(|m| m.modified())
+                        .ok()
+                        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                        .map(|d| d.as_secs())
+                        .unwrap_or(0);
+                    store.record_file_indexed(&relative.to_string_lossy(), mtime)?;
+                }
+            }
+            Err(e) => {
+                failed_files += 1;
+                eprintln!("Failed to index file: {}", e);
             }
-            Err(e) => eprintln!("Failed to index file: {}", e),
         }
+
+        if let Some((app_handle, events)) = events {
+            let current_file = path
+                .strip_prefix(vault_path)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .to_string();
+            events.progress(app_handle, processed, total, current_file, success);
+        }
+    }
+
+    // The store may hold more than this run embedded, evict the oldest files
+    if let Some(max_chunks) = config.max_chunks {
+        enforce_max_chunks(&mut store, &keyword_index, max_chunks)?;
+    }
+
+    // Record which provider produced the embeddings so searches can detect mismatches
+    let metadata = IndexMetadata {
+        total_chunks: store.count_chunks()?,
+        embedding_dimension: store.check_vector_dimension_consistency()?,
+        provider: Some(EMBEDDING_PROVIDER.to_string()),
+        last_indexed: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .ok(),
+    };
+    let json = serde_json::to_string_pretty(&metadata)
+        .map_err(|e| format!("Failed to serialize index metadata: {}", e))?;
+    std::fs::write(vault_path.join(INDEX_META_PATH), json)
+        .map_err(|e| format!("Failed to write index metadata: {}", e))?;
+
+    if let Some((app_handle, events)) = events {
+        events.complete(
+            app_handle,
+            metadata.total_chunks,
+            failed_files,
+            started.elapsed().as_millis() as u64,
+        );
     }
 
     Ok(())
@@ -79,10 +563,11 @@ async fn collect_files(dir: &Path) -> Result<Vec<PathBuf>, String> {
     Ok(files)
 }
 
-async fn process_file(
+/// Read a note and split it into chunks (without embeddings yet)
+async fn prepare_file(
     file_path: &Path,
     vault_path: &Path,
-    provider: &GeminiProvider,
+    config: &IndexingConfig,
 ) -> Result<Vec<DocumentChunk>, String> {
     let content = tokio::fs::read_to_string(file_path).await.map_err(|_| {
         format!(
@@ -98,58 +583,91 @@ async fn process_file(
         .to_string_lossy()
         .to_string();
 
-    // Split content into chunks
-    let chunks_text = chunk_text(&content, CHUNK_SIZE);
-    let mut chunks = Vec::new();
-
-    for chunk_text in chunks_text {
-        // Skip very small chunks
-        if chunk_text.trim().len() < 50 {
-            continue;
-        }
-
-        // Generate embedding
-        let vector = provider.get_embedding(&chunk_text).await?;
+    // Split content into chunks, skipping very small ones
+    Ok(
+        chunk_text(&content, config.chunk_size, config.chunk_overlap)
+            .into_iter()
+            .filter(|chunk_text| chunk_text.trim().len() >= config.min_chunk_length)
+            .map(|chunk_text| DocumentChunk {
+                id: Uuid::new_v4().to_string(),
+                file_path: relative_path.clone(), // Store relative path
+                content: chunk_text,
+                vector: Vec::new(),
+            })
+            .collect(),
+    )
+}
 
-        let chunk = DocumentChunk {
-            id: Uuid::new_v4().to_string(),
-            file_path: relative_path.clone(), // Store relative path
-            content: chunk_text,
-            vector,
-        };
-        chunks.push(chunk);
+/// Generate an embedding for each of a file's chunks
+async fn embed_chunks(
+    mut chunks: Vec<DocumentChunk>,
+    provider: &GeminiProvider,
+) -> Result<Vec<DocumentChunk>, String> {
+    for chunk in &mut chunks {
+        chunk.vector = provider.get_embedding(&chunk.content).await?;
     }
-
     Ok(chunks)
 }
 
-fn chunk_text(text: &str, max_chunk_size: usize) -> Vec<String> {
+/// Last `overlap` characters of a chunk, cut on a char boundary
+fn overlap_tail(chunk: &str, overlap: usize) -> &str {
+    if overlap == 0 {
+        return "";
+    }
+    let start = chunk
+        .char_indices()
+        .rev()
+        .nth(overlap - 1)
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+    &chunk[start..]
+}
+
+/// Split text into chunks of roughly `max_chunk_size` characters, on paragraph
+/// boundaries where possible. Each chunk starts with the last `overlap`
+/// characters of the previous one so context isn't lost at the seams.
+fn chunk_text(text: &str, max_chunk_size: usize, overlap: usize) -> Vec<String> {
     let mut chunks = Vec::new();
 
     // First, try to split by paragraphs
     let paragraphs: Vec<&str> = text.split("\n\n").collect();
     let mut current_chunk = String::new();
+    // Length of the overlap carried into `current_chunk` (not new content)
+    let mut carried = 0;
 
     for paragraph in paragraphs {
-        if current_chunk.len() + paragraph.len() > max_chunk_size && !current_chunk.is_empty() {
-            // Save current chunk and start a new one
-            chunks.push(current_chunk.clone());
-            current_chunk.clear();
+        if current_chunk.len() + paragraph.len() > max_chunk_size && current_chunk.len() > carried {
+            // Save current chunk and start the next one with its tail
+            let tail = overlap_tail(&current_chunk, overlap).to_string();
+            chunks.push(std::mem::take(&mut current_chunk));
+            carried = tail.len();
+            current_chunk = tail;
         }
 
         if paragraph.len() > max_chunk_size {
             // If a single paragraph is too large, split it by sentences or fixed size
-            if !current_chunk.is_empty() {
+            if current_chunk.len() > carried {
                 chunks.push(current_chunk.clone());
-                current_chunk.clear();
             }
+            current_chunk.clear();
+            carried = 0;
 
-            // Split large paragraph into fixed-size chunks
+            // Split large paragraph into fixed-size windows that overlap
+            let boundaries: Vec<usize> = paragraph
+                .char_indices()
+                .map(|(i, _)| i)
+                .chain(std::iter::once(paragraph.len()))
+                .collect();
+            let char_count = boundaries.len() - 1;
+            let step = max_chunk_size - overlap.min(max_chunk_size - 1);
             let mut start = 0;
-            while start < paragraph.len() {
-                let end = (start + max_chunk_size).min(paragraph.len());
-                chunks.push(paragraph[start..end].to_string());
-                start = end;
+            while start < char_count {
+                let end = (start + max_chunk_size).min(char_count);
+                chunks.push(paragraph[boundaries[start]..boundaries[end]].to_string());
+                if end == char_count {
+                    break;
+                }
+                start += step;
             }
         } else {
             if !current_chunk.is_empty() {
@@ -159,7 +677,7 @@ fn chunk_text(text: &str, max_chunk_size: usize) -> Vec<String> {
         }
     }
 
-    if !current_chunk.is_empty() {
+    if current_chunk.len() > carried {
         chunks.push(current_chunk);
     }
 
@@ -0,0 +1,249 @@
+use chrono::{Local, NaiveDateTime, TimeZone, Timelike};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Component, Path};
+use tauri::command;
+
+// ============================================================================
+// Journal Notes
+// ============================================================================
+//
+// Journals are append-only notes made of `## YYYY-MM-DD HH:MM` entries in
+// local time. Notes marked `journal: true` in their frontmatter are never
+// overwritten by the agent tools.
+
+const ENTRY_HEADING_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub timestamp: u64, // Unix timestamp, to the minute
+    pub content: String,
+}
+
+/// Whether note content is marked `journal: true`
+pub(crate) fn is_journal_note(content: &str) -> bool {
+    crate::frontmatter::parse(content)
+        .ok()
+        .and_then(|mapping| mapping.get("journal").and_then(|v| v.as_bool()))
+        .unwrap_or(false)
+}
+
+/// Append a timestamped entry to the end of a journal note
+/// The note is created (and marked as a journal) if it doesn't exist
+/// Returns the entry's Unix timestamp
+#[command]
+pub async fn append_to_journal(
+    vault_path: String,
+    note_path: String,
+    entry_content: String,
+) -> Result<u64, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let relative = Path::new(&note_path);
+    if relative.is_absolute() || relative.components().any(|c| c == Component::ParentDir) {
+        return Err("Journal note must be inside the vault".to_string());
+    }
+    let path = match relative.extension() {
+        Some(ext) if ext == "md" => vault.join(relative),
+        _ => vault.join(format!("{}.md", note_path)),
+    };
+
+    let entry_content = entry_content.trim();
+    if entry_content.is_empty() {
+        return Err("Journal entry is empty".to_string());
+    }
+
+    // Headings only carry minutes, so the timestamp is truncated to match
+    let now = Local::now();
+    let now = now
+        .with_second(0)
+        .and_then(|t| t.with_nanosecond(0))
+        .unwrap_or(now);
+
+    let mut content = if path.exists() {
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read note: {}", e))?
+    } else {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Failed to create folder: {}", e))?;
+        }
+        "---\njournal: true\n---\n".to_string()
+    };
+
+    if !content.is_empty() && !content.ends_with('\n') {
+        content.push('\n');
+    }
+    if !content.is_empty() && !content.ends_with("---\n") {
+        content.push('\n');
+    }
+    content.push_str(&format!(
+        "## {}\n\n{}\n",
+        now.format(ENTRY_HEADING_FORMAT),
+        entry_content
+    ));
+
+    fs::write(&path, content).map_err(|e| format!("Failed to write note: {}", e))?;
+
+    // Auto-commit if Git repository
+    if let Some(repo) = crate::git_manager::open_repository(vault) {
+        let _ = crate::git_manager::auto_commit_mosaic_changes(
+            &repo,
+            &format!("Journal entry in {}", note_path),
+            &[&path],
+        ); // Silently fail if commit fails
+    }
+
+    Ok(now.timestamp().max(0) as u64)
+}
+
+/// Entries of a journal note, oldest first
+/// `since` skips entries older than a Unix timestamp; `limit` keeps the most recent
+#[command]
+pub async fn read_journal_entries(
+    vault_path: String,
+    note_path: String,
+    since: Option<u64>,
+    limit: Option<usize>,
+) -> Result<Vec<JournalEntry>, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let path = crate::tools::resolve_note_path(vault, &note_path)
+        .ok_or_else(|| format!("Note '{}' not found", note_path))?;
+    let content = fs::read_to_string(&path).map_err(|e| format!("Failed to read note: {}", e))?;
+
+    let heading_regex = Regex::new(r"^## (\d{4}-\d{2}-\d{2} \d{2}:\d{2})\s*$").unwrap();
+
+    let mut entries: Vec<JournalEntry> = Vec::new();
+    let mut current: Option<(u64, Vec<&str>)> = None;
+    for line in crate::frontmatter::strip(&content).lines() {
+        let timestamp = heading_regex
+            .captures(line)
+            .and_then(|caps| NaiveDateTime::parse_from_str(&caps[1], ENTRY_HEADING_FORMAT).ok())
+            .and_then(|naive| Local.from_local_datetime(&naive).earliest())
+            .map(|time| time.timestamp().max(0) as u64);
+
+        match (timestamp, current.as_mut()) {
+            (Some(timestamp), _) => {
+                if let Some((timestamp, lines)) = current.take() {
+                    entries.push(JournalEntry {
+                        timestamp,
+                        content: lines.join("\n").trim().to_string(),
+                    });
+                }
+                current = Some((timestamp, Vec::new()));
+            }
+            (None, Some((_, lines))) => lines.push(line),
+            // Text before the first entry isn't part of the journal
+            (None, None) => {}
+        }
+    }
+    if let Some((timestamp, lines)) = current {
+        entries.push(JournalEntry {
+            timestamp,
+            content: lines.join("\n").trim().to_string(),
+        });
+    }
+
+    if let Some(since) = since {
+        entries.retain(|entry| entry.timestamp >= since);
+    }
+    if let Some(limit) = limit {
+        let skip = entries.len().saturating_sub(limit);
+        entries.drain(..skip);
+    }
+
+    Ok(entries)
+}
+
+// ============================================================================
+// Quick Capture
+// ============================================================================
+
+const DEFAULT_INBOX_NOTE: &str = "Inbox.md";
+
+/// Append a timestamped capture to the vault's inbox note
+/// The inbox is `Inbox.md` unless the vault config names another note, and is
+/// created on first use. Returns the inbox's absolute path
+#[command]
+pub async fn quick_capture(
+    vault_path: String,
+    content: String,
+    source: Option<String>,
+) -> Result<String, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let content = content.trim();
+    if content.is_empty() {
+        return Err("Nothing to capture".to_string());
+    }
+
+    let inbox_note = crate::vault_config::load_config(vault)?
+        .inbox_note
+        .filter(|note| !note.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_INBOX_NOTE.to_string());
+    let relative = Path::new(inbox_note.trim());
+    if relative.is_absolute() || relative.components().any(|c| c == Component::ParentDir) {
+        return Err("Inbox note must be inside the vault".to_string());
+    }
+    let path = match relative.extension() {
+        Some(ext) if ext == "md" => vault.join(relative),
+        _ => vault.join(format!("{}.md", inbox_note.trim())),
+    };
+
+    let mut note = if path.exists() {
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read inbox: {}", e))?
+    } else {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Failed to create folder: {}", e))?;
+        }
+        "---\ntype: inbox\n---\n# Inbox\n".to_string()
+    };
+
+    // Sources become tags, e.g. "Web Clipper" -> #web-clipper
+    let source_tag = source
+        .map(|s| {
+            s.trim()
+                .to_lowercase()
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join("-")
+        })
+        .filter(|s| !s.is_empty())
+        .map(|s| format!(" #{}", s.trim_start_matches('#')))
+        .unwrap_or_default();
+
+    if !note.ends_with('\n') {
+        note.push('\n');
+    }
+    note.push_str(&format!(
+        "\n---\n**{}**{}\n{}\n",
+        Local::now().format(ENTRY_HEADING_FORMAT),
+        source_tag,
+        content
+    ));
+
+    fs::write(&path, note).map_err(|e| format!("Failed to write inbox: {}", e))?;
+
+    // Auto-commit if Git repository
+    if let Some(repo) = crate::git_manager::open_repository(vault) {
+        let _ = crate::git_manager::auto_commit_mosaic_changes(
+            &repo,
+            "Quick capture to inbox",
+            &[&path],
+        ); // Silently fail if commit fails
+    }
+
+    Ok(path.to_string_lossy().to_string())
+}
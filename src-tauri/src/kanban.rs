@@ -0,0 +1,146 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use tauri::command;
+
+// ============================================================================
+// Kanban Boards
+// ============================================================================
+//
+// A board is a folder of notes; each note's `status:` frontmatter field picks
+// its column.
+
+/// Standard columns, always shown in this order before any custom statuses
+const STANDARD_COLUMNS: &[&str] = &["backlog", "todo", "in-progress", "review", "done"];
+
+/// Column for notes without a status
+const DEFAULT_STATUS: &str = "backlog";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KanbanBoard {
+    pub columns: Vec<KanbanColumn>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KanbanColumn {
+    pub name: String,
+    pub cards: Vec<KanbanCard>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KanbanCard {
+    pub note_path: String, // Relative path
+    pub title: String,
+    pub tags: Vec<String>,
+    pub modified: u64, // Unix timestamp
+}
+
+fn normalize_status(status: &str) -> String {
+    status
+        .trim()
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Group the notes of a folder (including subfolders) into columns by status
+/// Cards are ordered most recently modified first
+#[command]
+pub async fn get_kanban_board(
+    vault_path: String,
+    board_folder: String,
+) -> Result<KanbanBoard, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let folder = vault.join(&board_folder);
+    if !folder.is_dir() {
+        return Err(format!("Folder '{}' does not exist", board_folder));
+    }
+
+    let mut notes = Vec::new();
+    crate::tools::collect_notes(&folder, &mut notes, vault)?;
+    notes.retain(|note| !note.is_encrypted);
+
+    let mut columns: BTreeMap<String, Vec<KanbanCard>> = BTreeMap::new();
+    for note in notes {
+        let Ok(content) = fs::read_to_string(vault.join(&note.path)) else {
+            continue;
+        };
+        let mapping = crate::frontmatter::parse(&content).unwrap_or_default();
+
+        let status = match mapping.get("status") {
+            Some(serde_yaml::Value::String(status)) => normalize_status(status),
+            _ => String::new(),
+        };
+        let status = if status.is_empty() {
+            DEFAULT_STATUS.to_string()
+        } else {
+            status
+        };
+
+        let title = if note.display_title.is_empty() {
+            note.title
+        } else {
+            note.display_title
+        };
+
+        columns.entry(status).or_default().push(KanbanCard {
+            note_path: note.path,
+            title,
+            tags: crate::frontmatter::tags(&mapping),
+            modified: note.modified,
+        });
+    }
+
+    // Standard columns first (even when empty), then the rest alphabetically
+    let mut ordered = Vec::new();
+    for name in STANDARD_COLUMNS {
+        let cards = columns.remove(*name).unwrap_or_default();
+        ordered.push(KanbanColumn {
+            name: name.to_string(),
+            cards,
+        });
+    }
+    ordered.extend(
+        columns
+            .into_iter()
+            .map(|(name, cards)| KanbanColumn { name, cards }),
+    );
+
+    for column in &mut ordered {
+        column.cards.sort_by(|a, b| {
+            b.modified
+                .cmp(&a.modified)
+                .then_with(|| a.title.cmp(&b.title))
+        });
+    }
+
+    Ok(KanbanBoard { columns: ordered })
+}
+
+/// Move a card by setting its note's `status:` field
+#[command]
+pub async fn update_note_status(
+    vault_path: String,
+    note_path: String,
+    status: String,
+) -> Result<(), String> {
+    let status = normalize_status(&status);
+    if status.is_empty() {
+        return Err("Status cannot be empty".to_string());
+    }
+
+    crate::tools::agent_set_frontmatter_field(
+        vault_path,
+        note_path,
+        "status".to_string(),
+        serde_json::Value::String(status),
+    )
+    .await
+}
@@ -1,25 +1,45 @@
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
 
 mod ai;
+mod auto_commit;
+mod backup;
+mod bm25;
+mod clipper;
+mod crypto;
+mod export;
+mod frontmatter;
 mod fs_extra;
 mod git_manager;
 mod github;
 mod graph;
+mod graph_analysis;
+mod health;
+mod import;
 mod indexer;
+mod journal;
+mod kanban;
+mod links;
+mod pins;
+mod search;
+mod stats;
 mod tags;
+mod tasks;
 mod templates;
+mod toc;
 mod tools;
+mod transclusion;
+mod vault_config;
+mod vault_registry;
 mod vector_store;
 mod watcher;
 mod wikipedia;
 
 use ai::{
     cerebras::CerebrasProvider, gemini::GeminiProvider, ollama::OllamaProvider,
-    openrouter::OpenRouterProvider, AIProvider,
+    openrouter::OpenRouterProvider, AIProvider, ConversationMessage,
 };
-use futures::StreamExt;
 use keyring::Entry;
-use tauri::Emitter;
+use tauri::Manager;
 
 #[tauri::command]
 fn greet(name: &str) -> String {
@@ -87,6 +107,16 @@ async fn test_ai_connection(provider: String) -> Result<bool, String> {
     provider_impl.test_connection().await
 }
 
+#[tauri::command]
+async fn ollama_list_models(host: String) -> Result<Vec<ai::ollama::OllamaModelInfo>, String> {
+    OllamaProvider::new(host).list_models().await
+}
+
+#[tauri::command]
+async fn openrouter_list_models() -> Result<Vec<ai::openrouter::OpenRouterModel>, String> {
+    ai::openrouter::list_openrouter_models().await
+}
+
 #[tauri::command]
 async fn ai_rewrite_text(
     app_handle: tauri::AppHandle,
@@ -96,44 +126,34 @@ async fn ai_rewrite_text(
     instruction: String,
     context: String,
 ) -> Result<(), String> {
-    let api_key = match get_api_key(provider.clone()).await {
-        Ok(key) => key,
-        Err(_) if provider == "ollama" => "".to_string(),
-        Err(e) => return Err(e.to_string()),
+    // A configured fallback chain takes over from the single provider
+    let ai_provider: Box<dyn AIProvider> = match ai::create_fallback_provider(&app_handle).await? {
+        Some(fallback) => Box::new(fallback),
+        None => ai::create_provider(&app_handle, &provider, model).await?,
     };
 
-    let ai_provider: Box<dyn AIProvider> = match provider.as_str() {
-        "gemini" => Box::new(GeminiProvider::new(api_key).with_model(model)),
-        "cerebras" => Box::new(CerebrasProvider::new(api_key).with_model(model)),
-        "openrouter" => Box::new(OpenRouterProvider::new(api_key).with_model(model)),
-        "ollama" => Box::new(OllamaProvider::new(api_key).with_model(model)),
-        _ => return Err("Invalid provider".to_string()),
-    };
-
-    let mut stream = ai_provider
+    let stream = ai_provider
         .stream_completion(system_prompt, instruction, context)
         .await?;
 
-    while let Some(chunk_result) = stream.next().await {
-        match chunk_result {
-            Ok(chunk) => {
-                app_handle
-                    .emit("ai-stream-chunk", chunk)
-                    .map_err(|e| e.to_string())?;
-            }
-            Err(e) => {
-                app_handle
-                    .emit("ai-stream-error", e)
-                    .map_err(|e| e.to_string())?;
-            }
-        }
-    }
+    ai::emit_stream(&app_handle, stream).await
+}
 
-    app_handle
-        .emit("ai-stream-done", ())
-        .map_err(|e| e.to_string())?;
+#[tauri::command]
+async fn ai_chat(
+    app_handle: tauri::AppHandle,
+    provider: String,
+    model: String,
+    system_prompt: String,
+    messages: Vec<ConversationMessage>,
+) -> Result<(), String> {
+    let ai_provider = ai::create_provider(&app_handle, &provider, model).await?;
 
-    Ok(())
+    let stream = ai_provider
+        .stream_completion_with_history(system_prompt, messages)
+        .await?;
+
+    ai::emit_stream(&app_handle, stream).await
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -146,10 +166,15 @@ struct FileNode {
     #[serde(rename = "noteId")]
     note_id: Option<String>,
     path: Option<String>,
+    #[serde(rename = "isPinned")]
+    is_pinned: bool,
 }
 
 #[tauri::command]
-async fn get_file_tree(vault_path: String) -> Result<Vec<FileNode>, String> {
+async fn get_file_tree(
+    vault_path: String,
+    apply_sort_configs: Option<bool>,
+) -> Result<Vec<FileNode>, String> {
     // println!("RUST: get_file_tree called with path: {}", vault_path);
     let path = std::path::Path::new(&vault_path);
     if !path.exists() || !path.is_dir() {
@@ -164,6 +189,7 @@ async fn get_file_tree(vault_path: String) -> Result<Vec<FileNode>, String> {
         return Err(format!("Vault path does not exist"));
     }
 
+    let pinned = pins::pinned_set(path);
     let mut nodes = Vec::new();
     let walker = WalkBuilder::new(path)
         .hidden(false) // We want to control hidden files manually if needed, but for now let's follow standard rules or custom ones
@@ -198,6 +224,7 @@ async fn get_file_tree(vault_path: String) -> Result<Vec<FileNode>, String> {
                         children: None, // Flat list, no children
                         note_id: None,
                         path: Some(entry_path.to_string_lossy().to_string()),
+                        is_pinned: false,
                     });
                 } else if entry_path.is_file() {
                     if let Some(ext) = entry_path.extension() {
@@ -210,6 +237,8 @@ async fn get_file_tree(vault_path: String) -> Result<Vec<FileNode>, String> {
                                 children: None,
                                 note_id: Some(entry_path.to_string_lossy().to_string()),
                                 path: Some(entry_path.to_string_lossy().to_string()),
+                                is_pinned: pinned
+                                    .contains(relative_path.to_string_lossy().as_ref()),
                             });
                         }
                     }
@@ -236,7 +265,169 @@ async fn get_file_tree(vault_path: String) -> Result<Vec<FileNode>, String> {
         path_a.components().cmp(path_b.components())
     });
 
-    Ok(nodes)
+    if apply_sort_configs.unwrap_or(false) {
+        return Ok(sort_by_folder_configs(path, nodes));
+    }
+
+    Ok(float_pinned(path, nodes))
+}
+
+/// Move pinned notes to the top of their folder, keeping the depth-first
+/// path order otherwise
+fn float_pinned(vault: &std::path::Path, nodes: Vec<FileNode>) -> Vec<FileNode> {
+    use std::collections::HashMap;
+    use std::path::{Path, PathBuf};
+
+    let mut children: HashMap<PathBuf, Vec<FileNode>> = HashMap::new();
+    for node in nodes {
+        let parent = Path::new(&node.id)
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| vault.to_path_buf());
+        children.entry(parent).or_default().push(node);
+    }
+
+    fn append(
+        dir: &Path,
+        children: &mut HashMap<PathBuf, Vec<FileNode>>,
+        ordered: &mut Vec<FileNode>,
+    ) {
+        let Some(mut entries) = children.remove(dir) else {
+            return;
+        };
+        entries.sort_by_key(|node| !node.is_pinned); // Stable, keeps path order
+        for node in entries {
+            let folder = (node.node_type == "folder").then(|| PathBuf::from(&node.id));
+            ordered.push(node);
+            if let Some(folder) = folder {
+                append(&folder, children, ordered);
+            }
+        }
+    }
+
+    let mut ordered = Vec::new();
+    append(vault, &mut children, &mut ordered);
+    ordered
+}
+
+/// Order file tree nodes depth-first, each folder's children sorted by the
+/// folder's saved sort config (or the default config)
+fn sort_by_folder_configs(vault: &std::path::Path, nodes: Vec<FileNode>) -> Vec<FileNode> {
+    use std::collections::HashMap;
+    use std::path::{Path, PathBuf};
+
+    let configs = vault_config::load_folder_sort_configs(vault).unwrap_or_default();
+
+    // Incoming links per note, only needed when some folder sorts by them
+    let mut link_counts: HashMap<String, u64> = HashMap::new();
+    if configs.values().any(|c| c.sort_by == "link_count") {
+        if let Ok(graph_data) = graph::get_graph_data_with_cache(vault, None) {
+            for link in graph_data.links.iter().filter(|l| l.source != l.target) {
+                *link_counts.entry(link.target.clone()).or_insert(0) += 1;
+            }
+        }
+    }
+
+    let mut children: HashMap<PathBuf, Vec<FileNode>> = HashMap::new();
+    for node in nodes {
+        let parent = Path::new(&node.id)
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| vault.to_path_buf());
+        children.entry(parent).or_default().push(node);
+    }
+
+    fn append_sorted(
+        dir: &Path,
+        vault: &Path,
+        children: &mut HashMap<PathBuf, Vec<FileNode>>,
+        configs: &HashMap<String, vault_config::FolderSortConfig>,
+        link_counts: &HashMap<String, u64>,
+        ordered: &mut Vec<FileNode>,
+    ) {
+        let Some(entries) = children.remove(dir) else {
+            return;
+        };
+
+        let key = dir
+            .strip_prefix(vault)
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let config = configs.get(&key).cloned().unwrap_or_default();
+
+        let mut keyed: Vec<(u64, FileNode)> = entries
+            .into_iter()
+            .map(|node| {
+                let metadata = std::fs::metadata(&node.id).ok();
+                let secs = |time: std::io::Result<std::time::SystemTime>| {
+                    time.ok()
+                        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                        .map_or(0, |d| d.as_secs())
+                };
+                let value = match config.sort_by.as_str() {
+                    "modified" => metadata.as_ref().map_or(0, |m| secs(m.modified())),
+                    "created" => metadata.as_ref().map_or(0, |m| secs(m.created())),
+                    "size" if node.node_type == "file" => metadata.as_ref().map_or(0, |m| m.len()),
+                    "link_count" => link_counts.get(&node.id).copied().unwrap_or(0),
+                    _ => 0,
+                };
+                (value, node)
+            })
+            .collect();
+
+        keyed.sort_by(|(value_a, a), (value_b, b)| {
+            let folders = if config.folders_first {
+                (b.node_type == "folder").cmp(&(a.node_type == "folder"))
+            } else {
+                std::cmp::Ordering::Equal
+            };
+            // Pinned notes always lead the other files; `pinned_first` also lifts
+            // them above folders
+            let pinned = b.is_pinned.cmp(&a.is_pinned);
+            let by_name = a
+                .name
+                .to_lowercase()
+                .cmp(&b.name.to_lowercase())
+                .then_with(|| a.name.cmp(&b.name));
+            let primary = if config.sort_by == "name" {
+                by_name
+            } else {
+                value_a.cmp(value_b)
+            };
+            let primary = if config.direction == "desc" {
+                primary.reverse()
+            } else {
+                primary
+            };
+
+            let grouped = if config.pinned_first {
+                pinned.then(folders)
+            } else {
+                folders.then(pinned)
+            };
+            grouped.then(primary).then(by_name)
+        });
+
+        for (_, node) in keyed {
+            let is_folder = node.node_type == "folder";
+            let node_path = PathBuf::from(&node.id);
+            ordered.push(node);
+            if is_folder {
+                append_sorted(&node_path, vault, children, configs, link_counts, ordered);
+            }
+        }
+    }
+
+    let mut ordered = Vec::new();
+    append_sorted(
+        vault,
+        vault,
+        &mut children,
+        &configs,
+        &link_counts,
+        &mut ordered,
+    );
+    ordered
 }
 
 #[tauri::command]
@@ -246,7 +437,83 @@ async fn get_graph_data(vault_path: String) -> Result<graph::GraphData, String>
         return Err(format!("Vault path '{}' does not exist", vault_path));
     }
 
-    graph::get_graph_data_with_cache(path)
+    let config = graph::load_layout_config(path)?;
+    graph::get_graph_data_with_cache(path, Some(&config))
+}
+
+#[tauri::command]
+async fn get_graph_layout_config(vault_path: String) -> Result<graph::GraphLayoutConfig, String> {
+    let path = std::path::Path::new(&vault_path);
+    if !path.exists() || !path.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    graph::load_layout_config(path)
+}
+
+#[tauri::command]
+async fn set_graph_layout_config(
+    vault_path: String,
+    config: graph::GraphLayoutConfig,
+) -> Result<(), String> {
+    let path = std::path::Path::new(&vault_path);
+    if !path.exists() || !path.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    graph::save_layout_config(path, &config)
+}
+
+/// Graph data with each node's tags, for colouring nodes by tag
+#[tauri::command]
+async fn get_graph_data_with_tags(vault_path: String) -> Result<graph::TaggedGraphData, String> {
+    let path = std::path::Path::new(&vault_path);
+    if !path.exists() || !path.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let graph_data = graph::get_graph_data_with_cache(path, None)?;
+    let tags_data = tags::get_tags_data_with_cache(path)?;
+
+    // Tags per note (relative path), with their vault-wide counts
+    let mut note_tags: std::collections::HashMap<&str, Vec<(&str, usize)>> =
+        std::collections::HashMap::new();
+    for info in &tags_data.tags {
+        for file in &info.files {
+            note_tags
+                .entry(file.as_str())
+                .or_default()
+                .push((info.tag.as_str(), info.count));
+        }
+    }
+
+    let nodes = graph_data
+        .nodes
+        .into_iter()
+        .map(|node| {
+            let relative = std::path::Path::new(&node.id)
+                .strip_prefix(path)
+                .map(|p| p.to_string_lossy().to_string())
+                .unwrap_or_else(|_| node.id.clone());
+            let mut tags = note_tags
+                .get(relative.as_str())
+                .cloned()
+                .unwrap_or_default();
+            // Most used first, ties alphabetical
+            tags.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+
+            graph::TaggedGraphNode {
+                node,
+                primary_tag: tags.first().map(|(tag, _)| tag.to_string()),
+                tags: tags.into_iter().map(|(tag, _)| tag.to_string()).collect(),
+            }
+        })
+        .collect();
+
+    Ok(graph::TaggedGraphData {
+        nodes,
+        links: graph_data.links,
+    })
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -263,7 +530,7 @@ async fn get_backlinks(vault_path: String, note_path: String) -> Result<Vec<Back
     }
 
     // Get graph data
-    let graph_data = graph::get_graph_data_with_cache(path)?;
+    let graph_data = graph::get_graph_data_with_cache(path, None)?;
 
     // Find all links where target matches the note_path
     let mut backlinks = Vec::new();
@@ -317,31 +584,130 @@ async fn get_notes_by_tag(vault_path: String, tag: String) -> Result<Vec<String>
 // ============================================================================
 
 #[tauri::command]
-async fn trigger_indexing(vault_path: String) -> Result<(), String> {
+async fn trigger_indexing(app_handle: tauri::AppHandle, vault_path: String) -> Result<(), String> {
     let api_key = get_api_key("gemini".to_string()).await?;
+    let config = ai::config::load_ai_config(&app_handle)?;
     let path = std::path::Path::new(&vault_path);
-    indexer::index_vault(path, &api_key).await
+    let indexing_config = indexer::load_indexing_config(path)?;
+    indexer::index_vault(
+        path,
+        &api_key,
+        ai::config::rate_limiter(&config, "gemini"),
+        &indexing_config,
+    )
+    .await
 }
 
+/// Index the vault, streaming `indexing-*` progress events to the frontend
 #[tauri::command]
-async fn agent_semantic_search(
+async fn trigger_indexing_with_progress(
+    app_handle: tauri::AppHandle,
     vault_path: String,
-    query: String,
+) -> Result<(), String> {
+    let api_key = get_api_key("gemini".to_string()).await?;
+    let config = ai::config::load_ai_config(&app_handle)?;
+    let path = std::path::Path::new(&vault_path);
+    let indexing_config = indexer::load_indexing_config(path)?;
+    indexer::index_vault_with_progress(
+        path,
+        &api_key,
+        ai::config::rate_limiter(&config, "gemini"),
+        &indexing_config,
+        &app_handle,
+    )
+    .await
+}
+
+/// Delete and rebuild the keyword and embedding indexes in the background
+/// Returns immediately; progress arrives as `search-index-rebuild-*` events
+#[tauri::command]
+async fn rebuild_search_index(
+    app_handle: tauri::AppHandle,
+    vault_path: String,
+) -> Result<(), String> {
+    let path = std::path::Path::new(&vault_path);
+    if !path.exists() || !path.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let api_key = get_api_key("gemini".to_string()).await?;
+    let config = ai::config::load_ai_config(&app_handle)?;
+    let indexing_config = indexer::load_indexing_config(path)?;
+    indexer::rebuild_index_in_background(
+        path.to_path_buf(),
+        api_key,
+        ai::config::rate_limiter(&config, "gemini"),
+        indexing_config,
+        app_handle,
+    )
+}
+
+#[tauri::command]
+async fn load_indexing_config(vault_path: String) -> Result<indexer::IndexingConfig, String> {
+    indexer::load_indexing_config(std::path::Path::new(&vault_path))
+}
+
+#[tauri::command]
+async fn save_indexing_config(
+    vault_path: String,
+    config: indexer::IndexingConfig,
+) -> Result<(), String> {
+    indexer::save_indexing_config(std::path::Path::new(&vault_path), &config)
+}
+
+#[tauri::command]
+async fn get_vector_store_stats(vault_path: String) -> Result<vector_store::StoreStats, String> {
+    indexer::get_store_stats(std::path::Path::new(&vault_path))
+}
+
+#[tauri::command]
+async fn set_max_store_size(vault_path: String, max_chunks: usize) -> Result<(), String> {
+    indexer::set_max_store_size(std::path::Path::new(&vault_path), max_chunks)
+}
+
+#[tauri::command]
+async fn get_index_metadata(vault_path: String) -> Result<indexer::IndexMetadata, String> {
+    indexer::get_index_metadata(std::path::Path::new(&vault_path))
+}
+
+/// Embed the query and rank indexed chunks with the given metric
+async fn semantic_search_with_metric(
+    app_handle: &tauri::AppHandle,
+    vault_path: &str,
+    query: &str,
     limit: Option<usize>,
+    metric: vector_store::DistanceMetric,
 ) -> Result<Vec<SearchResult>, String> {
     let api_key = get_api_key("gemini".to_string()).await?;
-    let provider = GeminiProvider::new(api_key);
+    let config = ai::config::load_ai_config(app_handle)?;
+    let provider =
+        GeminiProvider::new(api_key).with_rate_limiter(ai::config::rate_limiter(&config, "gemini"));
 
     // Get query embedding
-    let query_vector = provider.get_embedding(&query).await?;
+    let query_vector = provider.get_embedding(query).await?;
 
     // Load vector store
-    let store_path = std::path::Path::new(&vault_path).join(".moss/vector_store.db");
+    let store_path = std::path::Path::new(vault_path).join(".moss/vector_store.db");
     let store = vector_store::VectorStore::open(&store_path).map_err(|e| e.to_string())?;
 
+    // Vectors from different embedding models can't be compared
+    if let Some(dimension) = store.check_vector_dimension_consistency()? {
+        if dimension != query_vector.len() {
+            let indexed_with = indexer::load_index_metadata(std::path::Path::new(vault_path))
+                .and_then(|meta| meta.provider)
+                .unwrap_or_else(|| "a different provider".to_string());
+            return Err(format!(
+                "Vault was indexed with {} ({}-dimensional embeddings) but the query embedding has {} dimensions. Re-index the vault to search it.",
+                indexed_with,
+                dimension,
+                query_vector.len()
+            ));
+        }
+    }
+
     // Search
     let results = store
-        .search(&query_vector, limit.unwrap_or(5))
+        .search(&query_vector, limit.unwrap_or(5), metric)
         .map_err(|e| e.to_string())?;
 
     // Convert to SearchResult format (paths are already relative in DB)
@@ -357,6 +723,144 @@ async fn agent_semantic_search(
     Ok(search_results)
 }
 
+/// Keyword search over indexed chunks, ranked with BM25
+/// Works without an embedding provider once the vault has been indexed
+#[tauri::command]
+async fn agent_bm25_search(
+    vault_path: String,
+    query: String,
+    limit: Option<usize>,
+) -> Result<Vec<SearchResult>, String> {
+    let store_path = std::path::Path::new(&vault_path).join(".moss/vector_store.db");
+    let index = bm25::BM25Index::open(&store_path)?;
+
+    let results = index.search(&query, limit.unwrap_or(5))?;
+    Ok(results
+        .into_iter()
+        .map(|(chunk, score)| SearchResult {
+            file_path: chunk.file_path,
+            content: chunk.content,
+            score,
+        })
+        .collect())
+}
+
+#[tauri::command]
+async fn agent_semantic_search(
+    app_handle: tauri::AppHandle,
+    vault_path: String,
+    query: String,
+    limit: Option<usize>,
+) -> Result<Vec<SearchResult>, String> {
+    semantic_search_with_metric(
+        &app_handle,
+        &vault_path,
+        &query,
+        limit,
+        vector_store::DistanceMetric::Cosine,
+    )
+    .await
+}
+
+/// Semantic search with a chosen metric ("cosine", "euclidean" or "dot_product")
+/// Euclidean scores are distances, so lower means more similar
+#[tauri::command]
+async fn agent_semantic_search_with_metric(
+    app_handle: tauri::AppHandle,
+    vault_path: String,
+    query: String,
+    limit: Option<usize>,
+    metric: String,
+) -> Result<Vec<SearchResult>, String> {
+    let metric = vector_store::DistanceMetric::parse(&metric)?;
+    semantic_search_with_metric(&app_handle, &vault_path, &query, limit, metric).await
+}
+
+#[tauri::command]
+async fn agent_semantic_search_enriched(
+    app_handle: tauri::AppHandle,
+    vault_path: String,
+    query: String,
+    limit: Option<usize>,
+) -> Result<Vec<search::SearchResultEnriched>, String> {
+    let results = agent_semantic_search(app_handle, vault_path.clone(), query.clone(), limit)
+        .await?
+        .into_iter()
+        .map(|result| (result.file_path, result.content, result.score))
+        .collect();
+
+    search::enrich_results(std::path::Path::new(&vault_path), &query, results)
+}
+
+/// Default cap on indexed notes for the O(n²) duplicate scan
+const DEFAULT_MAX_DUPLICATE_NOTES: usize = 2000;
+
+#[tauri::command]
+async fn find_duplicate_notes(
+    vault_path: String,
+    threshold: f32,
+    max_notes: Option<usize>,
+) -> Result<Vec<vector_store::DuplicatePair>, String> {
+    let store_path = std::path::Path::new(&vault_path).join(".moss/vector_store.db");
+    let store = vector_store::VectorStore::open(&store_path)?;
+
+    let embeddings = store.file_embeddings()?;
+    if embeddings.is_empty() {
+        return Err("No embeddings found. Run indexing first.".to_string());
+    }
+
+    let max_notes = max_notes.unwrap_or(DEFAULT_MAX_DUPLICATE_NOTES);
+    if embeddings.len() > max_notes {
+        return Err(format!(
+            "Vault has {} indexed notes, more than the duplicate scan limit of {}",
+            embeddings.len(),
+            max_notes
+        ));
+    }
+
+    Ok(vector_store::find_similar_pairs(&embeddings, threshold))
+}
+
+/// Most pairs returned by `compute_similarity_matrix`
+const MAX_SIMILARITY_PAIRS: usize = 500;
+
+/// Pairwise similarity of indexed notes above `threshold`, as index triples
+#[tauri::command]
+async fn compute_similarity_matrix(
+    vault_path: String,
+    threshold: f32,
+) -> Result<vector_store::SimilarityMatrix, String> {
+    let store_path = std::path::Path::new(&vault_path).join(".moss/vector_store.db");
+    let store = vector_store::VectorStore::open(&store_path)?;
+
+    let embeddings = store.file_embeddings()?;
+    if embeddings.is_empty() {
+        return Err("No embeddings found. Run indexing first.".to_string());
+    }
+    if embeddings.len() > DEFAULT_MAX_DUPLICATE_NOTES {
+        return Err(format!(
+            "Vault has {} indexed notes, more than the similarity matrix limit of {}",
+            embeddings.len(),
+            DEFAULT_MAX_DUPLICATE_NOTES
+        ));
+    }
+
+    Ok(vector_store::similarity_matrix(
+        &embeddings,
+        threshold,
+        MAX_SIMILARITY_PAIRS,
+    ))
+}
+
+#[tauri::command]
+async fn agent_find_duplicates(
+    vault_path: String,
+    threshold: f32,
+    max_notes: Option<usize>,
+) -> Result<Vec<vector_store::DuplicatePair>, String> {
+    find_duplicate_notes(vault_path, threshold, max_notes).await
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 struct SearchResult {
     file_path: String,
@@ -372,18 +876,72 @@ struct SearchResult {
 async fn search_wikipedia(
     query: String,
     limit: Option<usize>,
+    language: Option<String>,
 ) -> Result<wikipedia::SearchResults, String> {
-    wikipedia::search_wikipedia(&query, limit.unwrap_or(5)).await
+    wikipedia::search_wikipedia(&query, limit.unwrap_or(5), language.as_deref()).await
+}
+
+#[tauri::command]
+async fn get_wikipedia_summary(
+    title: String,
+    language: Option<String>,
+) -> Result<wikipedia::WikiSummary, String> {
+    wikipedia::get_wikipedia_summary(&title, language.as_deref()).await
+}
+
+#[tauri::command]
+async fn get_wikipedia_content(
+    title: String,
+    language: Option<String>,
+) -> Result<wikipedia::WikiContent, String> {
+    wikipedia::get_wikipedia_content(&title, language.as_deref()).await
+}
+
+#[tauri::command]
+async fn create_note_from_wikipedia(
+    vault_path: String,
+    title: String,
+    language: Option<String>,
+    folder: Option<String>,
+) -> Result<String, String> {
+    let folder =
+        folder.or_else(|| vault_config::default_note_folder(std::path::Path::new(&vault_path)));
+    wikipedia::create_note_from_wikipedia(
+        &vault_path,
+        &title,
+        language.as_deref(),
+        folder.as_deref(),
+    )
+    .await
+}
+
+#[tauri::command]
+async fn lookup_wikidata_entity(
+    query: String,
+    language: Option<String>,
+) -> Result<Vec<wikipedia::WikidataEntity>, String> {
+    wikipedia::lookup_wikidata_entity(&query, language.as_deref()).await
+}
+
+#[tauri::command]
+async fn get_wikidata_entity_properties(
+    entity_id: String,
+) -> Result<std::collections::HashMap<String, Vec<String>>, String> {
+    wikipedia::get_wikidata_entity_properties(&entity_id).await
 }
 
 #[tauri::command]
-async fn get_wikipedia_summary(title: String) -> Result<wikipedia::WikiSummary, String> {
-    wikipedia::get_wikipedia_summary(&title).await
+async fn insert_wikidata_link(
+    entity_id: String,
+    note_path: String,
+    vault_path: String,
+) -> Result<(), String> {
+    wikipedia::insert_wikidata_link(&entity_id, &note_path, &vault_path).await
 }
 
 #[tauri::command]
-async fn get_wikipedia_content(title: String) -> Result<wikipedia::WikiContent, String> {
-    wikipedia::get_wikipedia_content(&title).await
+async fn get_available_wikipedia_languages() -> Result<Vec<wikipedia::WikiLanguage>, String> {
+    wikipedia::get_available_wikipedia_languages().await
 }
 
 // ============================================================================
@@ -472,6 +1030,34 @@ async fn get_file_content_at_commit(
     }
 }
 
+#[tauri::command]
+async fn get_note_uncommitted_diff(
+    vault_path: String,
+    note_path: String,
+) -> Result<Option<git_manager::NoteDiff>, String> {
+    let path = std::path::Path::new(&vault_path);
+    let full_note_path = std::path::Path::new(&note_path);
+
+    // Convert to relative path string for Git (uses forward slashes)
+    let relative_path_str = if full_note_path.is_absolute() {
+        let relative = full_note_path
+            .strip_prefix(path)
+            .map_err(|_| "Note path is not inside vault".to_string())?;
+        relative
+            .to_str()
+            .ok_or_else(|| "Path contains invalid UTF-8".to_string())?
+    } else {
+        note_path.as_str()
+    };
+
+    if let Some(repo) = git_manager::open_repository(path) {
+        git_manager::get_note_uncommitted_diff(&repo, relative_path_str)
+            .map_err(|e| format!("Failed to diff note: {}", e))
+    } else {
+        Err("Not a Git repository".to_string())
+    }
+}
+
 #[tauri::command]
 async fn undo_last_mosaic_change(vault_path: String) -> Result<String, String> {
     let path = std::path::Path::new(&vault_path);
@@ -528,6 +1114,25 @@ async fn commit_vault(vault_path: String, message: String) -> Result<String, Str
     }
 }
 
+#[tauri::command]
+async fn load_commit_config(
+    vault_path: String,
+) -> Result<git_manager::CommitMessageConfig, String> {
+    git_manager::load_commit_config(std::path::Path::new(&vault_path))
+}
+
+#[tauri::command]
+async fn save_commit_config(
+    vault_path: String,
+    config: git_manager::CommitMessageConfig,
+) -> Result<(), String> {
+    let path = std::path::Path::new(&vault_path);
+    if !path.exists() || !path.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+    git_manager::save_commit_config(path, &config)
+}
+
 #[tauri::command]
 async fn restore_vault(vault_path: String, commit_oid: String) -> Result<String, String> {
     let path = std::path::Path::new(&vault_path);
@@ -621,6 +1226,28 @@ async fn github_create_repository(
     github::create_repository(&token, &name, description).await
 }
 
+#[tauri::command]
+async fn github_list_issues(
+    repo_full_name: String,
+    state: String,
+    labels: Vec<String>,
+    limit: usize,
+) -> Result<Vec<github::GitHubIssue>, String> {
+    let token = github_get_token().await?;
+    github::list_issues(&token, &repo_full_name, &state, &labels, limit).await
+}
+
+#[tauri::command]
+async fn github_create_issue(
+    repo_full_name: String,
+    title: String,
+    body: String,
+    labels: Vec<String>,
+) -> Result<github::GitHubIssue, String> {
+    let token = github_get_token().await?;
+    github::create_issue(&token, &repo_full_name, &title, &body, labels).await
+}
+
 // ============================================================================
 // Git Remote Operations Commands
 // ============================================================================
@@ -769,6 +1396,186 @@ async fn git_get_commit_changes(
     }
 }
 
+/// Changelog of the vault's history as `markdown` or plain `text`
+#[tauri::command]
+async fn generate_changelog(
+    vault_path: String,
+    since_tag: Option<String>,
+    until_oid: Option<String>,
+    format: String,
+    mosaic_only: Option<bool>,
+) -> Result<String, String> {
+    let markdown = match format.as_str() {
+        "markdown" | "md" => true,
+        "text" | "plain" => false,
+        other => return Err(format!("Unknown changelog format '{}'", other)),
+    };
+
+    let path = std::path::Path::new(&vault_path);
+
+    if let Some(repo) = git_manager::open_repository(path) {
+        git_manager::generate_changelog(
+            &repo,
+            since_tag.as_deref(),
+            until_oid.as_deref(),
+            markdown,
+            mosaic_only.unwrap_or(false),
+        )
+        .map_err(|e| format!("Failed to generate changelog: {}", e))
+    } else {
+        Err("Not a Git repository".to_string())
+    }
+}
+
+#[tauri::command]
+async fn git_get_commit_statistics(
+    vault_path: String,
+    since_unix: i64,
+    until_unix: i64,
+) -> Result<git_manager::CommitHistoryStats, String> {
+    let path = std::path::Path::new(&vault_path);
+
+    if let Some(repo) = git_manager::open_repository(path) {
+        git_manager::get_commit_statistics(&repo, since_unix, until_unix)
+    } else {
+        Err("Not a Git repository".to_string())
+    }
+}
+
+#[tauri::command]
+async fn git_get_unstaged_hunks(vault_path: String) -> Result<Vec<git_manager::FileHunk>, String> {
+    let path = std::path::Path::new(&vault_path);
+
+    if let Some(repo) = git_manager::open_repository(path) {
+        git_manager::get_unstaged_hunks(&repo).map_err(|e| format!("Failed to get hunks: {}", e))
+    } else {
+        Err("Not a Git repository".to_string())
+    }
+}
+
+#[tauri::command]
+async fn git_stage_hunk(
+    vault_path: String,
+    file_path: String,
+    hunk_index: usize,
+) -> Result<(), String> {
+    let path = std::path::Path::new(&vault_path);
+
+    if let Some(repo) = git_manager::open_repository(path) {
+        git_manager::stage_hunk(&repo, &file_path, hunk_index)
+            .map_err(|e| format!("Failed to stage hunk: {}", e))
+    } else {
+        Err("Not a Git repository".to_string())
+    }
+}
+
+#[tauri::command]
+async fn git_cherry_pick(vault_path: String, commit_oid: String) -> Result<String, String> {
+    let path = std::path::Path::new(&vault_path);
+
+    if let Some(repo) = git_manager::open_repository(path) {
+        git_manager::cherry_pick_commit(&repo, &commit_oid)
+            .map(|oid| oid.to_string())
+            .map_err(|e| format!("Failed to cherry-pick: {}", e))
+    } else {
+        Err("Not a Git repository".to_string())
+    }
+}
+
+#[tauri::command]
+async fn git_create_tag(
+    vault_path: String,
+    name: String,
+    message: Option<String>,
+    commit_oid: Option<String>,
+) -> Result<(), String> {
+    let path = std::path::Path::new(&vault_path);
+
+    if let Some(repo) = git_manager::open_repository(path) {
+        git_manager::create_git_tag(&repo, &name, message.as_deref(), commit_oid.as_deref())
+            .map_err(|e| format!("Failed to create tag: {}", e))
+    } else {
+        Err("Not a Git repository".to_string())
+    }
+}
+
+#[tauri::command]
+async fn git_list_tags(vault_path: String) -> Result<Vec<git_manager::TagEntry>, String> {
+    let path = std::path::Path::new(&vault_path);
+
+    if let Some(repo) = git_manager::open_repository(path) {
+        git_manager::list_git_tags(&repo).map_err(|e| format!("Failed to list tags: {}", e))
+    } else {
+        Err("Not a Git repository".to_string())
+    }
+}
+
+#[tauri::command]
+async fn git_delete_tag(vault_path: String, name: String) -> Result<(), String> {
+    let path = std::path::Path::new(&vault_path);
+
+    if let Some(repo) = git_manager::open_repository(path) {
+        git_manager::delete_git_tag(&repo, &name)
+            .map_err(|e| format!("Failed to delete tag: {}", e))
+    } else {
+        Err("Not a Git repository".to_string())
+    }
+}
+
+/// Check out a branch into a linked worktree and watch it for changes
+#[tauri::command]
+async fn git_create_worktree(
+    app_handle: tauri::AppHandle,
+    watcher_state: tauri::State<'_, watcher::WatcherState>,
+    vault_path: String,
+    worktree_path: String,
+    branch_name: String,
+) -> Result<(), String> {
+    let path = std::path::Path::new(&vault_path);
+
+    if let Some(repo) = git_manager::open_repository(path) {
+        git_manager::create_worktree(&repo, std::path::Path::new(&worktree_path), &branch_name)
+            .map_err(|e| format!("Failed to create worktree: {}", e))?;
+        watcher::watch_worktree(app_handle, &watcher_state, &worktree_path)
+    } else {
+        Err("Not a Git repository".to_string())
+    }
+}
+
+#[tauri::command]
+async fn git_list_worktrees(vault_path: String) -> Result<Vec<git_manager::WorktreeInfo>, String> {
+    let path = std::path::Path::new(&vault_path);
+
+    if let Some(repo) = git_manager::open_repository(path) {
+        git_manager::list_worktrees(&repo).map_err(|e| format!("Failed to list worktrees: {}", e))
+    } else {
+        Err("Not a Git repository".to_string())
+    }
+}
+
+#[tauri::command]
+async fn git_remove_worktree(
+    watcher_state: tauri::State<'_, watcher::WatcherState>,
+    vault_path: String,
+    worktree_path: String,
+) -> Result<(), String> {
+    let path = std::path::Path::new(&vault_path);
+
+    if let Some(repo) = git_manager::open_repository(path) {
+        // Resolve the path while it still exists, the watcher slot is keyed by it
+        let worktree_path = std::path::Path::new(&worktree_path)
+            .canonicalize()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or(worktree_path);
+        git_manager::remove_worktree(&repo, std::path::Path::new(&worktree_path))
+            .map_err(|e| format!("Failed to remove worktree: {}", e))?;
+        // A refused removal (uncommitted changes) keeps the worktree watched
+        watcher::unwatch_worktree(&watcher_state, &worktree_path)
+    } else {
+        Err("Not a Git repository".to_string())
+    }
+}
+
 #[tauri::command]
 async fn save_pane_layout(vault_path: String, layout: String) -> Result<(), String> {
     let path = std::path::Path::new(&vault_path);
@@ -807,23 +1614,103 @@ pub fn run() {
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_window_state::Builder::new().build())
         .manage(watcher::WatcherState::new())
+        .manage(auto_commit::AutoCommitState::new())
+        .manage(backup::BackupScheduleState::new())
+        .manage(graph_analysis::SnapshotScheduleState::new())
+        .setup(|app| {
+            let app_data_dir = app.path().app_data_dir().ok();
+            app.manage(vault_registry::VaultRegistry::load(app_data_dir));
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             greet,
             save_api_key,
             get_api_key,
             delete_api_key,
             test_ai_connection,
+            ollama_list_models,
+            openrouter_list_models,
             ai_rewrite_text,
+            ai_chat,
+            ai::config::ai_set_token_budget,
+            ai::config::ai_set_rate_limit,
+            ai::config::ai_configure_fallback_chain,
+            ai::history::save_ai_rewrite_record,
+            ai::history::list_ai_rewrite_records,
+            ai::history::restore_ai_rewrite,
+            ai::notes::ai_suggest_tags,
+            ai::notes::ai_apply_suggested_tags,
+            ai::notes::ai_suggest_links,
+            ai::notes::apply_link_suggestion,
+            ai::notes::ai_preview_rewrite,
+            ai::notes::ai_apply_preview,
+            ai::notes::ai_batch_improve_notes,
+            ai::notes::ai_summarize_note,
+            ai::notes::ai_summarize_folder,
+            ai::notes::ai_generate_weekly_summary,
+            ai::prompts::save_ai_system_prompt,
+            ai::prompts::list_ai_system_prompts,
+            ai::prompts::delete_ai_system_prompt,
             get_file_tree,
             get_graph_data,
+            get_graph_layout_config,
+            set_graph_layout_config,
+            get_graph_data_with_tags,
             get_backlinks,
+            graph_analysis::find_orphaned_notes,
+            graph_analysis::compute_pagerank,
+            graph_analysis::get_all_links,
+            graph_analysis::get_graph_clusters,
+            graph_analysis::find_note_path,
+            graph_analysis::get_note_distance,
+            graph_analysis::get_graph_statistics,
+            graph_analysis::find_link_cycles,
+            graph_analysis::export_graph,
+            graph_analysis::scan_broken_links,
+            graph_analysis::save_graph_snapshot,
+            graph_analysis::list_graph_snapshots,
+            graph_analysis::compare_graph_snapshots,
+            graph_analysis::start_graph_snapshot_schedule,
+            health::check_vault_health,
+            links::create_bidirectional_link,
+            links::find_unlinked_mentions,
+            transclusion::resolve_transclusions,
+            transclusion::get_notes_that_transclude,
+            transclusion::generate_block_ids,
+            transclusion::resolve_block_reference,
+            transclusion::scan_broken_block_references,
+            toc::generate_toc,
+            toc::insert_toc,
+            tasks::get_all_tasks,
+            tasks::complete_task,
+            journal::append_to_journal,
+            journal::read_journal_entries,
+            journal::quick_capture,
+            kanban::get_kanban_board,
+            kanban::update_note_status,
             get_all_tags,
             get_notes_by_tag,
+            tags::convert_tags_format,
+            tags::rename_tag,
+            tags::get_tag_tree,
+            stats::get_vault_stats,
+            stats::get_global_stats,
+            stats::get_note_stats,
+            stats::get_folder_stats,
+            stats::compute_readability_score,
+            stats::compute_folder_readability_average,
             templates::list_templates,
             templates::get_template,
             templates::create_note_from_template,
+            templates::save_template_version,
+            templates::list_template_versions,
+            templates::restore_template_version,
+            templates::render_template_handlebars,
+            templates::import_template_from_url,
+            templates::import_template_from_vault,
             tools::agent_get_note,
             tools::agent_batch_read,
+            tools::read_note_paged,
             tools::agent_search_notes,
             tools::agent_list_recent_notes,
             tools::agent_list_all_notes,
@@ -834,24 +1721,97 @@ pub fn run() {
             tools::agent_batch_update_notes,
             tools::agent_resolve_path,
             tools::agent_resolve_wikilink,
+            tools::rename_note_to_match_heading,
+            tools::agent_get_frontmatter,
+            tools::agent_set_frontmatter_field,
+            tools::agent_delete_frontmatter_field,
+            tools::create_note_from_github_issue,
             trigger_indexing,
+            trigger_indexing_with_progress,
+            rebuild_search_index,
+            load_indexing_config,
+            save_indexing_config,
+            set_max_store_size,
+            get_vector_store_stats,
+            get_index_metadata,
             agent_semantic_search,
+            agent_bm25_search,
+            agent_semantic_search_with_metric,
+            agent_semantic_search_enriched,
+            find_duplicate_notes,
+            search::search_notes_fulltext,
+            search::save_search,
+            search::list_saved_searches,
+            search::delete_saved_search,
+            search::run_saved_search,
+            search::agent_filtered_search,
+            search::search_notes_ranked,
+            agent_find_duplicates,
+            compute_similarity_matrix,
             search_wikipedia,
             get_wikipedia_summary,
             get_wikipedia_content,
+            get_available_wikipedia_languages,
+            create_note_from_wikipedia,
+            clipper::clip_url_to_note,
+            clipper::fetch_youtube_transcript,
+            lookup_wikidata_entity,
+            get_wikidata_entity_properties,
+            insert_wikidata_link,
             check_git_status,
             init_git_repository,
             get_git_history,
             get_file_content_at_commit,
+            get_note_uncommitted_diff,
             undo_last_mosaic_change,
             check_uncommitted_changes,
             commit_note,
             commit_vault,
+            load_commit_config,
+            save_commit_config,
             restore_vault,
             fs_extra::rename_note,
             fs_extra::file_exists,
             fs_extra::save_image,
+            fs_extra::save_attachment,
+            fs_extra::list_attachments,
+            fs_extra::find_orphaned_attachments,
+            fs_extra::archive_inactive_notes,
+            fs_extra::bulk_rename_notes,
+            fs_extra::move_folder,
+            crypto::encrypt_note,
+            crypto::decrypt_note,
+            vault_registry::register_vault,
+            vault_registry::unregister_vault,
+            vault_registry::list_open_vaults,
+            vault_registry::get_active_vault,
+            vault_registry::set_active_vault,
+            vault_registry::resolve_wikilink_across_vaults,
+            vault_config::load_vault_config,
+            vault_config::save_vault_config,
+            vault_config::get_folder_sort_config,
+            vault_config::set_folder_sort_config,
+            pins::pin_note,
+            pins::unpin_note,
+            pins::list_pinned_notes,
+            pins::star_note,
+            pins::unstar_note,
+            pins::list_starred_notes,
             watcher::watch_vault,
+            watcher::unwatch_vault,
+            watcher::open_note_in_external_editor,
+            auto_commit::start_auto_commit,
+            auto_commit::stop_auto_commit,
+            auto_commit::get_auto_commit_config,
+            backup::backup_vault_to_zip,
+            backup::schedule_vault_backup,
+            import::import_notes_from_directory,
+            import::import_enex,
+            import::import_pdf_as_note,
+            import::extract_pdf_metadata,
+            export::export_note_html,
+            export::export_folder_html,
+            export::export_epub,
             github_start_device_flow,
             github_poll_token,
             github_save_token,
@@ -861,6 +1821,8 @@ pub fn run() {
             github_verify_token,
             github_list_repositories,
             github_create_repository,
+            github_list_issues,
+            github_create_issue,
             git_configure_remote,
             git_push_to_remote,
             git_pull_from_remote,
@@ -871,9 +1833,34 @@ pub fn run() {
             git_abort_merge,
             git_get_sync_status,
             git_get_commit_changes,
+            git_get_commit_statistics,
+            generate_changelog,
+            git_get_unstaged_hunks,
+            git_stage_hunk,
+            git_cherry_pick,
+            git_create_tag,
+            git_list_tags,
+            git_delete_tag,
+            git_create_worktree,
+            git_list_worktrees,
+            git_remove_worktree,
             save_pane_layout,
             load_pane_layout,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app_handle, event| {
+            // Stop background timers before the runtime shuts down
+            if let tauri::RunEvent::Exit = event {
+                app_handle
+                    .state::<auto_commit::AutoCommitState>()
+                    .abort_all();
+                app_handle
+                    .state::<backup::BackupScheduleState>()
+                    .abort_all();
+                app_handle
+                    .state::<graph_analysis::SnapshotScheduleState>()
+                    .abort_all();
+            }
+        });
 }
@@ -0,0 +1,180 @@
+use regex::{Regex, RegexBuilder};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::command;
+
+// ============================================================================
+// Link Editing
+// ============================================================================
+
+const SEE_ALSO_HEADING: &str = "## See also";
+
+fn note_stem(path: &Path) -> String {
+    path.file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// Matches `[[stem]]`, `[[folder/stem]]`, `[[stem.md]]`, `[[stem|alias]]` and `[[stem#heading]]`
+pub(crate) fn wikilink_regex(stem: &str) -> Regex {
+    RegexBuilder::new(&format!(
+        r"\[\[\s*(?:[^\]|#]*/)?{}(?:\.md)?\s*(?:[#|][^\]]*)?\]\]",
+        regex::escape(stem)
+    ))
+    .case_insensitive(true)
+    .build()
+    .unwrap()
+}
+
+/// Add `- [[target]]` to the note's "See also" section, creating it at the end if absent
+fn append_see_also(content: &str, target_stem: &str) -> String {
+    let link_line = format!("- [[{}]]", target_stem);
+    let lines: Vec<&str> = content.lines().collect();
+
+    let Some(heading) = lines
+        .iter()
+        .position(|l| l.trim().eq_ignore_ascii_case(SEE_ALSO_HEADING))
+    else {
+        let mut new_content = content.trim_end().to_string();
+        if !new_content.is_empty() {
+            new_content.push_str("\n\n");
+        }
+        new_content.push_str(&format!("{}\n\n{}\n", SEE_ALSO_HEADING, link_line));
+        return new_content;
+    };
+
+    // The section runs until the next heading of the same or higher level
+    let section_end = lines[heading + 1..]
+        .iter()
+        .position(|l| l.starts_with("# ") || l.starts_with("## "))
+        .map(|offset| heading + 1 + offset)
+        .unwrap_or(lines.len());
+    // Insert after the section's last non-blank line
+    let insert_at = (heading + 1..section_end)
+        .rev()
+        .find(|&i| !lines[i].trim().is_empty())
+        .map(|i| i + 1)
+        .unwrap_or(heading + 1);
+
+    let mut new_lines: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+    if insert_at == heading + 1 {
+        new_lines.insert(insert_at, String::new());
+        new_lines.insert(insert_at + 1, link_line);
+    } else {
+        new_lines.insert(insert_at, link_line);
+    }
+
+    let mut new_content = new_lines.join("\n");
+    new_content.push('\n');
+    new_content
+}
+
+fn resolve_existing(vault: &Path, note_path: &str) -> Result<PathBuf, String> {
+    crate::tools::resolve_note_path(vault, note_path)
+        .filter(|p| p.is_file())
+        .ok_or_else(|| format!("Note '{}' not found", note_path))
+}
+
+/// Link two notes to each other under a "See also" heading
+/// Existing links in either direction are left alone
+#[command]
+pub async fn create_bidirectional_link(
+    vault_path: String,
+    note_a: String,
+    note_b: String,
+) -> Result<(), String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let path_a = resolve_existing(vault, &note_a)?;
+    let path_b = resolve_existing(vault, &note_b)?;
+    if path_a == path_b {
+        return Err("Cannot link a note to itself".to_string());
+    }
+
+    let stem_a = note_stem(&path_a);
+    let stem_b = note_stem(&path_b);
+
+    let mut modified = Vec::new();
+    for (path, target_stem) in [(&path_a, &stem_b), (&path_b, &stem_a)] {
+        let content =
+            fs::read_to_string(path).map_err(|e| format!("Failed to read note: {}", e))?;
+        if wikilink_regex(target_stem).is_match(&content) {
+            continue;
+        }
+
+        fs::write(path, append_see_also(&content, target_stem))
+            .map_err(|e| format!("Failed to write note: {}", e))?;
+        modified.push(path.as_path());
+    }
+
+    if modified.is_empty() {
+        return Ok(());
+    }
+
+    if let Some(repo) = crate::git_manager::open_repository(vault) {
+        let _ = crate::git_manager::auto_commit_mosaic_changes(
+            &repo,
+            &format!("Linked: {} ↔ {}", stem_a, stem_b),
+            &modified,
+        ); // Silently fail if commit fails
+    }
+
+    Ok(())
+}
+
+/// Notes that mention this note's title in plain text but don't link to it
+/// Returns relative paths, alphabetically
+#[command]
+pub async fn find_unlinked_mentions(
+    vault_path: String,
+    note_path: String,
+) -> Result<Vec<String>, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let target = resolve_existing(vault, &note_path)?;
+    let title = note_stem(&target);
+    if title.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let any_wikilink = Regex::new(r"\[\[[^\]]*\]\]").unwrap();
+    let mention = RegexBuilder::new(&format!(r"\b{}\b", regex::escape(&title)))
+        .case_insensitive(true)
+        .build()
+        .map_err(|e| e.to_string())?;
+    let link_to_target = wikilink_regex(&title);
+
+    let mut notes = Vec::new();
+    crate::tools::collect_notes(vault, &mut notes, vault)?;
+
+    let mut mentions = Vec::new();
+    for note in notes.iter().filter(|n| !n.is_encrypted) {
+        let path = vault.join(&note.path);
+        if path == target {
+            continue;
+        }
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        if link_to_target.is_match(&content) {
+            continue;
+        }
+
+        // Text inside other wikilinks isn't a plain mention
+        let plain = any_wikilink.replace_all(&content, "");
+        if mention.is_match(&plain) {
+            mentions.push(note.path.clone());
+        }
+    }
+
+    mentions.sort();
+    Ok(mentions)
+}
@@ -0,0 +1,159 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use tauri::command;
+
+// ============================================================================
+// Pinned & Starred Notes
+// ============================================================================
+//
+// Both lists hold vault-relative note paths. Pinned notes stay at the top of
+// the file tree; starred notes are favourites the agent can list first.
+
+const PINNED_FILE_NAME: &str = ".moss/pinned.json";
+const STARRED_FILE_NAME: &str = ".moss/starred.json";
+
+fn load_list(vault: &Path, file_name: &str) -> Result<Vec<String>, String> {
+    let path = vault.join(file_name);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", file_name, e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse {}: {}", file_name, e))
+}
+
+fn save_list(vault: &Path, file_name: &str, paths: &[String]) -> Result<(), String> {
+    let path = vault.join(file_name);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create .moss dir: {}", e))?;
+    }
+
+    let json = serde_json::to_string_pretty(paths)
+        .map_err(|e| format!("Failed to serialize {}: {}", file_name, e))?;
+    fs::write(&path, json).map_err(|e| format!("Failed to write {}: {}", file_name, e))
+}
+
+/// Normalize a note path (absolute or relative) to its vault-relative form
+fn relative_note_path(vault: &Path, note_path: &str) -> Result<String, String> {
+    let path = Path::new(note_path);
+    let relative = if path.is_absolute() {
+        path.strip_prefix(vault)
+            .map_err(|_| format!("Note '{}' is outside the vault", note_path))?
+    } else {
+        path
+    };
+    Ok(relative.to_string_lossy().to_string())
+}
+
+fn vault_dir(vault_path: &str) -> Result<&Path, String> {
+    let vault = Path::new(vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+    Ok(vault)
+}
+
+fn add_to_list(vault_path: &str, note_path: &str, file_name: &str) -> Result<(), String> {
+    let vault = vault_dir(vault_path)?;
+    let relative = relative_note_path(vault, note_path)?;
+    if !vault.join(&relative).exists() {
+        return Err(format!("Note '{}' not found", note_path));
+    }
+
+    let mut paths = load_list(vault, file_name)?;
+    if !paths.contains(&relative) {
+        paths.push(relative);
+        save_list(vault, file_name, &paths)?;
+    }
+    Ok(())
+}
+
+fn remove_from_list(vault_path: &str, note_path: &str, file_name: &str) -> Result<(), String> {
+    let vault = vault_dir(vault_path)?;
+    let relative = relative_note_path(vault, note_path)?;
+
+    let mut paths = load_list(vault, file_name)?;
+    let before = paths.len();
+    paths.retain(|p| p != &relative);
+    if paths.len() != before {
+        save_list(vault, file_name, &paths)?;
+    }
+    Ok(())
+}
+
+/// Point pinned and starred entries at a note's new location after a rename or move
+/// When `old` is a folder, entries inside it follow it to `new`
+pub(crate) fn repoint(vault: &Path, old: &Path, new: &Path) {
+    for file_name in [PINNED_FILE_NAME, STARRED_FILE_NAME] {
+        let Ok(mut paths) = load_list(vault, file_name) else {
+            continue;
+        };
+
+        let mut changed = false;
+        for path in &mut paths {
+            let moved = match Path::new(path.as_str()).strip_prefix(old) {
+                Ok(rest) if rest.as_os_str().is_empty() => new.to_path_buf(),
+                Ok(rest) => new.join(rest),
+                Err(_) => continue,
+            };
+            *path = moved.to_string_lossy().to_string();
+            changed = true;
+        }
+
+        if changed {
+            if let Err(e) = save_list(vault, file_name, &paths) {
+                eprintln!("Failed to update {}: {}", file_name, e);
+            }
+        }
+    }
+}
+
+/// Pinned relative paths (empty if unreadable)
+pub(crate) fn pinned_set(vault: &Path) -> HashSet<String> {
+    load_list(vault, PINNED_FILE_NAME)
+        .unwrap_or_default()
+        .into_iter()
+        .collect()
+}
+
+/// Starred relative paths (empty if unreadable)
+pub(crate) fn starred_set(vault: &Path) -> HashSet<String> {
+    load_list(vault, STARRED_FILE_NAME)
+        .unwrap_or_default()
+        .into_iter()
+        .collect()
+}
+
+#[command]
+pub async fn pin_note(vault_path: String, note_path: String) -> Result<(), String> {
+    add_to_list(&vault_path, &note_path, PINNED_FILE_NAME)
+}
+
+#[command]
+pub async fn unpin_note(vault_path: String, note_path: String) -> Result<(), String> {
+    remove_from_list(&vault_path, &note_path, PINNED_FILE_NAME)
+}
+
+/// Pinned notes in the order they were pinned
+#[command]
+pub async fn list_pinned_notes(vault_path: String) -> Result<Vec<String>, String> {
+    load_list(vault_dir(&vault_path)?, PINNED_FILE_NAME)
+}
+
+#[command]
+pub async fn star_note(vault_path: String, note_path: String) -> Result<(), String> {
+    add_to_list(&vault_path, &note_path, STARRED_FILE_NAME)
+}
+
+#[command]
+pub async fn unstar_note(vault_path: String, note_path: String) -> Result<(), String> {
+    remove_from_list(&vault_path, &note_path, STARRED_FILE_NAME)
+}
+
+/// Starred notes in the order they were starred
+#[command]
+pub async fn list_starred_notes(vault_path: String) -> Result<Vec<String>, String> {
+    load_list(vault_dir(&vault_path)?, STARRED_FILE_NAME)
+}
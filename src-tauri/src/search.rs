@@ -0,0 +1,914 @@
+use regex::RegexBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::command;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchMatch {
+    pub note_path: String, // Relative path
+    pub title: String,
+    pub line_number: usize, // 1-based
+    pub line_content: String,
+    pub match_start: usize, // Character offset within the line
+    pub match_end: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResultEnriched {
+    pub file_path: String,
+    pub content: String,
+    pub score: f32,
+    pub snippet_html: String, // Escaped HTML with query terms wrapped in <mark>
+    pub highlighted_terms: Vec<String>,
+    pub note_title: String,
+    pub note_tags: Vec<String>,
+    pub backlink_count: usize,
+}
+
+/// Stop collecting once this many matches are found
+const MAX_FULLTEXT_MATCHES: usize = 1000;
+
+// ============================================================================
+// Full-text Search
+// ============================================================================
+
+/// Recursively collect all markdown files, skipping hidden folders like .moss
+fn walk_dir(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), String> {
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                // Skip .moss directory and hidden folders
+                if let Some(name) = path.file_name() {
+                    if name.to_string_lossy().starts_with('.') {
+                        continue;
+                    }
+                }
+                walk_dir(&path, files)?;
+            } else if path.is_file() && path.extension().is_some_and(|ext| ext == "md") {
+                files.push(path);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Search note contents line by line, returning every match location
+///
+/// Works without the embedding index, so it's available as soon as a vault
+/// is opened. `query` is matched literally unless `use_regex` is set.
+#[command]
+pub async fn search_notes_fulltext(
+    vault_path: String,
+    query: String,
+    case_sensitive: bool,
+    use_regex: bool,
+) -> Result<Vec<SearchMatch>, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let pattern = if use_regex {
+        query.clone()
+    } else {
+        regex::escape(&query)
+    };
+    let regex = RegexBuilder::new(&pattern)
+        .case_insensitive(!case_sensitive)
+        .build()
+        .map_err(|e| format!("Invalid search pattern: {}", e))?;
+
+    let mut files = Vec::new();
+    walk_dir(vault, &mut files)?;
+    files.sort();
+
+    let mut matches = Vec::new();
+
+    'files: for path in files {
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(_) => continue,
+        };
+
+        let note_path = path
+            .strip_prefix(vault)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .to_string();
+        let title = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        for (index, line) in content.lines().enumerate() {
+            for found in regex.find_iter(line) {
+                if found.start() == found.end() {
+                    continue; // Ignore empty regex matches
+                }
+
+                matches.push(SearchMatch {
+                    note_path: note_path.clone(),
+                    title: title.clone(),
+                    line_number: index + 1,
+                    line_content: line.to_string(),
+                    match_start: line[..found.start()].chars().count(),
+                    match_end: line[..found.end()].chars().count(),
+                });
+
+                if matches.len() >= MAX_FULLTEXT_MATCHES {
+                    break 'files;
+                }
+            }
+        }
+    }
+
+    Ok(matches)
+}
+
+// ============================================================================
+// Search Result Snippets
+// ============================================================================
+
+/// Sentences on either side of the best-matching sentence kept in a snippet
+const SNIPPET_CONTEXT_SENTENCES: usize = 2;
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Split text into sentences on terminal punctuation and line breaks
+fn split_sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        let boundary = match c {
+            '\n' => true,
+            '.' | '!' | '?' => chars.peek().is_none_or(|(_, next)| next.is_whitespace()),
+            _ => false,
+        };
+
+        if boundary {
+            let end = i + c.len_utf8();
+            let sentence = text[start..end].trim();
+            if !sentence.is_empty() {
+                sentences.push(sentence);
+            }
+            start = end;
+        }
+    }
+
+    let rest = text[start..].trim();
+    if !rest.is_empty() {
+        sentences.push(rest);
+    }
+
+    sentences
+}
+
+/// Build an HTML snippet around the part of `text` that best matches `query`
+/// Returns the snippet and the query terms that were highlighted in it
+pub(crate) fn build_snippet(text: &str, query: &str) -> (String, Vec<String>) {
+    let mut terms: Vec<String> = Vec::new();
+    for term in query.split_whitespace() {
+        let term = term
+            .trim_matches(|c: char| !c.is_alphanumeric())
+            .to_lowercase();
+        if term.chars().count() >= 2 && !terms.contains(&term) {
+            terms.push(term);
+        }
+    }
+
+    let sentences = split_sentences(text);
+    if sentences.is_empty() {
+        return (String::new(), Vec::new());
+    }
+
+    // Centre the snippet on the sentence with the most term hits
+    let best = sentences
+        .iter()
+        .enumerate()
+        .max_by_key(|(i, sentence)| {
+            let lower = sentence.to_lowercase();
+            let hits: usize = terms
+                .iter()
+                .map(|t| lower.matches(t.as_str()).count())
+                .sum();
+            (hits, std::cmp::Reverse(*i))
+        })
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+
+    let start = best.saturating_sub(SNIPPET_CONTEXT_SENTENCES);
+    let end = (best + SNIPPET_CONTEXT_SENTENCES + 1).min(sentences.len());
+    let snippet = sentences[start..end].join(" ");
+
+    if terms.is_empty() {
+        return (escape_html(&snippet), Vec::new());
+    }
+
+    let pattern = terms
+        .iter()
+        .map(|t| regex::escape(t))
+        .collect::<Vec<_>>()
+        .join("|");
+    let term_regex = match RegexBuilder::new(&pattern).case_insensitive(true).build() {
+        Ok(regex) => regex,
+        Err(_) => return (escape_html(&snippet), Vec::new()),
+    };
+
+    let mut html = String::new();
+    let mut highlighted = HashSet::new();
+    let mut last_end = 0;
+
+    for found in term_regex.find_iter(&snippet) {
+        html.push_str(&escape_html(&snippet[last_end..found.start()]));
+        html.push_str("<mark>");
+        html.push_str(&escape_html(found.as_str()));
+        html.push_str("</mark>");
+        highlighted.insert(found.as_str().to_lowercase());
+        last_end = found.end();
+    }
+    html.push_str(&escape_html(&snippet[last_end..]));
+
+    let highlighted_terms = terms
+        .into_iter()
+        .filter(|t| highlighted.contains(t))
+        .collect();
+
+    (html, highlighted_terms)
+}
+
+/// Add snippets, titles, tags and backlink counts to semantic search hits
+/// `results` are `(relative file path, chunk content, score)` tuples
+pub(crate) fn enrich_results(
+    vault: &Path,
+    query: &str,
+    results: Vec<(String, String, f32)>,
+) -> Result<Vec<SearchResultEnriched>, String> {
+    // Tags per note from the tags cache
+    let tags_data = crate::tags::get_tags_data_with_cache(vault)?;
+    let mut tags_by_file: HashMap<&str, Vec<String>> = HashMap::new();
+    for info in &tags_data.tags {
+        for file in &info.files {
+            tags_by_file
+                .entry(file.as_str())
+                .or_default()
+                .push(info.tag.clone());
+        }
+    }
+
+    // Distinct linking notes per target from the graph cache
+    let graph_data = crate::graph::get_graph_data_with_cache(vault, None)?;
+    let mut backlinks: HashMap<&str, HashSet<&str>> = HashMap::new();
+    for link in &graph_data.links {
+        if link.source != link.target {
+            backlinks
+                .entry(link.target.as_str())
+                .or_default()
+                .insert(link.source.as_str());
+        }
+    }
+
+    let enriched = results
+        .into_iter()
+        .map(|(file_path, content, score)| {
+            let (snippet_html, highlighted_terms) = build_snippet(&content, query);
+
+            let note_title = Path::new(&file_path)
+                .file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_default();
+
+            let mut note_tags = tags_by_file
+                .get(file_path.as_str())
+                .cloned()
+                .unwrap_or_default();
+            note_tags.sort();
+
+            let node_id = vault.join(&file_path).to_string_lossy().to_string();
+            let backlink_count = backlinks.get(node_id.as_str()).map_or(0, |s| s.len());
+
+            SearchResultEnriched {
+                file_path,
+                content,
+                score,
+                snippet_html,
+                highlighted_terms,
+                note_title,
+                note_tags,
+                backlink_count,
+            }
+        })
+        .collect();
+
+    Ok(enriched)
+}
+
+// ============================================================================
+// Saved Searches
+// ============================================================================
+
+const SAVED_SEARCHES_FILE_NAME: &str = ".moss/saved_searches.json";
+
+/// Number of chunks fetched from the vector store for semantic saved searches
+const SEMANTIC_SEARCH_LIMIT: usize = 50;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchQuery {
+    pub text: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>, // Notes must have all of these tags
+    pub created_after: Option<u64>, // Unix timestamp
+    pub created_before: Option<u64>,
+    pub folder: Option<String>, // Relative folder to search within
+    #[serde(default)]
+    pub use_semantic: bool, // Match `text` by embeddings instead of substring
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedSearch {
+    pub name: String,
+    pub query: SearchQuery,
+    pub created_at: u64,
+    pub last_run_at: Option<u64>,
+}
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn saved_searches_path(vault_path: &str) -> Result<PathBuf, String> {
+    let vault = Path::new(vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+    Ok(vault.join(SAVED_SEARCHES_FILE_NAME))
+}
+
+fn load_saved_searches(path: &Path) -> Result<Vec<SavedSearch>, String> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content =
+        fs::read_to_string(path).map_err(|e| format!("Failed to read saved searches: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse saved searches: {}", e))
+}
+
+fn write_saved_searches(path: &Path, searches: &[SavedSearch]) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create .moss dir: {}", e))?;
+    }
+
+    let json = serde_json::to_string_pretty(searches)
+        .map_err(|e| format!("Failed to serialize saved searches: {}", e))?;
+    fs::write(path, json).map_err(|e| format!("Failed to write saved searches: {}", e))
+}
+
+/// Save a named search (replacing any existing search with the same name)
+#[command]
+pub async fn save_search(
+    vault_path: String,
+    name: String,
+    query: SearchQuery,
+) -> Result<(), String> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("Search name cannot be empty".to_string());
+    }
+
+    let path = saved_searches_path(&vault_path)?;
+    let mut searches = load_saved_searches(&path)?;
+
+    match searches.iter_mut().find(|s| s.name == name) {
+        Some(existing) => existing.query = query,
+        None => searches.push(SavedSearch {
+            name,
+            query,
+            created_at: now_secs(),
+            last_run_at: None,
+        }),
+    }
+
+    write_saved_searches(&path, &searches)
+}
+
+/// List all saved searches, alphabetically
+#[command]
+pub async fn list_saved_searches(vault_path: String) -> Result<Vec<SavedSearch>, String> {
+    let path = saved_searches_path(&vault_path)?;
+    let mut searches = load_saved_searches(&path)?;
+    searches.sort_by_key(|s| s.name.to_lowercase());
+    Ok(searches)
+}
+
+/// Delete a saved search by name
+#[command]
+pub async fn delete_saved_search(vault_path: String, name: String) -> Result<(), String> {
+    let path = saved_searches_path(&vault_path)?;
+    let mut searches = load_saved_searches(&path)?;
+
+    let before = searches.len();
+    searches.retain(|s| s.name != name);
+    if searches.len() == before {
+        return Err(format!("Saved search '{}' not found", name));
+    }
+
+    write_saved_searches(&path, &searches)
+}
+
+/// Relative paths of notes whose chunks best match `text` semantically
+async fn semantic_matches(
+    app_handle: &tauri::AppHandle,
+    vault: &Path,
+    text: &str,
+) -> Result<HashSet<String>, String> {
+    Ok(
+        semantic_scores(app_handle, vault, text, SEMANTIC_SEARCH_LIMIT)
+            .await?
+            .into_keys()
+            .collect(),
+    )
+}
+
+/// Best cosine similarity to `text` per note, over the top `limit` chunks
+async fn semantic_scores(
+    app_handle: &tauri::AppHandle,
+    vault: &Path,
+    text: &str,
+    limit: usize,
+) -> Result<HashMap<String, f32>, String> {
+    use crate::ai::AIProvider;
+
+    let api_key = crate::get_api_key("gemini".to_string()).await?;
+    let config = crate::ai::config::load_ai_config(app_handle)?;
+    let provider = crate::ai::gemini::GeminiProvider::new(api_key)
+        .with_rate_limiter(crate::ai::config::rate_limiter(&config, "gemini"));
+
+    let query_vector = provider.get_embedding(text).await?;
+    let store = crate::vector_store::VectorStore::open(&vault.join(".moss/vector_store.db"))?;
+
+    let mut scores: HashMap<String, f32> = HashMap::new();
+    for (chunk, score) in store.search(
+        &query_vector,
+        limit,
+        crate::vector_store::DistanceMetric::Cosine,
+    )? {
+        let best = scores.entry(chunk.file_path).or_insert(score);
+        *best = best.max(score);
+    }
+    Ok(scores)
+}
+
+/// Creation time of a note, falling back to modification time where unsupported
+fn created_secs(vault: &Path, note: &crate::tools::NoteMetadata) -> u64 {
+    fs::metadata(vault.join(&note.path))
+        .and_then(|m| m.created())
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or(note.modified)
+}
+
+/// Run a saved search against the vault and return the matching notes
+/// Results are ordered by most recently modified first
+#[command]
+pub async fn run_saved_search(
+    app_handle: tauri::AppHandle,
+    vault_path: String,
+    name: String,
+) -> Result<Vec<crate::tools::NoteMetadata>, String> {
+    let vault = Path::new(&vault_path);
+    let path = saved_searches_path(&vault_path)?;
+    let mut searches = load_saved_searches(&path)?;
+
+    let query = searches
+        .iter()
+        .find(|s| s.name == name)
+        .map(|s| s.query.clone())
+        .ok_or_else(|| format!("Saved search '{}' not found", name))?;
+
+    let search_root = match query.folder.as_deref().filter(|f| !f.trim().is_empty()) {
+        Some(folder) => vault.join(folder),
+        None => vault.to_path_buf(),
+    };
+    if !search_root.is_dir() {
+        return Err(format!(
+            "Folder '{}' does not exist",
+            query.folder.clone().unwrap_or_default()
+        ));
+    }
+
+    let mut notes = Vec::new();
+    crate::tools::collect_notes(&search_root, &mut notes, vault)?;
+
+    // Tag filter: a note must carry every requested tag
+    if !query.tags.is_empty() {
+        let tags_data = crate::tags::get_tags_data_with_cache(vault)?;
+        for tag in &query.tags {
+            let tag = tag.trim().trim_start_matches('#').to_lowercase();
+            let files: HashSet<&String> = tags_data
+                .tags
+                .iter()
+                .find(|info| info.tag == tag)
+                .map(|info| info.files.iter().collect())
+                .unwrap_or_default();
+            notes.retain(|note| files.contains(&note.path));
+        }
+    }
+
+    // Creation date filters (falls back to modification time where unsupported)
+    if query.created_after.is_some() || query.created_before.is_some() {
+        notes.retain(|note| {
+            let created = created_secs(vault, note);
+
+            query.created_after.is_none_or(|after| created >= after)
+                && query.created_before.is_none_or(|before| created <= before)
+        });
+    }
+
+    // Text filter
+    if let Some(text) = query.text.as_deref().filter(|t| !t.trim().is_empty()) {
+        if query.use_semantic {
+            let matches = semantic_matches(&app_handle, vault, text).await?;
+            notes.retain(|note| matches.contains(&note.path));
+        } else {
+            let needle = text.to_lowercase();
+            notes.retain(|note| {
+                note.title.to_lowercase().contains(&needle)
+                    || fs::read_to_string(vault.join(&note.path))
+                        .map(|content| content.to_lowercase().contains(&needle))
+                        .unwrap_or(false)
+            });
+        }
+    }
+
+    notes.sort_by_key(|note| std::cmp::Reverse(note.modified));
+
+    if let Some(saved) = searches.iter_mut().find(|s| s.name == name) {
+        saved.last_run_at = Some(now_secs());
+    }
+    write_saved_searches(&path, &searches)?;
+
+    Ok(notes)
+}
+
+// ============================================================================
+// Filtered Search
+// ============================================================================
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchFilter {
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub tag_mode: String, // "any" or "all" (default)
+    pub created_after: Option<u64>, // Unix timestamps
+    pub created_before: Option<u64>,
+    pub modified_after: Option<u64>,
+    pub folder_prefix: Option<String>, // Relative folder path
+    pub min_size: Option<u64>,         // Bytes
+    pub max_size: Option<u64>,
+}
+
+/// Find notes matching every filter criterion, then (optionally) a text query
+///
+/// Filters use file metadata and the tags cache, so the text search only
+/// reads the notes that survive them. Most recently modified first.
+#[command]
+pub async fn agent_filtered_search(
+    vault_path: String,
+    text_query: Option<String>,
+    filter: SearchFilter,
+    limit: usize,
+) -> Result<Vec<crate::tools::NoteMetadata>, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let match_all = match filter.tag_mode.as_str() {
+        "" | "all" => true,
+        "any" => false,
+        other => {
+            return Err(format!(
+                "Unknown tag mode '{}', expected 'any' or 'all'",
+                other
+            ))
+        }
+    };
+
+    let mut notes = Vec::new();
+    crate::tools::collect_notes(vault, &mut notes, vault)?;
+    notes.retain(|note| !note.is_encrypted);
+
+    if let Some(prefix) = filter
+        .folder_prefix
+        .as_deref()
+        .map(|p| p.trim().trim_matches('/'))
+        .filter(|p| !p.is_empty())
+    {
+        let prefix = Path::new(prefix);
+        notes.retain(|note| Path::new(&note.path).starts_with(prefix));
+    }
+
+    notes.retain(|note| {
+        filter.min_size.is_none_or(|min| note.size >= min)
+            && filter.max_size.is_none_or(|max| note.size <= max)
+            && filter
+                .modified_after
+                .is_none_or(|after| note.modified >= after)
+    });
+
+    if filter.created_after.is_some() || filter.created_before.is_some() {
+        notes.retain(|note| {
+            let created = created_secs(vault, note);
+            filter.created_after.is_none_or(|after| created >= after)
+                && filter.created_before.is_none_or(|before| created <= before)
+        });
+    }
+
+    if !filter.tags.is_empty() {
+        let tags_data = crate::tags::get_tags_data_with_cache(vault)?;
+        let tag_files: Vec<HashSet<&String>> = filter
+            .tags
+            .iter()
+            .map(|tag| {
+                let tag = tag.trim().trim_start_matches('#').to_lowercase();
+                tags_data
+                    .tags
+                    .iter()
+                    .find(|info| info.tag == tag)
+                    .map(|info| info.files.iter().collect())
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        notes.retain(|note| {
+            if match_all {
+                tag_files.iter().all(|files| files.contains(&note.path))
+            } else {
+                tag_files.iter().any(|files| files.contains(&note.path))
+            }
+        });
+    }
+
+    // Text query only runs over the filtered subset
+    if let Some(text) = text_query.as_deref().filter(|t| !t.trim().is_empty()) {
+        let needle = text.to_lowercase();
+        notes.retain(|note| {
+            note.title.to_lowercase().contains(&needle)
+                || fs::read_to_string(vault.join(&note.path))
+                    .map(|content| content.to_lowercase().contains(&needle))
+                    .unwrap_or(false)
+        });
+    }
+
+    notes.sort_by_key(|note| std::cmp::Reverse(note.modified));
+    notes.truncate(limit);
+
+    Ok(notes)
+}
+
+// ============================================================================
+// Ranked Search
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RankedSearchResult {
+    pub note_path: String, // Relative path
+    pub title: String,
+    pub final_score: f32,
+    pub bm25_score: f32,     // 0-1, relative to the best keyword match
+    pub semantic_score: f32, // 0-1 cosine similarity, 0 when not indexed
+    pub recency_score: f32,  // 1 for notes modified today
+    pub backlink_score: f32, // 0-1, relative to the most linked note
+    pub snippet: String,     // Escaped HTML with query terms wrapped in <mark>
+}
+
+/// Weights of the keyword, semantic, recency and backlink signals
+const RANKED_SEARCH_WEIGHTS: (f32, f32, f32, f32) = (0.4, 0.3, 0.2, 0.1);
+
+/// Chunks fetched from each index before ranking
+const RANKED_SEARCH_CANDIDATES: usize = 200;
+
+/// Okapi BM25 parameters for the fallback scorer
+const BM25_K1: f32 = 1.2;
+const BM25_B: f32 = 0.75;
+
+fn search_terms(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// BM25 over whole notes, for vaults without a keyword index
+fn grep_bm25_scores(notes: &[(String, String)], query: &str) -> HashMap<String, f32> {
+    let query_terms: HashSet<String> = search_terms(query).into_iter().collect();
+    if query_terms.is_empty() || notes.is_empty() {
+        return HashMap::new();
+    }
+
+    let documents: Vec<(&str, Vec<String>)> = notes
+        .iter()
+        .map(|(path, content)| (path.as_str(), search_terms(content)))
+        .collect();
+    let average_length = documents
+        .iter()
+        .map(|(_, terms)| terms.len())
+        .sum::<usize>() as f32
+        / documents.len() as f32;
+
+    let mut term_frequencies: Vec<HashMap<&str, usize>> = Vec::new();
+    let mut document_frequency: HashMap<&str, usize> = HashMap::new();
+    for (_, terms) in &documents {
+        let mut frequencies: HashMap<&str, usize> = HashMap::new();
+        for term in terms.iter().filter(|t| query_terms.contains(*t)) {
+            *frequencies.entry(term.as_str()).or_insert(0) += 1;
+        }
+        for term in frequencies.keys() {
+            *document_frequency.entry(term).or_insert(0) += 1;
+        }
+        term_frequencies.push(frequencies);
+    }
+
+    let total = documents.len() as f32;
+    let mut scores = HashMap::new();
+    for ((path, terms), frequencies) in documents.iter().zip(&term_frequencies) {
+        let length_norm = 1.0 - BM25_B + BM25_B * terms.len() as f32 / average_length.max(1.0);
+        let score: f32 = frequencies
+            .iter()
+            .map(|(term, &tf)| {
+                let df = document_frequency[term] as f32;
+                let idf = ((total - df + 0.5) / (df + 0.5) + 1.0).ln();
+                let tf = tf as f32;
+                idf * tf * (BM25_K1 + 1.0) / (tf + BM25_K1 * length_norm)
+            })
+            .sum();
+        if score > 0.0 {
+            scores.insert(path.to_string(), score);
+        }
+    }
+
+    scores
+}
+
+/// Search the vault combining keyword relevance, meaning, recency and links
+/// Keyword scores come from the FTS5 index when the vault is indexed and from a
+/// scan of every note otherwise; semantic scores are skipped when unavailable
+#[command]
+pub async fn search_notes_ranked(
+    app_handle: tauri::AppHandle,
+    vault_path: String,
+    query: String,
+    limit: usize,
+) -> Result<Vec<RankedSearchResult>, String> {
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+    if query.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut notes = Vec::new();
+    crate::tools::collect_notes(vault, &mut notes, vault)?;
+    notes.retain(|note| !note.is_encrypted);
+    let notes_by_path: HashMap<&str, &crate::tools::NoteMetadata> = notes
+        .iter()
+        .map(|note| (note.path.as_str(), note))
+        .collect();
+
+    // Keyword relevance, best chunk per note
+    let store_path = vault.join(".moss/vector_store.db");
+    let mut bm25_scores: HashMap<String, f32> = HashMap::new();
+    if store_path.exists() {
+        let index = crate::bm25::BM25Index::open(&store_path)?;
+        for (chunk, score) in index.search(&query, RANKED_SEARCH_CANDIDATES)? {
+            let best = bm25_scores.entry(chunk.file_path).or_insert(score);
+            *best = best.max(score);
+        }
+    }
+    let mut contents: HashMap<String, String> = HashMap::new();
+    if bm25_scores.is_empty() {
+        let documents: Vec<(String, String)> = notes
+            .iter()
+            .filter_map(|note| {
+                fs::read_to_string(vault.join(&note.path))
+                    .ok()
+                    .map(|content| (note.path.clone(), content))
+            })
+            .collect();
+        bm25_scores = grep_bm25_scores(&documents, &query);
+        contents.extend(documents);
+    }
+
+    // Meaning, when the vault is indexed and an embedding provider is available
+    let semantic = if store_path.exists() {
+        semantic_scores(&app_handle, vault, &query, RANKED_SEARCH_CANDIDATES)
+            .await
+            .unwrap_or_default()
+    } else {
+        HashMap::new()
+    };
+
+    // Distinct linking notes per target from the graph cache
+    let graph_data = crate::graph::get_graph_data_with_cache(vault, None)?;
+    let mut backlinks: HashMap<&str, HashSet<&str>> = HashMap::new();
+    for link in &graph_data.links {
+        if link.source != link.target {
+            backlinks
+                .entry(link.target.as_str())
+                .or_default()
+                .insert(link.source.as_str());
+        }
+    }
+    let max_backlink_log = backlinks
+        .values()
+        .map(|sources| (1.0 + sources.len() as f32).ln())
+        .fold(0.0, f32::max);
+
+    let max_bm25 = bm25_scores.values().copied().fold(0.0, f32::max);
+    let now = now_secs();
+    let (bm25_weight, semantic_weight, recency_weight, backlink_weight) = RANKED_SEARCH_WEIGHTS;
+
+    let candidates: HashSet<&String> = bm25_scores.keys().chain(semantic.keys()).collect();
+    let mut results: Vec<RankedSearchResult> = candidates
+        .into_iter()
+        .filter_map(|path| {
+            let note = notes_by_path.get(path.as_str())?;
+
+            let bm25_score = match bm25_scores.get(path) {
+                Some(score) if max_bm25 > 0.0 => score / max_bm25,
+                _ => 0.0,
+            };
+            let semantic_score = semantic.get(path).copied().unwrap_or(0.0).clamp(0.0, 1.0);
+
+            let days = now.saturating_sub(note.modified) as f32 / (24.0 * 60.0 * 60.0);
+            let recency_score = 1.0 / (1.0 + (1.0 + days).ln());
+
+            let node_id = vault.join(path).to_string_lossy().to_string();
+            let backlink_count = backlinks.get(node_id.as_str()).map_or(0, |s| s.len());
+            let backlink_score = if max_backlink_log > 0.0 {
+                (1.0 + backlink_count as f32).ln() / max_backlink_log
+            } else {
+                0.0
+            };
+
+            Some(RankedSearchResult {
+                note_path: path.clone(),
+                title: note.title.clone(),
+                final_score: bm25_weight * bm25_score
+                    + semantic_weight * semantic_score
+                    + recency_weight * recency_score
+                    + backlink_weight * backlink_score,
+                bm25_score,
+                semantic_score,
+                recency_score,
+                backlink_score,
+                snippet: String::new(),
+            })
+        })
+        .collect();
+
+    results.sort_by(|a, b| {
+        b.final_score
+            .partial_cmp(&a.final_score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.note_path.cmp(&b.note_path))
+    });
+    results.truncate(limit);
+
+    // Snippets only for the notes being returned
+    for result in &mut results {
+        let content = match contents.remove(&result.note_path) {
+            Some(content) => content,
+            None => fs::read_to_string(vault.join(&result.note_path)).unwrap_or_default(),
+        };
+        result.snippet = build_snippet(crate::frontmatter::strip(&content), &query).0;
+    }
+
+    Ok(results)
+}
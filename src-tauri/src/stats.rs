@@ -0,0 +1,556 @@
+use crate::tools::{collect_notes, NoteMetadata};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use tauri::command;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VaultStats {
+    pub total_notes: usize,
+    pub total_words: usize,
+    pub total_characters: usize,
+    pub total_links: usize,     // Wikilinks written in notes (resolved or not)
+    pub total_tags: usize,      // Distinct tags across the vault
+    pub total_backlinks: usize, // Wikilinks that resolve to another note
+    pub oldest_note: Option<NoteMetadata>,
+    pub newest_note: Option<NoteMetadata>,
+    pub most_linked_note: Option<(NoteMetadata, usize)>,
+    pub average_note_length: usize, // Average words per note
+    pub notes_with_tasks: usize,
+    pub vault_size_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GlobalStats {
+    pub per_vault: Vec<(String, VaultStats)>, // Vault path with its stats, in request order
+    pub total_notes: usize,
+    pub total_words: usize,
+    pub total_links: usize,
+    pub total_tags: usize, // Distinct tags across all vaults
+    pub combined_most_used_tags: Vec<(String, usize)>,
+}
+
+// ============================================================================
+// Vault Statistics
+// ============================================================================
+
+/// Aggregate statistics for the whole vault in a single call
+#[command]
+pub async fn get_vault_stats(vault_path: String) -> Result<VaultStats, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    compute_vault_stats(vault)
+}
+
+pub(crate) fn compute_vault_stats(vault: &Path) -> Result<VaultStats, String> {
+    let mut notes = Vec::new();
+    collect_notes(vault, &mut notes, vault)?;
+
+    let wikilink_regex = Regex::new(r"\[\[([^|\]]+)(?:\|([^\]]+))?\]\]").unwrap();
+    let task_regex = Regex::new(r"(?m)^\s*[-*+] \[[ xX]\]").unwrap();
+
+    let mut total_words = 0;
+    let mut total_characters = 0;
+    let mut total_links = 0;
+    let mut notes_with_tasks = 0;
+
+    for note in &notes {
+        let content = match fs::read_to_string(vault.join(&note.path)) {
+            Ok(content) => content,
+            Err(_) => continue,
+        };
+
+        total_words += content.split_whitespace().count();
+        total_characters += content.chars().count();
+        total_links += wikilink_regex.find_iter(&content).count();
+
+        if task_regex.is_match(&content) {
+            notes_with_tasks += 1;
+        }
+    }
+
+    // Resolved links and inbound counts come from the graph cache
+    let graph_data = crate::graph::get_graph_data_with_cache(vault, None)?;
+    let mut inbound: HashMap<&str, usize> = HashMap::new();
+    for link in &graph_data.links {
+        *inbound.entry(link.target.as_str()).or_insert(0) += 1;
+    }
+
+    let most_linked_note = inbound
+        .iter()
+        .max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+        .and_then(|(id, count)| {
+            crate::tools::create_note_metadata(Path::new(id), vault).map(|meta| (meta, *count))
+        });
+
+    let total_tags = crate::tags::get_tags_data_with_cache(vault)?.tags.len();
+
+    let oldest_note = notes.iter().min_by_key(|note| note.modified).cloned();
+    let newest_note = notes.iter().max_by_key(|note| note.modified).cloned();
+
+    let average_note_length = if notes.is_empty() {
+        0
+    } else {
+        total_words / notes.len()
+    };
+
+    Ok(VaultStats {
+        total_notes: notes.len(),
+        total_words,
+        total_characters,
+        total_links,
+        total_tags,
+        total_backlinks: graph_data.links.len(),
+        oldest_note,
+        newest_note,
+        most_linked_note,
+        average_note_length,
+        notes_with_tasks,
+        vault_size_bytes: directory_size(vault),
+    })
+}
+
+/// Total size of all visible files under a directory
+fn directory_size(dir: &Path) -> u64 {
+    let mut size = 0;
+
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+
+            // Skip hidden files and directories (.git, .moss, ...)
+            if let Some(name) = path.file_name() {
+                if name.to_string_lossy().starts_with('.') {
+                    continue;
+                }
+            }
+
+            if path.is_dir() {
+                size += directory_size(&path);
+            } else if let Ok(metadata) = entry.metadata() {
+                size += metadata.len();
+            }
+        }
+    }
+
+    size
+}
+
+// ============================================================================
+// Multi-Vault Statistics
+// ============================================================================
+
+/// Most tags listed in `combined_most_used_tags`
+const MAX_COMBINED_TAGS: usize = 20;
+
+/// Stats and tag counts for one vault, for merging into `GlobalStats`
+fn vault_stats_with_tags(vault_path: &str) -> Result<(VaultStats, Vec<(String, usize)>), String> {
+    let vault = Path::new(vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let stats = compute_vault_stats(vault)?;
+    let tag_counts = crate::tags::get_tags_data_with_cache(vault)?
+        .tags
+        .into_iter()
+        .map(|info| (info.tag, info.count))
+        .collect();
+
+    Ok((stats, tag_counts))
+}
+
+/// Combined statistics for several vaults, computed in parallel
+/// Tag counts are merged by name, so a tag used in two vaults counts both
+#[command]
+pub async fn get_global_stats(vault_paths: Vec<String>) -> Result<GlobalStats, String> {
+    let tasks = vault_paths
+        .iter()
+        .cloned()
+        .map(|vault_path| tokio::task::spawn_blocking(move || vault_stats_with_tags(&vault_path)));
+    let results = futures::future::join_all(tasks).await;
+
+    let mut per_vault = Vec::new();
+    let mut tag_counts: HashMap<String, usize> = HashMap::new();
+    for (vault_path, result) in vault_paths.into_iter().zip(results) {
+        let (stats, tags) = result.map_err(|e| format!("Failed to compute stats: {}", e))??;
+        for (tag, count) in tags {
+            *tag_counts.entry(tag).or_insert(0) += count;
+        }
+        per_vault.push((vault_path, stats));
+    }
+
+    let total_tags = tag_counts.len();
+    let mut combined_most_used_tags: Vec<(String, usize)> = tag_counts.into_iter().collect();
+    combined_most_used_tags.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    combined_most_used_tags.truncate(MAX_COMBINED_TAGS);
+
+    Ok(GlobalStats {
+        total_notes: per_vault.iter().map(|(_, s)| s.total_notes).sum(),
+        total_words: per_vault.iter().map(|(_, s)| s.total_words).sum(),
+        total_links: per_vault.iter().map(|(_, s)| s.total_links).sum(),
+        total_tags,
+        combined_most_used_tags,
+        per_vault,
+    })
+}
+
+// ============================================================================
+// Note Statistics
+// ============================================================================
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NoteStats {
+    pub word_count: usize,
+    pub character_count: usize,
+    pub character_count_no_spaces: usize,
+    pub line_count: usize,
+    pub paragraph_count: usize,
+    pub estimated_reading_time_seconds: usize,
+    pub heading_count: usize,
+    pub link_count: usize, // Wikilinks and markdown links
+    pub task_count: usize,
+    pub completed_task_count: usize,
+    pub code_block_count: usize,
+}
+
+/// Average silent reading speed used for reading time estimates
+const WORDS_PER_MINUTE: usize = 200;
+
+impl NoteStats {
+    fn add(&mut self, other: &NoteStats) {
+        self.word_count += other.word_count;
+        self.character_count += other.character_count;
+        self.character_count_no_spaces += other.character_count_no_spaces;
+        self.line_count += other.line_count;
+        self.paragraph_count += other.paragraph_count;
+        self.estimated_reading_time_seconds += other.estimated_reading_time_seconds;
+        self.heading_count += other.heading_count;
+        self.link_count += other.link_count;
+        self.task_count += other.task_count;
+        self.completed_task_count += other.completed_task_count;
+        self.code_block_count += other.code_block_count;
+    }
+}
+
+/// Compute statistics for note content (frontmatter is ignored)
+/// Headings, links and tasks inside fenced code blocks aren't counted
+fn compute_note_stats(content: &str) -> NoteStats {
+    let body = crate::frontmatter::strip(content);
+
+    let word_regex = Regex::new(r"\b\w+\b").unwrap();
+    let heading_regex = Regex::new(r"^#{1,6}\s").unwrap();
+    let link_regex = Regex::new(r"\[\[[^\]]+\]\]|\[[^\]]*\]\([^)]+\)").unwrap();
+    let task_regex = Regex::new(r"^\s*[-*+] \[([ xX])\]").unwrap();
+
+    let mut stats = NoteStats {
+        word_count: word_regex.find_iter(body).count(),
+        character_count: body.chars().count(),
+        character_count_no_spaces: body.chars().filter(|c| !c.is_whitespace()).count(),
+        line_count: body.lines().count(),
+        ..Default::default()
+    };
+
+    stats.estimated_reading_time_seconds = (stats.word_count * 60).div_ceil(WORDS_PER_MINUTE);
+
+    let mut in_code_block = false;
+    let mut in_paragraph = false;
+
+    for line in body.lines() {
+        if line.trim_start().starts_with("```") {
+            if !in_code_block {
+                stats.code_block_count += 1;
+            }
+            in_code_block = !in_code_block;
+            in_paragraph = false;
+            continue;
+        }
+
+        if in_code_block {
+            continue;
+        }
+
+        // Paragraphs are runs of non-blank lines
+        if line.trim().is_empty() {
+            in_paragraph = false;
+        } else if !in_paragraph {
+            stats.paragraph_count += 1;
+            in_paragraph = true;
+        }
+
+        if heading_regex.is_match(line) {
+            stats.heading_count += 1;
+        }
+
+        stats.link_count += link_regex.find_iter(line).count();
+
+        if let Some(caps) = task_regex.captures(line) {
+            stats.task_count += 1;
+            if &caps[1] != " " {
+                stats.completed_task_count += 1;
+            }
+        }
+    }
+
+    stats
+}
+
+/// Word, character, structure and reading time statistics for one note
+#[command]
+pub async fn get_note_stats(vault_path: String, note_path: String) -> Result<NoteStats, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let path = crate::tools::resolve_note_path(vault, &note_path)
+        .ok_or_else(|| format!("Note '{}' not found", note_path))?;
+    let content = fs::read_to_string(&path).map_err(|e| format!("Failed to read note: {}", e))?;
+
+    Ok(compute_note_stats(&content))
+}
+
+/// Aggregate note statistics across every note in a folder (including subfolders)
+#[command]
+pub async fn get_folder_stats(
+    vault_path: String,
+    folder_path: String,
+) -> Result<NoteStats, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let folder = vault.join(&folder_path);
+    if !folder.is_dir() {
+        return Err(format!("Folder '{}' does not exist", folder_path));
+    }
+
+    let mut notes = Vec::new();
+    collect_notes(&folder, &mut notes, vault)?;
+
+    let mut totals = NoteStats::default();
+    for note in &notes {
+        if let Ok(content) = fs::read_to_string(vault.join(&note.path)) {
+            totals.add(&compute_note_stats(&content));
+        }
+    }
+
+    Ok(totals)
+}
+
+// ============================================================================
+// Readability
+// ============================================================================
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReadabilityReport {
+    pub flesch_reading_ease: f32,  // 0-100, higher is easier
+    pub flesch_kincaid_grade: f32, // US school grade level
+    pub avg_sentence_length: f32,  // Words per sentence
+    pub avg_word_length: f32,      // Letters per word
+    pub long_sentence_count: usize,
+    pub complex_word_count: usize,
+}
+
+/// Sentences with more words than this count as long
+const LONG_SENTENCE_WORDS: usize = 20;
+
+/// Words with at least this many syllables count as complex
+const COMPLEX_WORD_SYLLABLES: usize = 3;
+
+/// Raw counts behind a readability report, summed across notes for folders
+#[derive(Debug, Default)]
+struct TextCounts {
+    words: usize,
+    sentences: usize,
+    syllables: usize,
+    letters: usize,
+    long_sentences: usize,
+    complex_words: usize,
+}
+
+impl TextCounts {
+    fn add(&mut self, other: &TextCounts) {
+        self.words += other.words;
+        self.sentences += other.sentences;
+        self.syllables += other.syllables;
+        self.letters += other.letters;
+        self.long_sentences += other.long_sentences;
+        self.complex_words += other.complex_words;
+    }
+
+    fn report(&self) -> ReadabilityReport {
+        if self.words == 0 || self.sentences == 0 {
+            return ReadabilityReport::default();
+        }
+
+        let words_per_sentence = self.words as f32 / self.sentences as f32;
+        let syllables_per_word = self.syllables as f32 / self.words as f32;
+
+        ReadabilityReport {
+            flesch_reading_ease: 206.835 - 1.015 * words_per_sentence - 84.6 * syllables_per_word,
+            flesch_kincaid_grade: 0.39 * words_per_sentence + 11.8 * syllables_per_word - 15.59,
+            avg_sentence_length: words_per_sentence,
+            avg_word_length: self.letters as f32 / self.words as f32,
+            long_sentence_count: self.long_sentences,
+            complex_word_count: self.complex_words,
+        }
+    }
+}
+
+/// Estimate syllables by counting vowel groups, ignoring a silent final `e`
+fn count_syllables(word: &str) -> usize {
+    let word = word.to_lowercase();
+    let is_vowel = |c: char| matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y');
+
+    let mut count = 0;
+    let mut previous_vowel = false;
+    for c in word.chars() {
+        let vowel = is_vowel(c);
+        if vowel && !previous_vowel {
+            count += 1;
+        }
+        previous_vowel = vowel;
+    }
+
+    if count > 1 && word.ends_with('e') && !word.ends_with("le") && !word.ends_with("ee") {
+        count -= 1;
+    }
+
+    count.max(1)
+}
+
+/// Prose of a note: no frontmatter, code or Markdown syntax
+/// Headings and list items become their own sentences
+fn readable_text(content: &str) -> String {
+    let inline_code_regex = Regex::new(r"`[^`]*`").unwrap();
+    let image_regex = Regex::new(r"!\[[^\]]*\]\([^)]*\)").unwrap();
+    let link_regex = Regex::new(r"\[([^\]]*)\]\([^)]*\)").unwrap();
+    let wikilink_regex = Regex::new(r"\[\[(?:[^\]|]*\|)?([^\]]*)\]\]").unwrap();
+    let block_regex =
+        Regex::new(r"^\s*(?:#{1,6}\s|[-*+]\s(?:\[[ xX]\]\s)?|\d+[.)]\s|>\s?)").unwrap();
+
+    let mut text = String::new();
+    let mut in_code_block = false;
+
+    for line in crate::frontmatter::strip(content).lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+
+        let is_block = block_regex.is_match(line);
+        let line = block_regex.replace(line, "");
+        let line = inline_code_regex.replace_all(&line, "");
+        let line = image_regex.replace_all(&line, "");
+        let line = link_regex.replace_all(&line, "$1");
+        let line = wikilink_regex.replace_all(&line, "$1");
+        let line = line.trim();
+
+        // A blank line or a new block ends any unterminated sentence
+        if line.is_empty() || is_block {
+            text.push_str(".\n");
+        }
+        text.push_str(line);
+        text.push(' ');
+    }
+
+    text
+}
+
+fn compute_text_counts(content: &str) -> TextCounts {
+    let sentence_end_regex = Regex::new(r"[.!?]+").unwrap();
+    let word_regex = Regex::new(r"[\p{L}]+(?:['’][\p{L}]+)*").unwrap();
+
+    let mut counts = TextCounts::default();
+    for sentence in sentence_end_regex.split(&readable_text(content)) {
+        let words: Vec<&str> = word_regex.find_iter(sentence).map(|m| m.as_str()).collect();
+        if words.is_empty() {
+            continue;
+        }
+
+        counts.sentences += 1;
+        counts.words += words.len();
+        if words.len() > LONG_SENTENCE_WORDS {
+            counts.long_sentences += 1;
+        }
+
+        for word in words {
+            let syllables = count_syllables(word);
+            counts.syllables += syllables;
+            counts.letters += word.chars().filter(|c| c.is_alphabetic()).count();
+            if syllables >= COMPLEX_WORD_SYLLABLES {
+                counts.complex_words += 1;
+            }
+        }
+    }
+
+    counts
+}
+
+/// Flesch reading ease and Flesch-Kincaid grade level for one note
+#[command]
+pub async fn compute_readability_score(
+    vault_path: String,
+    note_path: String,
+) -> Result<ReadabilityReport, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let path = crate::tools::resolve_note_path(vault, &note_path)
+        .ok_or_else(|| format!("Note '{}' not found", note_path))?;
+    let content = fs::read_to_string(&path).map_err(|e| format!("Failed to read note: {}", e))?;
+
+    Ok(compute_text_counts(&content).report())
+}
+
+/// Readability across every note in a folder (including subfolders)
+/// Scores are computed over the combined text, so longer notes weigh more
+#[command]
+pub async fn compute_folder_readability_average(
+    vault_path: String,
+    folder_path: String,
+) -> Result<ReadabilityReport, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let folder = vault.join(&folder_path);
+    if !folder.is_dir() {
+        return Err(format!("Folder '{}' does not exist", folder_path));
+    }
+
+    let mut notes = Vec::new();
+    collect_notes(&folder, &mut notes, vault)?;
+
+    let mut totals = TextCounts::default();
+    for note in &notes {
+        if let Ok(content) = fs::read_to_string(vault.join(&note.path)) {
+            totals.add(&compute_text_counts(&content));
+        }
+    }
+
+    Ok(totals.report())
+}
@@ -7,9 +7,19 @@ use std::time::SystemTime;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct TagInfo {
-    pub tag: String,        // Normalized lowercase tag name
-    pub count: usize,       // Number of notes with this tag
-    pub files: Vec<String>, // Relative paths of notes with this tag
+    pub tag: String,              // Normalized lowercase tag name
+    pub count: usize,             // Number of notes with this tag
+    pub files: Vec<String>,       // Relative paths of notes with this tag
+    pub frontmatter_count: usize, // Number of notes declaring this tag in frontmatter
+    pub parent: Option<String>,   // Parent tag for nested tags (e.g. "project" for "project/rust")
+    pub children: Vec<String>,    // Direct child tags
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TagNode {
+    pub tag: String, // Full tag path (e.g. "project/rust")
+    pub count: usize,
+    pub children: Vec<TagNode>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -19,9 +29,10 @@ pub struct TagsData {
 
 #[derive(Debug, Serialize, Deserialize)]
 struct CachedFile {
-    path: String,       // Relative path
-    tags: Vec<String>,  // Normalized tags found in this file
-    last_modified: u64, // Timestamp for cache invalidation
+    path: String,                  // Relative path
+    tags: Vec<String>,             // Normalized tags found in this file
+    frontmatter_tags: Vec<String>, // Subset of tags declared in frontmatter
+    last_modified: u64,            // Timestamp for cache invalidation
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -30,21 +41,23 @@ struct TagsCache {
     files: HashMap<String, CachedFile>, // Key is relative path
 }
 
-const CACHE_VERSION: u32 = 2;
+const CACHE_VERSION: u32 = 4;
 const CACHE_FILE_NAME: &str = ".moss/tags_cache.json";
 
+/// Inline tag pattern, supporting nested tags like #project/rust/async
+const INLINE_TAG_PATTERN: &str = r"#([a-zA-Z0-9_-]+(?:/[a-zA-Z0-9_-]+)*)";
+
 /// Remove inline code from a line (text between backticks)
 fn remove_inline_code(line: &str) -> String {
     let inline_code_regex = Regex::new(r"`[^`]+`").unwrap();
     inline_code_regex.replace_all(line, "").to_string()
 }
 
-/// Extract tags from markdown content
+/// Extract inline tags from markdown content
 /// Tags are in the format #tag-name and are case-insensitive
 /// Tags inside code blocks and inline code are excluded
-fn extract_tags_from_content(content: &str) -> Vec<String> {
-    // Require at least 2 characters to avoid noise like #1
-    let tag_regex = Regex::new(r"#([a-zA-Z0-9_-]{2,})").unwrap();
+fn extract_inline_tags(content: &str) -> Vec<String> {
+    let tag_regex = Regex::new(INLINE_TAG_PATTERN).unwrap();
     let mut tags = HashSet::new();
 
     let mut in_code_block = false;
@@ -67,14 +80,63 @@ fn extract_tags_from_content(content: &str) -> Vec<String> {
     // Extract tags from cleaned content
     for cap in tag_regex.captures_iter(&cleaned_content) {
         if let Some(tag) = cap.get(1) {
-            // Normalize to lowercase
-            tags.insert(tag.as_str().to_lowercase());
+            // Require at least 2 characters to avoid noise like #1
+            if tag.as_str().len() >= 2 {
+                // Normalize to lowercase
+                tags.insert(tag.as_str().to_lowercase());
+            }
         }
     }
 
     tags.into_iter().collect()
 }
 
+/// Extract tags declared in the YAML frontmatter `tags:` key
+/// Invalid frontmatter is ignored rather than failing the whole scan
+fn extract_frontmatter_tags(content: &str) -> Vec<String> {
+    let mapping = match crate::frontmatter::parse(content) {
+        Ok(mapping) => mapping,
+        Err(_) => return Vec::new(),
+    };
+
+    let tags: HashSet<String> = crate::frontmatter::tags(&mapping)
+        .into_iter()
+        .map(|tag| tag.to_lowercase())
+        .collect();
+
+    tags.into_iter().collect()
+}
+
+/// Expand nested tags so every ancestor is present too
+/// e.g. "project/rust/async" -> "project", "project/rust", "project/rust/async"
+fn expand_tag_hierarchy(tags: Vec<String>) -> Vec<String> {
+    let mut expanded = HashSet::new();
+
+    for tag in tags {
+        let mut prefix = String::new();
+        for segment in tag.split('/').filter(|s| !s.is_empty()) {
+            if !prefix.is_empty() {
+                prefix.push('/');
+            }
+            prefix.push_str(segment);
+            expanded.insert(prefix.clone());
+        }
+    }
+
+    expanded.into_iter().collect()
+}
+
+/// Extract all tags from markdown content (inline hashtags and frontmatter)
+/// Nested tags also count towards each of their ancestors
+pub(crate) fn extract_tags_from_content(content: &str) -> Vec<String> {
+    let body = crate::frontmatter::strip(content);
+
+    let mut tags = extract_inline_tags(body);
+    tags.extend(extract_frontmatter_tags(content));
+
+    expand_tag_hierarchy(tags)
+}
+
 /// Recursively walk directory to find markdown files
 fn walk_dir(dir: &Path, files: &mut HashMap<String, PathBuf>) -> Result<(), String> {
     if let Ok(entries) = fs::read_dir(dir) {
@@ -169,12 +231,14 @@ pub fn get_tags_data_with_cache(vault_path: &Path) -> Result<TagsData, String> {
                 .map_err(|e| format!("Failed to read {}: {}", relative_path, e))?;
 
             let tags = extract_tags_from_content(&content);
+            let frontmatter_tags = expand_tag_hierarchy(extract_frontmatter_tags(&content));
 
             cache.files.insert(
                 relative_path.clone(),
                 CachedFile {
                     path: relative_path.clone(),
                     tags,
+                    frontmatter_tags,
                     last_modified: modified,
                 },
             );
@@ -200,23 +264,507 @@ pub fn get_tags_data_with_cache(vault_path: &Path) -> Result<TagsData, String> {
 
     for cached_file in cache.files.values() {
         for tag in &cached_file.tags {
+            let in_frontmatter = usize::from(cached_file.frontmatter_tags.contains(tag));
             tag_map
                 .entry(tag.clone())
                 .and_modify(|info| {
                     info.count += 1;
+                    info.frontmatter_count += in_frontmatter;
                     info.files.push(cached_file.path.clone());
                 })
                 .or_insert(TagInfo {
                     tag: tag.clone(),
                     count: 1,
                     files: vec![cached_file.path.clone()],
+                    frontmatter_count: in_frontmatter,
+                    parent: None,
+                    children: Vec::new(),
                 });
         }
     }
 
+    // Second pass: wire up parent/child relationships for nested tags
+    let tag_names: Vec<String> = tag_map.keys().cloned().collect();
+    for tag in &tag_names {
+        if let Some((parent, _)) = tag.rsplit_once('/') {
+            if let Some(info) = tag_map.get_mut(tag) {
+                info.parent = Some(parent.to_string());
+            }
+            if let Some(parent_info) = tag_map.get_mut(parent) {
+                parent_info.children.push(tag.clone());
+            }
+        }
+    }
+    for info in tag_map.values_mut() {
+        info.children.sort();
+    }
+
     // Convert to sorted vec (by count descending, then alphabetically)
     let mut tags: Vec<TagInfo> = tag_map.into_values().collect();
     tags.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.tag.cmp(&b.tag)));
 
     Ok(TagsData { tags })
 }
+
+/// Build a nested tag tree from the flat tag list
+fn build_tag_tree(tags: &[TagInfo]) -> Vec<TagNode> {
+    let by_name: HashMap<&str, &TagInfo> = tags.iter().map(|t| (t.tag.as_str(), t)).collect();
+
+    fn build_node(info: &TagInfo, by_name: &HashMap<&str, &TagInfo>) -> TagNode {
+        let mut children: Vec<TagNode> = info
+            .children
+            .iter()
+            .filter_map(|child| by_name.get(child.as_str()))
+            .map(|child| build_node(child, by_name))
+            .collect();
+        children.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.tag.cmp(&b.tag)));
+
+        TagNode {
+            tag: info.tag.clone(),
+            count: info.count,
+            children,
+        }
+    }
+
+    // `tags` is already sorted by count, so roots keep that order
+    tags.iter()
+        .filter(|t| t.parent.is_none())
+        .map(|t| build_node(t, &by_name))
+        .collect()
+}
+
+/// Get all tags as a tree following nested tag paths
+#[tauri::command]
+pub async fn get_tag_tree(vault_path: String) -> Result<Vec<TagNode>, String> {
+    let path = Path::new(&vault_path);
+    if !path.exists() || !path.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let tags_data = get_tags_data_with_cache(path)?;
+    Ok(build_tag_tree(&tags_data.tags))
+}
+
+// ============================================================================
+// Tag Format Conversion
+// ============================================================================
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TagConversion {
+    pub path: String, // Relative path
+    pub changed: bool,
+}
+
+/// Byte ranges of a line where `#` never starts a tag: inline code, wikilinks
+/// (`[[Note#Heading]]`), Markdown link targets and bare URLs
+fn protected_ranges(line: &str) -> Vec<(usize, usize)> {
+    let protected_regex =
+        Regex::new(r"`[^`]*`|\[\[[^\]]*\]\]|\]\([^)]*\)|<[^>\s]+>|[a-zA-Z][a-zA-Z0-9+.-]*://\S+")
+            .unwrap();
+    protected_regex
+        .find_iter(line)
+        .map(|m| (m.start(), m.end()))
+        .collect()
+}
+
+/// Remove inline #tags from a note body, returning the new body and the
+/// (lowercased) tags removed
+///
+/// Only tags starting a word are removed; code, links and URLs are left
+/// untouched. Each tag takes one neighbouring space with it, everything else
+/// (indentation, line endings) is kept, and lines that only contained tags
+/// are dropped.
+fn remove_inline_tags(body: &str) -> (String, Vec<String>) {
+    let tag_regex = Regex::new(INLINE_TAG_PATTERN).unwrap();
+    let mut in_code_block = false;
+    let mut result = String::with_capacity(body.len());
+    let mut removed_tags = HashSet::new();
+
+    for line in body.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code_block = !in_code_block;
+            result.push_str(line);
+            continue;
+        }
+        if in_code_block {
+            result.push_str(line);
+            continue;
+        }
+
+        let content_end = line.trim_end_matches(['\r', '\n']).len();
+        let text = &line[..content_end];
+        let protected = protected_ranges(text);
+        let bytes = text.as_bytes();
+
+        let mut removals: Vec<(usize, usize)> = Vec::new();
+        for caps in tag_regex.captures_iter(text) {
+            let whole = caps.get(0).unwrap();
+            let tag = caps.get(1).unwrap().as_str();
+            // Require at least 2 characters to avoid noise like #1
+            if tag.len() < 2 {
+                continue;
+            }
+            let (start, end) = (whole.start(), whole.end());
+            if start > 0 && !bytes[start - 1].is_ascii_whitespace() {
+                continue;
+            }
+            if protected.iter().any(|&(s, e)| start < e && end > s) {
+                continue;
+            }
+
+            // Take one neighbouring space so no double gap is left behind
+            let range = if start > 0 && bytes[start - 1] == b' ' {
+                (start - 1, end)
+            } else if bytes.get(end) == Some(&b' ') {
+                (start, end + 1)
+            } else {
+                (start, end)
+            };
+            if removals
+                .last()
+                .is_some_and(|&(_, last_end)| range.0 < last_end)
+            {
+                removals.push((start, end));
+            } else {
+                removals.push(range);
+            }
+            removed_tags.insert(tag.to_lowercase());
+        }
+
+        if removals.is_empty() {
+            result.push_str(line);
+            continue;
+        }
+
+        let mut rebuilt = String::with_capacity(text.len());
+        let mut last = 0;
+        for (start, end) in removals {
+            rebuilt.push_str(&text[last..start]);
+            last = end;
+        }
+        rebuilt.push_str(&text[last..]);
+
+        if rebuilt.trim().is_empty() {
+            continue;
+        }
+        result.push_str(&rebuilt);
+        result.push_str(&line[content_end..]);
+    }
+
+    (result, removed_tags.into_iter().collect())
+}
+
+/// Move inline tags into frontmatter (or the reverse) for a single note
+/// Returns the new content, or `None` when nothing needs to change
+fn convert_note_tags(content: &str, to: &str) -> Result<Option<String>, String> {
+    let mut mapping = crate::frontmatter::parse(content)?;
+    let body = crate::frontmatter::strip(content);
+    let frontmatter_tags = crate::frontmatter::tags(&mapping);
+    let inline_tags = extract_inline_tags(body);
+
+    match to {
+        "frontmatter" => {
+            // Only tags actually removed from the body move to frontmatter
+            let (new_body, mut moved_tags) = remove_inline_tags(body);
+            if moved_tags.is_empty() {
+                return Ok(None);
+            }
+
+            let mut merged = frontmatter_tags.clone();
+            moved_tags.sort();
+            for tag in moved_tags {
+                if !merged.iter().any(|t| t.to_lowercase() == tag) {
+                    merged.push(tag);
+                }
+            }
+
+            crate::frontmatter::set_tags(&mut mapping, &merged);
+            crate::frontmatter::compose(&mapping, &new_body).map(Some)
+        }
+        "inline" => {
+            if frontmatter_tags.is_empty() {
+                return Ok(None);
+            }
+
+            // Tags that can't be written as #tag stay in frontmatter
+            let inline_tag_regex = Regex::new(r"^[a-zA-Z0-9_-]+(?:/[a-zA-Z0-9_-]+)*$").unwrap();
+            let (movable, remaining): (Vec<String>, Vec<String>) = frontmatter_tags
+                .into_iter()
+                .partition(|tag| inline_tag_regex.is_match(tag));
+
+            if movable.is_empty() {
+                return Ok(None);
+            }
+
+            let to_add: Vec<String> = movable
+                .iter()
+                .map(|tag| tag.to_lowercase())
+                .filter(|tag| !inline_tags.contains(tag))
+                .map(|tag| format!("#{}", tag))
+                .collect();
+
+            crate::frontmatter::set_tags(&mut mapping, &remaining);
+
+            let mut new_body = body.to_string();
+            if !to_add.is_empty() {
+                new_body = format!("{}\n\n{}\n", new_body.trim_end(), to_add.join(" "));
+            }
+
+            crate::frontmatter::compose(&mapping, &new_body).map(Some)
+        }
+        _ => Err(format!(
+            "Unknown tag format '{}', expected 'frontmatter' or 'inline'",
+            to
+        )),
+    }
+}
+
+/// Convert tags across the vault between inline #tags and the frontmatter `tags:` list
+#[tauri::command]
+pub async fn convert_tags_format(
+    vault_path: String,
+    to: String,
+    dry_run: bool,
+) -> Result<Vec<TagConversion>, String> {
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    if to != "frontmatter" && to != "inline" {
+        return Err(format!(
+            "Unknown tag format '{}', expected 'frontmatter' or 'inline'",
+            to
+        ));
+    }
+
+    let mut files = HashMap::new();
+    walk_dir(vault, &mut files)?;
+
+    let mut paths: Vec<PathBuf> = files.into_values().collect();
+    paths.sort();
+
+    let mut results = Vec::new();
+    let mut changed_paths = Vec::new();
+
+    for path in paths {
+        let relative_path = path
+            .strip_prefix(vault)
+            .map_err(|_| "Failed to get relative path")?
+            .to_string_lossy()
+            .to_string();
+
+        let content = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", relative_path, e))?;
+
+        let new_content = match convert_note_tags(&content, &to) {
+            Ok(new_content) => new_content.filter(|c| *c != content),
+            Err(e) => {
+                eprintln!("Skipping {}: {}", relative_path, e);
+                None
+            }
+        };
+
+        let changed = new_content.is_some();
+        if let Some(new_content) = new_content {
+            if !dry_run {
+                fs::write(&path, new_content)
+                    .map_err(|e| format!("Failed to write {}: {}", relative_path, e))?;
+                changed_paths.push(path.clone());
+            }
+        }
+
+        results.push(TagConversion {
+            path: relative_path,
+            changed,
+        });
+    }
+
+    // Auto-commit if Git repository
+    if !changed_paths.is_empty() {
+        if let Some(repo) = crate::git_manager::open_repository(vault) {
+            let file_refs: Vec<&Path> = changed_paths.iter().map(|p| p.as_path()).collect();
+            let _ = crate::git_manager::auto_commit_mosaic_changes(
+                &repo,
+                &format!(
+                    "Converted tags to {} in {} note{}",
+                    to,
+                    changed_paths.len(),
+                    if changed_paths.len() == 1 { "" } else { "s" }
+                ),
+                &file_refs,
+            ); // Silently fail if commit fails
+        }
+    }
+
+    Ok(results)
+}
+
+// ============================================================================
+// Tag Rename
+// ============================================================================
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RenameTagResult {
+    pub files_updated: usize,
+    pub replacements: usize,
+}
+
+/// Replace inline `#old` tags (and nested `#old/...` tags) in a note body
+/// Code, links and URLs are left untouched, as are `#old` inside words
+fn rename_inline_tag(body: &str, old_tag: &str, new_tag: &str) -> (String, usize) {
+    // Capture the trailing tag characters so `#old-thing` isn't treated as `#old`
+    let tag_regex =
+        Regex::new(&format!(r"(?i)#{}([a-zA-Z0-9_-]*)", regex::escape(old_tag))).unwrap();
+    let mut in_code_block = false;
+    let mut replacements = 0;
+    let mut lines = Vec::new();
+
+    for line in body.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code_block = !in_code_block;
+            lines.push(line.to_string());
+            continue;
+        }
+
+        if in_code_block {
+            lines.push(line.to_string());
+            continue;
+        }
+
+        let protected = protected_ranges(line);
+        let bytes = line.as_bytes();
+        let replaced = tag_regex.replace_all(line, |caps: &regex::Captures| {
+            let whole = caps.get(0).unwrap();
+            let (start, end) = (whole.start(), whole.end());
+            let starts_word = start == 0 || bytes[start - 1].is_ascii_whitespace();
+            let is_protected = protected.iter().any(|&(s, e)| start < e && end > s);
+
+            if caps[1].is_empty() && starts_word && !is_protected {
+                replacements += 1;
+                format!("#{}", new_tag)
+            } else {
+                caps[0].to_string()
+            }
+        });
+        lines.push(replaced.into_owned());
+    }
+
+    (lines.concat(), replacements)
+}
+
+/// Rename a tag (and its nested children) in a single note
+/// Returns the new content and the number of replacements made
+fn rename_note_tag(content: &str, old_tag: &str, new_tag: &str) -> Result<(String, usize), String> {
+    let body = crate::frontmatter::strip(content);
+    let (new_body, mut replacements) = rename_inline_tag(body, old_tag, new_tag);
+
+    let mut mapping = crate::frontmatter::parse(content)?;
+    let old_lower = old_tag.to_lowercase();
+    let mut frontmatter_changed = false;
+
+    let renamed: Vec<String> = crate::frontmatter::tags(&mapping)
+        .into_iter()
+        .map(|tag| {
+            let lower = tag.to_lowercase();
+            if lower == old_lower {
+                frontmatter_changed = true;
+                new_tag.to_string()
+            } else if let Some(rest) = lower.strip_prefix(&format!("{}/", old_lower)) {
+                frontmatter_changed = true;
+                format!("{}/{}", new_tag, rest)
+            } else {
+                tag
+            }
+        })
+        .collect();
+
+    if frontmatter_changed {
+        replacements += 1;
+        crate::frontmatter::set_tags(&mut mapping, &renamed);
+        return Ok((
+            crate::frontmatter::compose(&mapping, &new_body)?,
+            replacements,
+        ));
+    }
+
+    // Keep the original frontmatter text byte-for-byte
+    let prefix = &content[..content.len() - body.len()];
+    Ok((format!("{}{}", prefix, new_body), replacements))
+}
+
+/// Rename a tag across the vault, in both inline #tags and frontmatter `tags:` lists
+/// Nested tags follow their parent, so renaming `project` also renames `project/rust`
+#[tauri::command]
+pub async fn rename_tag(
+    vault_path: String,
+    old_tag: String,
+    new_tag: String,
+) -> Result<RenameTagResult, String> {
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let old_tag = old_tag.trim().trim_start_matches('#').to_string();
+    let new_tag = new_tag.trim().trim_start_matches('#').to_string();
+
+    let tag_regex = Regex::new(r"^[a-zA-Z0-9_-]+(?:/[a-zA-Z0-9_-]+)*$").unwrap();
+    if !tag_regex.is_match(&old_tag) {
+        return Err(format!("Invalid tag '{}'", old_tag));
+    }
+    if !tag_regex.is_match(&new_tag) {
+        return Err(format!("Invalid tag '{}'", new_tag));
+    }
+
+    let mut notes = Vec::new();
+    crate::tools::collect_notes(vault, &mut notes, vault)?;
+
+    let mut replacements = 0;
+    let mut changed_paths = Vec::new();
+
+    for note in notes.iter().filter(|note| !note.is_encrypted) {
+        let path = vault.join(&note.path);
+        let content = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", note.path, e))?;
+
+        let (new_content, count) = match rename_note_tag(&content, &old_tag, &new_tag) {
+            Ok(result) => result,
+            Err(e) => {
+                eprintln!("Skipping {}: {}", note.path, e);
+                continue;
+            }
+        };
+
+        if count == 0 || new_content == content {
+            continue;
+        }
+
+        fs::write(&path, new_content)
+            .map_err(|e| format!("Failed to write {}: {}", note.path, e))?;
+        replacements += count;
+        changed_paths.push(path);
+    }
+
+    // Auto-commit if Git repository
+    if !changed_paths.is_empty() {
+        if let Some(repo) = crate::git_manager::open_repository(vault) {
+            let file_refs: Vec<&Path> = changed_paths.iter().map(|p| p.as_path()).collect();
+            let _ = crate::git_manager::auto_commit_mosaic_changes(
+                &repo,
+                &format!("Renamed tag #{} → #{}", old_tag, new_tag),
+                &file_refs,
+            ); // Silently fail if commit fails
+        }
+    }
+
+    // Force a clean rebuild of the tags cache on next access
+    let _ = fs::remove_file(vault.join(CACHE_FILE_NAME));
+
+    Ok(RenameTagResult {
+        files_updated: changed_paths.len(),
+        replacements,
+    })
+}
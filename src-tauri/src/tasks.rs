@@ -0,0 +1,165 @@
+use crate::tools::collect_notes;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use tauri::command;
+
+// ============================================================================
+// Task Aggregation
+// ============================================================================
+//
+// Tasks are plain Markdown checkboxes (`- [ ] text` / `- [x] text`). Due dates
+// and priorities are read from inline `@due(YYYY-MM-DD)` / `due: YYYY-MM-DD`
+// and `@priority(high)` / `priority: high` annotations.
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Task {
+    pub note_path: String, // Relative to the vault
+    pub note_title: String,
+    pub line_number: usize, // 1-based
+    pub text: String,
+    pub completed: bool,
+    pub due_date: Option<String>,
+    pub priority: Option<String>,
+}
+
+const TASK_PATTERN: &str = r"^\s*[-*+] \[([ xX])\]\s+(.*)$";
+
+/// Parse every checkbox task in a note, skipping fenced code blocks
+fn parse_tasks(content: &str, note_path: &str, note_title: &str) -> Vec<Task> {
+    let task_regex = Regex::new(TASK_PATTERN).unwrap();
+    let due_regex =
+        Regex::new(r"@due\((\d{4}-\d{2}-\d{2})\)|\bdue:\s*(\d{4}-\d{2}-\d{2})").unwrap();
+    let priority_regex = Regex::new(r"@priority\((\w+)\)|\bpriority:\s*(\w+)").unwrap();
+
+    let mut tasks = Vec::new();
+    let mut in_code_block = false;
+
+    for (index, line) in content.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+
+        let Some(caps) = task_regex.captures(line) else {
+            continue;
+        };
+        let text = caps[2].trim().to_string();
+
+        let first_group = |caps: regex::Captures| {
+            caps.get(1)
+                .or_else(|| caps.get(2))
+                .map(|m| m.as_str().to_string())
+        };
+
+        tasks.push(Task {
+            note_path: note_path.to_string(),
+            note_title: note_title.to_string(),
+            line_number: index + 1,
+            completed: &caps[1] != " ",
+            due_date: due_regex.captures(&text).and_then(first_group),
+            priority: priority_regex
+                .captures(&text)
+                .and_then(first_group)
+                .map(|p| p.to_lowercase()),
+            text,
+        });
+    }
+
+    tasks
+}
+
+/// Collect checkbox tasks from every note in the vault
+#[command]
+pub async fn get_all_tasks(
+    vault_path: String,
+    include_completed: bool,
+) -> Result<Vec<Task>, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let mut notes = Vec::new();
+    collect_notes(vault, &mut notes, vault)?;
+
+    let mut tasks = Vec::new();
+    for note in notes.iter().filter(|n| !n.is_encrypted) {
+        let Ok(content) = fs::read_to_string(vault.join(&note.path)) else {
+            continue;
+        };
+        tasks.extend(
+            parse_tasks(&content, &note.path, &note.title)
+                .into_iter()
+                .filter(|task| include_completed || !task.completed),
+        );
+    }
+
+    tasks.sort_by(|a, b| {
+        a.note_title
+            .cmp(&b.note_title)
+            .then_with(|| a.note_path.cmp(&b.note_path))
+            .then_with(|| a.line_number.cmp(&b.line_number))
+    });
+
+    Ok(tasks)
+}
+
+/// Toggle the checkbox on a task line (`[ ]` <-> `[x]`)
+/// `line_number` is 1-based, as returned by `get_all_tasks`
+#[command]
+pub async fn complete_task(
+    vault_path: String,
+    note_path: String,
+    line_number: usize,
+) -> Result<(), String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let path = crate::tools::resolve_note_path(vault, &note_path)
+        .ok_or_else(|| format!("Note '{}' not found", note_path))?;
+    let content = fs::read_to_string(&path).map_err(|e| format!("Failed to read note: {}", e))?;
+
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    let line = line_number
+        .checked_sub(1)
+        .and_then(|index| lines.get_mut(index))
+        .ok_or_else(|| format!("Line {} is out of range", line_number))?;
+
+    let task_regex = Regex::new(TASK_PATTERN).unwrap();
+    let caps = task_regex
+        .captures(line)
+        .ok_or_else(|| format!("Line {} is not a task", line_number))?;
+    let checkbox = caps.get(1).unwrap();
+    let toggled = if checkbox.as_str() == " " { "x" } else { " " };
+    line.replace_range(checkbox.range(), toggled);
+
+    let mut new_content = lines.join("\n");
+    if content.ends_with('\n') {
+        new_content.push('\n');
+    }
+    fs::write(&path, new_content).map_err(|e| format!("Failed to write note: {}", e))?;
+
+    if let Some(repo) = crate::git_manager::open_repository(vault) {
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let _ = crate::git_manager::auto_commit_mosaic_changes(
+            &repo,
+            &format!("Toggled task in {}", file_name),
+            &[&path],
+        ); // Silently fail if commit fails
+    }
+
+    Ok(())
+}
@@ -1,11 +1,116 @@
-use chrono::Local;
+use chrono::{Local, TimeZone};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 use tauri::command;
 
 const TEMPLATES_DIR: &str = ".moss/templates";
 
+/// Marker written alongside the built-in templates so they can be recognised
+/// (and offered for reset) later
+const DEFAULT_TEMPLATES_MARKER: &str = ".moss-default";
+
+const DAILY_NOTE_TEMPLATE: &str = "# {{date}}
+
+## Tasks
+
+- [ ] 
+
+## Notes
+
+";
+
+const MEETING_NOTES_TEMPLATE: &str = "# {{title}}
+
+**Date:** {{date}} {{time}}
+
+## Attendees
+
+- 
+
+## Agenda
+
+1. 
+
+## Notes
+
+## Action Items
+
+- [ ] 
+";
+
+const BOOK_SUMMARY_TEMPLATE: &str = "# {{title}}
+
+**Author:** 
+**Started:** {{date}}
+**Rating:** 
+
+## Summary
+
+## Key Ideas
+
+- 
+
+## Quotes
+
+> 
+
+## Thoughts
+";
+
+const PROJECT_TEMPLATE: &str = "# {{title}}
+
+**Created:** {{date}}
+**Status:** Planning
+
+## Goal
+
+## Milestones
+
+- [ ] 
+
+## Tasks
+
+- [ ] 
+
+## Resources
+
+## Log
+
+- {{date}}: Project created
+";
+
+const LITERATURE_NOTE_TEMPLATE: &str = "# {{title}}
+
+**Source:** 
+**Authors:** 
+**Read on:** {{date}}
+
+## Summary
+
+## Key Points
+
+- 
+
+## Quotes
+
+> 
+
+## Connections
+
+- [[]]
+";
+
+const DEFAULT_TEMPLATES: &[(&str, &str)] = &[
+    ("Daily Note", DAILY_NOTE_TEMPLATE),
+    ("Meeting Notes", MEETING_NOTES_TEMPLATE),
+    ("Book Summary", BOOK_SUMMARY_TEMPLATE),
+    ("Project", PROJECT_TEMPLATE),
+    ("Literature Note", LITERATURE_NOTE_TEMPLATE),
+];
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Template {
     pub name: String,
@@ -21,6 +126,8 @@ pub struct TemplateVars {
     pub year: Option<String>,
     pub month: Option<String>,
     pub day: Option<String>,
+    #[serde(default)]
+    pub custom_vars: HashMap<String, String>, // User-defined template slots
 }
 
 /// List all templates in the vault's .moss/templates directory
@@ -43,6 +150,16 @@ pub async fn list_templates(vault_path: String) -> Result<Vec<Template>, String>
             .map_err(|e| format!("Failed to create templates directory: {}", e))?;
     }
 
+    // Seed the built-in templates when the directory has no templates yet
+    let has_templates = fs::read_dir(&templates_dir)
+        .map_err(|e| format!("Failed to read templates directory: {}", e))?
+        .flatten()
+        .any(|entry| entry.path().extension().is_some_and(|ext| ext == "md"));
+
+    if !has_templates {
+        write_default_templates(&templates_dir)?;
+    }
+
     let mut templates = Vec::new();
 
     // Read all .md files in templates directory
@@ -80,15 +197,37 @@ pub async fn list_templates(vault_path: String) -> Result<Vec<Template>, String>
     Ok(templates)
 }
 
+/// Write the built-in starter templates and the defaults marker
+fn write_default_templates(templates_dir: &Path) -> Result<(), String> {
+    for (name, content) in DEFAULT_TEMPLATES {
+        fs::write(templates_dir.join(format!("{}.md", name)), content)
+            .map_err(|e| format!("Failed to write default template '{}': {}", name, e))?;
+    }
+
+    let names: Vec<&str> = DEFAULT_TEMPLATES.iter().map(|(name, _)| *name).collect();
+    fs::write(
+        templates_dir.join(DEFAULT_TEMPLATES_MARKER),
+        names.join("\n"),
+    )
+    .map_err(|e| format!("Failed to write default templates marker: {}", e))?;
+
+    Ok(())
+}
+
 /// Get a specific template by name
 #[command]
 pub async fn get_template(vault_path: String, template_name: String) -> Result<String, String> {
-    let vault = Path::new(&vault_path);
-    let templates_dir = vault.join(TEMPLATES_DIR);
+    expand_template_inheritance(&vault_path, &template_name)
+}
+
+/// Maximum length of an `{{extends}}` chain, guards against cycles
+const MAX_INHERITANCE_DEPTH: usize = 5;
 
+/// Read a raw template file from the templates directory
+fn read_template_file(templates_dir: &Path, template_name: &str) -> Result<String, String> {
     // Try with and without .md extension
     let template_path = if template_name.ends_with(".md") {
-        templates_dir.join(&template_name)
+        templates_dir.join(template_name)
     } else {
         templates_dir.join(format!("{}.md", template_name))
     };
@@ -100,11 +239,70 @@ pub async fn get_template(vault_path: String, template_name: String) -> Result<S
     fs::read_to_string(&template_path).map_err(|e| format!("Failed to read template: {}", e))
 }
 
-/// Substitute variables in template content
-fn substitute_variables(content: String, title: String, vars: Option<TemplateVars>) -> String {
-    let now = Local::now();
+fn block_regex() -> Regex {
+    Regex::new(r#"(?s)\{\{\s*block\s+"?([\w-]+)"?\s*\}\}(.*?)\{\{\s*/block\s*\}\}"#).unwrap()
+}
 
-    let mut result = content;
+/// Resolve the `{{extends}}` chain, keeping `{{block}}` markers in place so
+/// templates further down the chain can still override them
+fn resolve_template(
+    templates_dir: &Path,
+    template_name: &str,
+    depth: usize,
+) -> Result<String, String> {
+    let content = read_template_file(templates_dir, template_name)?;
+
+    let extends_regex = Regex::new(r#"^\s*\{\{\s*extends\s+"([^"]+)"\s*\}\}"#).unwrap();
+    let parent_name = match extends_regex.captures(&content) {
+        Some(caps) => caps[1].to_string(),
+        None => return Ok(content),
+    };
+
+    if depth >= MAX_INHERITANCE_DEPTH {
+        return Err(format!(
+            "Template '{}' exceeds the maximum inheritance depth of {}",
+            template_name, MAX_INHERITANCE_DEPTH
+        ));
+    }
+
+    let parent = resolve_template(templates_dir, &parent_name, depth + 1)?;
+
+    // Child blocks override the parent's blocks of the same name
+    let block_regex = block_regex();
+    let overrides: HashMap<String, String> = block_regex
+        .captures_iter(&content)
+        .map(|caps| (caps[1].to_string(), caps[2].to_string()))
+        .collect();
+
+    let merged = block_regex.replace_all(&parent, |caps: &regex::Captures| {
+        match overrides.get(&caps[1]) {
+            Some(body) => format!("{{{{block \"{}\"}}}}{}{{{{/block}}}}", &caps[1], body),
+            None => caps[0].to_string(),
+        }
+    });
+
+    Ok(merged.to_string())
+}
+
+/// Load a template with its `{{extends "parent"}}` chain applied
+///
+/// The child's `{{block name}}...{{/block}}` sections replace the parent's
+/// blocks of the same name; blocks that aren't overridden keep the parent's
+/// content.
+pub(crate) fn expand_template_inheritance(
+    vault_path: &str,
+    template_name: &str,
+) -> Result<String, String> {
+    let templates_dir = Path::new(vault_path).join(TEMPLATES_DIR);
+    let resolved = resolve_template(&templates_dir, template_name, 0)?;
+
+    // Strip the remaining block markers, leaving their content
+    Ok(block_regex().replace_all(&resolved, "$2").to_string())
+}
+
+/// Built-in and custom variable values for a template
+fn template_values(title: String, vars: Option<TemplateVars>) -> HashMap<String, String> {
+    let now = Local::now();
 
     // Use provided vars or generate defaults
     let date = vars
@@ -128,17 +326,97 @@ fn substitute_variables(content: String, title: String, vars: Option<TemplateVar
         .and_then(|v| v.day.clone())
         .unwrap_or_else(|| now.format("%d").to_string());
 
+    // Custom vars first so built-in names always win
+    let mut values: HashMap<String, String> = vars.map(|v| v.custom_vars).unwrap_or_default();
+    values.insert("title".to_string(), title);
+    values.insert("date".to_string(), date);
+    values.insert("time".to_string(), time);
+    values.insert("year".to_string(), year);
+    values.insert("month".to_string(), month);
+    values.insert("day".to_string(), day);
+
+    values
+}
+
+/// Substitute variables in template content
+fn substitute_variables(content: String, title: String, vars: Option<TemplateVars>) -> String {
+    let values = template_values(title, vars);
+
+    // Resolve {{#if}} / {{#unless}} blocks before plain substitution
+    let mut result = evaluate_conditionals(&content, &values);
+
     // Perform substitutions
-    result = result.replace("{{title}}", &title);
-    result = result.replace("{{date}}", &date);
-    result = result.replace("{{time}}", &time);
-    result = result.replace("{{year}}", &year);
-    result = result.replace("{{month}}", &month);
-    result = result.replace("{{day}}", &day);
+    for (name, value) in &values {
+        result = result.replace(&format!("{{{{{}}}}}", name), value);
+    }
 
     result
 }
 
+/// Evaluate `{{#if var}}`, `{{#unless var}}` and `{{else}}` blocks
+///
+/// A variable counts as set when it has a non-empty value. Blocks can be
+/// nested; unmatched closing tags are left in the output as-is.
+fn evaluate_conditionals(content: &str, values: &HashMap<String, String>) -> String {
+    struct Block {
+        kind: &'static str,
+        condition: bool,
+        in_else: bool,
+    }
+
+    let directive_regex =
+        Regex::new(r"\{\{\s*(#if|#unless|else|/if|/unless)\s*([^}]*?)\s*\}\}").unwrap();
+
+    let mut stack: Vec<Block> = Vec::new();
+    let mut output = String::new();
+    let mut last_end = 0;
+
+    let is_active = |stack: &[Block]| stack.iter().all(|b| b.condition != b.in_else);
+
+    for caps in directive_regex.captures_iter(content) {
+        let whole = caps.get(0).unwrap();
+
+        if is_active(&stack) {
+            output.push_str(&content[last_end..whole.start()]);
+        }
+        last_end = whole.end();
+
+        let var = caps[2].trim();
+        let is_set = values.get(var).is_some_and(|v| !v.trim().is_empty());
+
+        match &caps[1] {
+            "#if" => stack.push(Block {
+                kind: "if",
+                condition: is_set,
+                in_else: false,
+            }),
+            "#unless" => stack.push(Block {
+                kind: "unless",
+                condition: !is_set,
+                in_else: false,
+            }),
+            "else" => match stack.last_mut() {
+                Some(block) => block.in_else = true,
+                None => output.push_str(whole.as_str()),
+            },
+            closing => {
+                let kind = &closing[1..];
+                if stack.last().is_some_and(|b| b.kind == kind) {
+                    stack.pop();
+                } else if is_active(&stack) {
+                    output.push_str(whole.as_str());
+                }
+            }
+        }
+    }
+
+    if is_active(&stack) {
+        output.push_str(&content[last_end..]);
+    }
+
+    output
+}
+
 /// Create a note from a template
 #[command]
 pub async fn create_note_from_template(
@@ -150,11 +428,21 @@ pub async fn create_note_from_template(
 ) -> Result<String, String> {
     let vault = Path::new(&vault_path);
 
-    // Load template content
-    let template_content = get_template(vault_path.clone(), template_name.clone()).await?;
+    // Keep a copy of templates edited since their last version
+    let _ = auto_save_template_version(vault, &template_name);
 
-    // Substitute variables
-    let final_content = substitute_variables(template_content, note_title.clone(), vars);
+    // Load template content
+    let template_content = expand_template_inheritance(&vault_path, &template_name)?;
+
+    // Handlebars-only syntax needs the Handlebars renderer; everything else
+    // (including `{{#if}}`) keeps the cheaper substitution
+    let final_content = if uses_handlebars(&template_content) {
+        let context = serde_json::to_value(template_values(note_title.clone(), vars))
+            .map_err(|e| format!("Failed to build template context: {}", e))?;
+        render_handlebars(&template_content, &context)?
+    } else {
+        substitute_variables(template_content, note_title.clone(), vars)
+    };
 
     // Determine note path
     let folder_path = parent_path.unwrap_or(vault_path.clone());
@@ -199,3 +487,437 @@ pub async fn create_note_from_template(
 
     Ok(final_path.to_string_lossy().to_string())
 }
+
+// ============================================================================
+// Template History
+// ============================================================================
+
+const TEMPLATE_HISTORY_DIR: &str = ".moss/template_history";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TemplateVersion {
+    pub timestamp: u64, // Unix timestamp, also the version's file name
+    pub size: usize,    // Bytes
+    pub first_line: String,
+}
+
+/// Live template file and history folder for a template name
+fn template_history_paths(
+    vault: &Path,
+    template_name: &str,
+) -> Result<(std::path::PathBuf, std::path::PathBuf), String> {
+    let name = template_name.trim().trim_end_matches(".md");
+    if name.is_empty() || name.contains(['/', '\\']) || name.starts_with('.') {
+        return Err(format!("Invalid template name '{}'", template_name));
+    }
+
+    Ok((
+        vault.join(TEMPLATES_DIR).join(format!("{}.md", name)),
+        vault.join(TEMPLATE_HISTORY_DIR).join(name),
+    ))
+}
+
+/// Version timestamps of a template, newest first
+fn version_timestamps(history_dir: &Path) -> Vec<u64> {
+    let mut timestamps: Vec<u64> = fs::read_dir(history_dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|entry| {
+                    let path = entry.path();
+                    if path.extension().is_some_and(|ext| ext == "md") {
+                        path.file_stem()?.to_str()?.parse().ok()
+                    } else {
+                        None
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    timestamps.sort_unstable_by(|a, b| b.cmp(a));
+    timestamps
+}
+
+fn write_template_version(history_dir: &Path, content: &str) -> Result<u64, String> {
+    fs::create_dir_all(history_dir)
+        .map_err(|e| format!("Failed to create template history: {}", e))?;
+
+    let mut timestamp = Local::now().timestamp().max(0) as u64;
+    // Two saves within the same second would otherwise share a file
+    while history_dir.join(format!("{}.md", timestamp)).exists() {
+        timestamp += 1;
+    }
+    fs::write(history_dir.join(format!("{}.md", timestamp)), content)
+        .map_err(|e| format!("Failed to write template version: {}", e))?;
+
+    Ok(timestamp)
+}
+
+/// Save a version when the template differs from its latest version and no
+/// version was saved today, so templates edited often keep one copy per day
+fn auto_save_template_version(vault: &Path, template_name: &str) -> Result<(), String> {
+    let (template_path, history_dir) = template_history_paths(vault, template_name)?;
+    let content = fs::read_to_string(&template_path)
+        .map_err(|e| format!("Failed to read template: {}", e))?;
+
+    if let Some(latest) = version_timestamps(&history_dir).first() {
+        let saved_today = Local
+            .timestamp_opt(*latest as i64, 0)
+            .single()
+            .is_some_and(|time| time.date_naive() == Local::now().date_naive());
+        let unchanged = fs::read_to_string(history_dir.join(format!("{}.md", latest)))
+            .is_ok_and(|saved| saved == content);
+        if saved_today || unchanged {
+            return Ok(());
+        }
+    }
+
+    write_template_version(&history_dir, &content).map(|_| ())
+}
+
+/// Snapshot a template's current content
+/// Returns the version's timestamp
+#[command]
+pub async fn save_template_version(
+    vault_path: String,
+    template_name: String,
+) -> Result<u64, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let (template_path, history_dir) = template_history_paths(vault, &template_name)?;
+    if !template_path.exists() {
+        return Err(format!("Template '{}' not found", template_name));
+    }
+    let content = fs::read_to_string(&template_path)
+        .map_err(|e| format!("Failed to read template: {}", e))?;
+
+    write_template_version(&history_dir, &content)
+}
+
+/// Saved versions of a template, newest first
+#[command]
+pub async fn list_template_versions(
+    vault_path: String,
+    template_name: String,
+) -> Result<Vec<TemplateVersion>, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let (_, history_dir) = template_history_paths(vault, &template_name)?;
+
+    let mut versions = Vec::new();
+    for timestamp in version_timestamps(&history_dir) {
+        let Ok(content) = fs::read_to_string(history_dir.join(format!("{}.md", timestamp))) else {
+            continue;
+        };
+        versions.push(TemplateVersion {
+            timestamp,
+            size: content.len(),
+            first_line: content
+                .lines()
+                .map(str::trim)
+                .find(|line| !line.is_empty())
+                .unwrap_or_default()
+                .to_string(),
+        });
+    }
+
+    Ok(versions)
+}
+
+/// Overwrite a template with one of its saved versions
+/// The current content is saved as a version first if it isn't one already
+#[command]
+pub async fn restore_template_version(
+    vault_path: String,
+    template_name: String,
+    timestamp: u64,
+) -> Result<(), String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let (template_path, history_dir) = template_history_paths(vault, &template_name)?;
+    let version = fs::read_to_string(history_dir.join(format!("{}.md", timestamp)))
+        .map_err(|_| format!("Version {} of '{}' not found", timestamp, template_name))?;
+
+    if let Ok(current) = fs::read_to_string(&template_path) {
+        let saved = version_timestamps(&history_dir).iter().any(|t| {
+            fs::read_to_string(history_dir.join(format!("{}.md", t))).is_ok_and(|c| c == current)
+        });
+        if !saved {
+            write_template_version(&history_dir, &current)?;
+        }
+    }
+
+    if let Some(parent) = template_path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create templates directory: {}", e))?;
+    }
+    fs::write(&template_path, version).map_err(|e| format!("Failed to restore template: {}", e))
+}
+
+// ============================================================================
+// Handlebars Templates
+// ============================================================================
+
+/// Moment-style date tokens and their chrono equivalents
+fn chrono_format(format: &str) -> String {
+    let token_regex = Regex::new(r"YYYY|YY|MM|DD|HH|mm|ss").unwrap();
+    token_regex
+        .replace_all(&format.replace('%', "%%"), |caps: &regex::Captures| {
+            match &caps[0] {
+                "YYYY" => "%Y",
+                "YY" => "%y",
+                "MM" => "%m",
+                "DD" => "%d",
+                "HH" => "%H",
+                "mm" => "%M",
+                _ => "%S",
+            }
+            .to_string()
+        })
+        .to_string()
+}
+
+/// Format a Unix timestamp or an RFC 3339 / `YYYY-MM-DD` date string
+/// Values that aren't dates are returned unchanged
+fn format_date(value: &serde_json::Value, format: &str) -> String {
+    let time = match value {
+        serde_json::Value::Number(n) => n
+            .as_i64()
+            .and_then(|secs| Local.timestamp_opt(secs, 0).single()),
+        serde_json::Value::String(s) => chrono::DateTime::parse_from_rfc3339(s)
+            .map(|t| t.with_timezone(&Local))
+            .ok()
+            .or_else(|| {
+                chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d")
+                    .ok()
+                    .and_then(|d| d.and_hms_opt(0, 0, 0))
+                    .and_then(|d| Local.from_local_datetime(&d).earliest())
+            }),
+        _ => None,
+    };
+
+    match time {
+        Some(time) => time.format(&chrono_format(format)).to_string(),
+        None => match value {
+            serde_json::Value::String(s) => s.clone(),
+            other => other.to_string(),
+        },
+    }
+}
+
+fn truncate_text(text: &str, length: usize) -> String {
+    if text.chars().count() <= length {
+        return text.to_string();
+    }
+    let truncated: String = text.chars().take(length).collect();
+    format!("{}…", truncated.trim_end())
+}
+
+fn slug(text: &str) -> String {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Whether a template uses syntax only the Handlebars renderer understands:
+/// `{{#each}}` / `{{#with}}` blocks or the custom helpers
+fn uses_handlebars(template: &str) -> bool {
+    Regex::new(r"\{\{\s*(?:#(?:each|with)|date_format|truncate|slug)\s")
+        .unwrap()
+        .is_match(template)
+}
+
+handlebars::handlebars_helper!(date_format_helper: |value: Json, format: str| format_date(value, format));
+handlebars::handlebars_helper!(truncate_helper: |text: str, length: u64| truncate_text(text, length as usize));
+handlebars::handlebars_helper!(slug_helper: |text: str| slug(text));
+
+/// Render a template with Handlebars, without HTML escaping
+/// Adds `date_format`, `truncate` and `slug` helpers to the built-in ones
+fn render_handlebars(template: &str, context: &serde_json::Value) -> Result<String, String> {
+    let mut handlebars = handlebars::Handlebars::new();
+    handlebars.register_escape_fn(handlebars::no_escape);
+    handlebars.register_helper("date_format", Box::new(date_format_helper));
+    handlebars.register_helper("truncate", Box::new(truncate_helper));
+    handlebars.register_helper("slug", Box::new(slug_helper));
+
+    handlebars
+        .render_template(template, context)
+        .map_err(|e| format!("Failed to render template: {}", e))
+}
+
+/// Render template content against a JSON context with Handlebars
+#[command]
+pub async fn render_template_handlebars(
+    vault_path: String,
+    template_content: String,
+    context: serde_json::Value,
+) -> Result<String, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    render_handlebars(&template_content, &context)
+}
+
+// ============================================================================
+// Template Import
+// ============================================================================
+
+const MAX_IMPORTED_TEMPLATE_BYTES: usize = 50 * 1024;
+
+/// Rough check that fetched text is a Markdown template rather than a web page
+fn looks_like_markdown(content: &str) -> bool {
+    let trimmed = content.trim_start();
+    if trimmed.is_empty() || trimmed.starts_with('<') || content.contains('\0') {
+        return false;
+    }
+
+    crate::frontmatter::split(content).0.is_some()
+        || content.contains("{{")
+        || content.lines().any(|line| {
+            let line = line.trim_start();
+            line.starts_with('#')
+                || line.starts_with("- ")
+                || line.starts_with("* ")
+                || line.starts_with("> ")
+        })
+}
+
+/// Save a new template, refusing to overwrite an existing one
+/// Returns the template name
+fn save_new_template(vault: &Path, name: &str, content: &str) -> Result<String, String> {
+    let name = crate::tools::sanitize_file_stem(name.trim().trim_end_matches(".md"));
+    if name.is_empty() || name.starts_with('.') {
+        return Err("Template name cannot be empty".to_string());
+    }
+
+    let templates_dir = vault.join(TEMPLATES_DIR);
+    fs::create_dir_all(&templates_dir)
+        .map_err(|e| format!("Failed to create templates directory: {}", e))?;
+
+    let path = templates_dir.join(format!("{}.md", name));
+    if path.exists() {
+        return Err(format!("Template '{}' already exists", name));
+    }
+
+    fs::write(&path, content).map_err(|e| format!("Failed to write template: {}", e))?;
+    Ok(name)
+}
+
+/// Download a Markdown template (at most 50 KB) into the vault's templates
+/// The name defaults to the URL's file name. Returns the saved template name
+#[command]
+pub async fn import_template_from_url(
+    vault_path: String,
+    url: String,
+    template_name: Option<String>,
+) -> Result<String, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let parsed_url = reqwest::Url::parse(&url).map_err(|e| format!("Invalid URL: {}", e))?;
+    if !matches!(parsed_url.scheme(), "http" | "https") {
+        return Err("Only http and https URLs can be imported".to_string());
+    }
+
+    let name = match template_name.filter(|n| !n.trim().is_empty()) {
+        Some(name) => name,
+        None => parsed_url
+            .path_segments()
+            .and_then(|mut segments| segments.next_back())
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| {
+                urlencoding::decode(segment)
+                    .map(|s| s.to_string())
+                    .unwrap_or_else(|_| segment.to_string())
+            })
+            .ok_or_else(|| "Could not derive a template name from the URL".to_string())?,
+    };
+
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(10))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let mut response = client
+        .get(parsed_url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch '{}': {}", url, e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Failed to fetch '{}': {}", url, response.status()));
+    }
+
+    let too_large = || {
+        format!(
+            "Template exceeds the {} KB limit",
+            MAX_IMPORTED_TEMPLATE_BYTES / 1024
+        )
+    };
+    if response
+        .content_length()
+        .is_some_and(|len| len as usize > MAX_IMPORTED_TEMPLATE_BYTES)
+    {
+        return Err(too_large());
+    }
+
+    // Content-Length can be missing or wrong, so stop reading once past the limit
+    let mut bytes = Vec::new();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| format!("Failed to read template: {}", e))?
+    {
+        if bytes.len() + chunk.len() > MAX_IMPORTED_TEMPLATE_BYTES {
+            return Err(too_large());
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+
+    let content =
+        String::from_utf8(bytes).map_err(|_| "Template is not valid UTF-8 text".to_string())?;
+    if !looks_like_markdown(&content) {
+        return Err("URL does not point to a Markdown template".to_string());
+    }
+
+    save_new_template(vault, &name, &content)
+}
+
+/// Copy a template from another vault, with its `{{extends}}` chain applied
+/// so it doesn't depend on the source vault's other templates
+#[command]
+pub async fn import_template_from_vault(
+    source_vault_path: String,
+    template_name: String,
+    target_vault_path: String,
+) -> Result<String, String> {
+    for vault_path in [&source_vault_path, &target_vault_path] {
+        let vault = Path::new(vault_path);
+        if !vault.exists() || !vault.is_dir() {
+            return Err(format!("Vault path '{}' does not exist", vault_path));
+        }
+    }
+
+    let content = expand_template_inheritance(&source_vault_path, &template_name)?;
+    save_new_template(Path::new(&target_vault_path), &template_name, &content)
+}
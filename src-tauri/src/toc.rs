@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use tauri::command;
+
+// ============================================================================
+// Table of Contents
+// ============================================================================
+
+pub(crate) const TOC_START: &str = "<!-- toc -->";
+pub(crate) const TOC_END: &str = "<!-- /toc -->";
+
+pub(crate) struct Heading {
+    pub(crate) level: usize,
+    pub(crate) text: String,
+    pub(crate) line: usize, // Line index in the body
+}
+
+/// ATX headings up to `max_depth`, skipping fenced code blocks
+pub(crate) fn extract_headings(body: &str, max_depth: usize) -> Vec<Heading> {
+    let mut headings = Vec::new();
+    let mut in_code_block = false;
+
+    for (line_index, line) in body.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+
+        let level = line.chars().take_while(|c| *c == '#').count();
+        if level == 0 || level > max_depth || level > 6 {
+            continue;
+        }
+        let rest = &line[level..];
+        if !rest.starts_with([' ', '\t']) {
+            continue;
+        }
+
+        let text = rest.trim().trim_end_matches('#').trim_end().to_string();
+        if !text.is_empty() {
+            headings.push(Heading {
+                level,
+                text,
+                line: line_index,
+            });
+        }
+    }
+
+    headings
+}
+
+/// GitHub-style anchor: lowercase, punctuation stripped, spaces to hyphens
+fn slugify(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == ' ' || *c == '-' || *c == '_')
+        .map(|c| if c == ' ' { '-' } else { c })
+        .collect()
+}
+
+/// Nested Markdown list of anchor links, indented relative to the shallowest heading
+fn render_toc(headings: &[Heading]) -> String {
+    let min_level = headings.iter().map(|h| h.level).min().unwrap_or(1);
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    let mut toc = String::new();
+
+    for heading in headings {
+        // Repeated headings get `-1`, `-2`, ... like GitHub
+        let base = slugify(&heading.text);
+        let count = seen.entry(base.clone()).or_insert(0);
+        let slug = if *count == 0 {
+            base
+        } else {
+            format!("{}-{}", base, count)
+        };
+        *count += 1;
+
+        toc.push_str(&"  ".repeat(heading.level - min_level));
+        toc.push_str(&format!("- [{}](#{})\n", heading.text, slug));
+    }
+
+    toc
+}
+
+fn read_note(vault_path: &str, note_path: &str) -> Result<(std::path::PathBuf, String), String> {
+    let vault = Path::new(vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let path = crate::tools::resolve_note_path(vault, note_path)
+        .ok_or_else(|| format!("Note '{}' not found", note_path))?;
+    let content = fs::read_to_string(&path).map_err(|e| format!("Failed to read note: {}", e))?;
+    Ok((path, content))
+}
+
+/// Build a table of contents for a note from headings up to `max_depth`
+#[command]
+pub async fn generate_toc(
+    vault_path: String,
+    note_path: String,
+    max_depth: usize,
+) -> Result<String, String> {
+    let (_, content) = read_note(&vault_path, &note_path)?;
+    let headings = extract_headings(crate::frontmatter::strip(&content), max_depth);
+    Ok(render_toc(&headings))
+}
+
+/// Insert (or refresh) a table of contents wrapped in `<!-- toc -->` markers
+///
+/// An existing TOC block is replaced in place. Otherwise the TOC goes after
+/// `insert_after_heading`, or at the top of the note (below a leading title).
+#[command]
+pub async fn insert_toc(
+    vault_path: String,
+    note_path: String,
+    max_depth: usize,
+    insert_after_heading: Option<String>,
+) -> Result<(), String> {
+    let (path, content) = read_note(&vault_path, &note_path)?;
+    let (frontmatter, body) = crate::frontmatter::split(&content);
+    let frontmatter_len = content.len() - body.len();
+
+    // Strip any previous TOC first so its links aren't mistaken for content
+    let (before, after) = match (body.find(TOC_START), body.find(TOC_END)) {
+        (Some(start), Some(end)) if start < end => {
+            (Some(&body[..start]), &body[end + TOC_END.len()..])
+        }
+        _ => (None, body),
+    };
+
+    let new_body = match before {
+        Some(before) => {
+            let stripped = format!("{}{}", before, after);
+            let toc = render_toc(&extract_headings(&stripped, max_depth));
+            format!("{}{}\n{}{}{}", before, TOC_START, toc, TOC_END, after)
+        }
+        None => {
+            let headings = extract_headings(body, max_depth);
+            let toc = render_toc(&headings);
+            let block = format!("{}\n{}{}\n", TOC_START, toc, TOC_END);
+
+            let lines: Vec<&str> = body.lines().collect();
+            let insert_at = match &insert_after_heading {
+                Some(target) => {
+                    let all_headings = extract_headings(body, 6);
+                    let heading = all_headings
+                        .iter()
+                        .find(|h| h.text.eq_ignore_ascii_case(target.trim()))
+                        .ok_or_else(|| format!("Heading '{}' not found", target))?;
+                    heading.line + 1
+                }
+                // Keep a leading `# Title` above the TOC
+                None => match lines.iter().position(|l| !l.trim().is_empty()) {
+                    Some(first) if lines[first].starts_with("# ") => first + 1,
+                    _ => 0,
+                },
+            };
+
+            let mut new_lines: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+            let mut insertion = vec![block.trim_end().to_string()];
+            if insert_at > 0 {
+                insertion.insert(0, String::new());
+            }
+            if lines.get(insert_at).is_some_and(|l| !l.trim().is_empty()) {
+                insertion.push(String::new());
+            }
+            new_lines.splice(insert_at..insert_at, insertion);
+
+            let mut joined = new_lines.join("\n");
+            if body.ends_with('\n') || body.is_empty() {
+                joined.push('\n');
+            }
+            joined
+        }
+    };
+
+    let new_content = match frontmatter {
+        Some(_) => format!("{}{}", &content[..frontmatter_len], new_body),
+        None => new_body,
+    };
+    if new_content == content {
+        return Ok(());
+    }
+
+    fs::write(&path, new_content).map_err(|e| format!("Failed to write note: {}", e))?;
+
+    let vault = Path::new(&vault_path);
+    if let Some(repo) = crate::git_manager::open_repository(vault) {
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let _ = crate::git_manager::auto_commit_mosaic_changes(
+            &repo,
+            &format!("Updated table of contents in {}", file_name),
+            &[&path],
+        ); // Silently fail if commit fails
+    }
+
+    Ok(())
+}
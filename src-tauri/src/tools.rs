@@ -64,10 +64,27 @@ pub struct BatchUpdateError {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NoteMetadata {
     pub id: String,
-    pub title: String,
+    pub title: String, // File name stem
+    #[serde(default)]
+    pub display_title: String, // First `# Heading`, falling back to `title`
     pub path: String,
     pub modified: u64, // Unix timestamp
     pub size: u64,
+    #[serde(default)]
+    pub is_encrypted: bool, // Stored as `.md.enc`, content unreadable without password
+    #[serde(default)]
+    pub is_pinned: bool,
+    #[serde(default)]
+    pub is_starred: bool,
+    #[serde(default)]
+    pub estimated_reading_minutes: f32, // Only filled when requested, 0 otherwise
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotePage {
+    pub content: String,
+    pub has_more: bool,
+    pub total_bytes: u64,
 }
 
 // ============================================================================
@@ -84,6 +101,18 @@ pub async fn agent_get_note(vault_path: String, note_path: String) -> Result<Str
         Path::new(&vault_path).join(path)
     };
 
+    // Encrypted notes must be decrypted explicitly with the user's password
+    let encrypted_path = format!("{}.enc", full_path.to_string_lossy());
+    if full_path.extension().is_some_and(|ext| ext == "enc")
+        || Path::new(&encrypted_path).exists()
+        || Path::new(&format!("{}.md.enc", full_path.to_string_lossy())).exists()
+    {
+        return Err(format!(
+            "Note '{}' is encrypted. Ask the user to decrypt it first.",
+            sanitize_path(&full_path, &vault_path)
+        ));
+    }
+
     // Try adding .md if file not found and extension missing
     if !full_path.exists() && full_path.extension().is_none() {
         let with_ext = full_path.with_extension("md");
@@ -108,6 +137,117 @@ pub async fn agent_get_note(vault_path: String, note_path: String) -> Result<Str
     })
 }
 
+/// Read a window of a (potentially very large) note
+///
+/// Pages are `page_size_bytes` wide and snapped forward to the next line
+/// start, so consecutive pages never overlap or split a line. Lines longer
+/// than a page are split on a UTF-8 character boundary instead.
+#[command]
+pub async fn read_note_paged(
+    vault_path: String,
+    note_path: String,
+    page: u64,
+    page_size_bytes: u64,
+) -> Result<NotePage, String> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    if page_size_bytes == 0 {
+        return Err("Page size must be greater than zero".to_string());
+    }
+
+    let path = Path::new(&note_path);
+    let mut full_path = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        Path::new(&vault_path).join(path)
+    };
+
+    // Try adding .md if file not found and extension missing
+    if !full_path.exists() && full_path.extension().is_none() {
+        full_path = full_path.with_extension("md");
+    }
+
+    let mut file = fs::File::open(&full_path).map_err(|e| {
+        format!(
+            "Failed to read note '{}': {}",
+            sanitize_path(&full_path, &vault_path),
+            e
+        )
+    })?;
+
+    let total_bytes = file
+        .metadata()
+        .map_err(|e| format!("Failed to read note metadata: {}", e))?
+        .len();
+
+    let nominal_start = page.saturating_mul(page_size_bytes).min(total_bytes);
+    let nominal_end = nominal_start
+        .saturating_add(page_size_bytes)
+        .min(total_bytes);
+
+    // Read a little before the window (to know if we start on a line) and
+    // up to one extra page after it (to find the next line break)
+    let read_start = nominal_start.saturating_sub(1);
+    let read_end = nominal_end.saturating_add(page_size_bytes).min(total_bytes);
+
+    file.seek(SeekFrom::Start(read_start))
+        .map_err(|e| format!("Failed to seek in note: {}", e))?;
+    let mut buffer = vec![0u8; (read_end - read_start) as usize];
+    file.read_exact(&mut buffer)
+        .map_err(|e| format!("Failed to read note: {}", e))?;
+
+    let start = page_boundary(
+        &buffer,
+        read_start,
+        nominal_start,
+        total_bytes,
+        page_size_bytes,
+    );
+    let end = page_boundary(
+        &buffer,
+        read_start,
+        nominal_end,
+        total_bytes,
+        page_size_bytes,
+    );
+
+    let slice = &buffer[(start - read_start) as usize..(end - read_start) as usize];
+
+    Ok(NotePage {
+        content: String::from_utf8_lossy(slice).to_string(),
+        has_more: end < total_bytes,
+        total_bytes,
+    })
+}
+
+/// Snap a byte offset forward to the start of the next line
+/// Falls back to the next UTF-8 character boundary when no line break is
+/// found within one page
+fn page_boundary(buffer: &[u8], buffer_start: u64, offset: u64, total: u64, page_size: u64) -> u64 {
+    if offset == 0 || offset >= total {
+        return offset;
+    }
+
+    let local = (offset - buffer_start) as usize;
+
+    // Already at the start of a line
+    if buffer[local - 1] == b'\n' {
+        return offset;
+    }
+
+    let search_end = (local + page_size as usize).min(buffer.len());
+    if let Some(pos) = buffer[local..search_end].iter().position(|&b| b == b'\n') {
+        return offset + pos as u64 + 1;
+    }
+
+    // No line break nearby: avoid splitting a multibyte character
+    let mut boundary = local;
+    while boundary < buffer.len() && (buffer[boundary] & 0xC0) == 0x80 {
+        boundary += 1;
+    }
+    buffer_start + boundary as u64
+}
+
 /// Read multiple notes in a single batch operation
 #[command]
 pub async fn agent_batch_read(
@@ -232,8 +372,13 @@ pub async fn agent_list_recent_notes(
 }
 
 /// List all notes in the vault
+/// With `include_starred_first`, starred notes come before the rest
 #[command]
-pub async fn agent_list_all_notes(vault_path: String) -> Result<Vec<NoteMetadata>, String> {
+pub async fn agent_list_all_notes(
+    vault_path: String,
+    include_starred_first: bool,
+    include_reading_time: Option<bool>,
+) -> Result<Vec<NoteMetadata>, String> {
     let vault = Path::new(&vault_path);
 
     if !vault.exists() || !vault.is_dir() {
@@ -246,8 +391,23 @@ pub async fn agent_list_all_notes(vault_path: String) -> Result<Vec<NoteMetadata
     let mut notes = Vec::new();
     collect_notes(vault, &mut notes, vault)?;
 
+    let pinned = crate::pins::pinned_set(vault);
+    let starred = crate::pins::starred_set(vault);
+    for note in &mut notes {
+        note.is_pinned = pinned.contains(&note.path);
+        note.is_starred = starred.contains(&note.path);
+    }
+
+    // Reading every file is slow on large vaults, so this is opt-in
+    if include_reading_time.unwrap_or(false) {
+        apply_reading_times(vault, &mut notes);
+    }
+
     // Sort alphabetically by title
     notes.sort_by(|a, b| a.title.cmp(&b.title));
+    if include_starred_first {
+        notes.sort_by_key(|note| !note.is_starred); // Stable, keeps titles ordered
+    }
 
     Ok(notes)
 }
@@ -453,6 +613,16 @@ pub async fn agent_update_note(
         ));
     }
 
+    // Journals are append-only
+    if fs::read_to_string(&note_path)
+        .is_ok_and(|existing| crate::journal::is_journal_note(&existing))
+    {
+        return Err(format!(
+            "Note '{}' is a journal and can't be overwritten. Use append_to_journal to add entries.",
+            filename
+        ));
+    }
+
     // Overwrite file
     fs::write(&note_path, content)
         .map_err(|e| format!("Failed to update note '{}': {}", filename, e))?;
@@ -508,6 +678,16 @@ pub async fn agent_batch_update_notes(
             continue;
         }
 
+        if fs::read_to_string(&note_path)
+            .is_ok_and(|existing| crate::journal::is_journal_note(&existing))
+        {
+            failed.push(BatchUpdateError {
+                filename: note_to_update.filename.clone(),
+                error: format!("Note '{}' is a journal and can't be overwritten", filename),
+            });
+            continue;
+        }
+
         // Update file
         match fs::write(&note_path, &note_to_update.content) {
             Ok(_) => {
@@ -550,34 +730,53 @@ pub async fn agent_batch_update_notes(
 /// Resolve a relative path to an absolute path in the vault
 #[command]
 pub async fn agent_resolve_path(vault_path: String, short_path: String) -> Result<String, String> {
-    let path = Path::new(&short_path);
+    resolve_note_path(Path::new(&vault_path), &short_path)
+        .map(|path| path.to_string_lossy().to_string())
+        .ok_or_else(|| {
+            format!(
+                "File not found: {}",
+                sanitize_path(&Path::new(&vault_path).join(&short_path), &vault_path)
+            )
+        })
+}
+
+/// Resolve a vault-relative (or absolute) note path, trying `.md` as a fallback
+pub(crate) fn resolve_note_path(vault: &Path, note_path: &str) -> Option<std::path::PathBuf> {
+    let path = Path::new(note_path);
     let full_path = if path.is_absolute() {
         path.to_path_buf()
     } else {
-        Path::new(&vault_path).join(path)
+        vault.join(path)
     };
 
     if full_path.exists() {
-        return Ok(full_path.to_string_lossy().to_string());
+        return Some(full_path);
     }
 
     // Try with .md
     let with_ext = full_path.with_extension("md");
     if with_ext.exists() {
-        return Ok(with_ext.to_string_lossy().to_string());
+        return Some(with_ext);
     }
 
-    Err(format!(
-        "File not found: {}",
-        sanitize_path(&full_path, &vault_path)
-    ))
+    None
 }
 
 /// Resolve a wikilink to a file path, handling fuzzy matching
+///
+/// `strategy` controls how basename matches are disambiguated:
+/// - `exact_only`: only accept an exact relative path (with or without `.md`)
+/// - `prefer_shortest_path` (default): pick the match closest to the vault root
+/// - `prefer_nearest`: pick the match closest to `base_dir`
+///
+/// If several candidates tie, an error listing them is returned instead of
+/// silently picking one.
 #[command]
 pub async fn agent_resolve_wikilink(
     vault_path: String,
     link_text: String,
+    strategy: Option<String>,
+    base_dir: Option<String>,
 ) -> Result<String, String> {
     let vault = Path::new(&vault_path);
 
@@ -588,6 +787,14 @@ pub async fn agent_resolve_wikilink(
         ));
     }
 
+    let strategy = strategy.unwrap_or_else(|| "prefer_shortest_path".to_string());
+    if !matches!(
+        strategy.as_str(),
+        "exact_only" | "prefer_shortest_path" | "prefer_nearest"
+    ) {
+        return Err(format!("Unknown resolution strategy: {}", strategy));
+    }
+
     // 1. Try exact match (relative path)
     let path = Path::new(&link_text);
     let full_path = if path.is_absolute() {
@@ -606,6 +813,10 @@ pub async fn agent_resolve_wikilink(
         return Ok(sanitize_path(&with_ext, &vault_path));
     }
 
+    if strategy == "exact_only" {
+        return Err(format!("Link target not found: {}", link_text));
+    }
+
     // 3. Deep search for basename match (case-insensitive and slugified)
     // This is expensive but necessary for "fuzzy" wikilinks like [[My Note]] matching "Folder/my-note.md"
     let link_stem = Path::new(&link_text)
@@ -614,15 +825,56 @@ pub async fn agent_resolve_wikilink(
         .to_string_lossy()
         .to_string();
 
-    if let Some(found_path) = find_file_fuzzy(vault, &link_stem) {
-        return Ok(sanitize_path(&found_path, &vault_path));
+    let mut candidates = Vec::new();
+    collect_fuzzy_matches(vault, &link_stem, &mut candidates);
+
+    if candidates.is_empty() {
+        return Err(format!("Link target not found: {}", link_text));
     }
 
-    Err(format!("Link target not found: {}", link_text))
+    // Rank candidates by distance (lower is better)
+    let base = match (&strategy[..], base_dir) {
+        ("prefer_nearest", Some(dir)) => {
+            let dir_path = Path::new(&dir);
+            if dir_path.is_absolute() {
+                dir_path.to_path_buf()
+            } else {
+                vault.join(dir_path)
+            }
+        }
+        _ => vault.to_path_buf(),
+    };
+
+    let ranked: Vec<(usize, std::path::PathBuf)> = candidates
+        .into_iter()
+        .map(|candidate| (path_distance(&base, &candidate), candidate))
+        .collect();
+
+    let best = ranked
+        .iter()
+        .map(|(distance, _)| *distance)
+        .min()
+        .unwrap_or(0);
+    let mut best_matches: Vec<String> = ranked
+        .iter()
+        .filter(|(distance, _)| *distance == best)
+        .map(|(_, candidate)| sanitize_path(candidate, &vault_path))
+        .collect();
+
+    if best_matches.len() > 1 {
+        best_matches.sort();
+        return Err(format!(
+            "Ambiguous link '{}': multiple notes match ({})",
+            link_text,
+            best_matches.join(", ")
+        ));
+    }
+
+    Ok(best_matches.remove(0))
 }
 
-/// Helper to find a file fuzzy matching the name
-fn find_file_fuzzy(dir: &Path, target_stem: &str) -> Option<std::path::PathBuf> {
+/// Recursively collect every file whose stem fuzzy matches the target
+fn collect_fuzzy_matches(dir: &Path, target_stem: &str, matches: &mut Vec<std::path::PathBuf>) {
     let target_lower = target_stem.to_lowercase();
     let target_slug = target_lower.replace(' ', "-");
 
@@ -638,9 +890,7 @@ fn find_file_fuzzy(dir: &Path, target_stem: &str) -> Option<std::path::PathBuf>
             }
 
             if path.is_dir() {
-                if let Some(found) = find_file_fuzzy(&path, target_stem) {
-                    return Some(found);
-                }
+                collect_fuzzy_matches(&path, target_stem, matches);
             } else if let Some(stem) = path.file_stem() {
                 let stem_str = stem.to_string_lossy().to_string();
                 let stem_lower = stem_str.to_lowercase();
@@ -650,12 +900,463 @@ fn find_file_fuzzy(dir: &Path, target_stem: &str) -> Option<std::path::PathBuf>
                     || stem_lower == target_lower
                     || stem_lower == target_slug
                 {
-                    return Some(path);
+                    matches.push(path);
                 }
             }
         }
     }
-    None
+}
+
+/// Number of directory hops between `base` and the folder containing `target`
+fn path_distance(base: &Path, target: &Path) -> usize {
+    let base_components: Vec<_> = base.components().collect();
+    let target_components: Vec<_> = target.parent().unwrap_or(target).components().collect();
+
+    let common = base_components
+        .iter()
+        .zip(target_components.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    (base_components.len() - common) + (target_components.len() - common)
+}
+
+/// Find the note a wikilink points to, without erroring on ambiguity
+///
+/// Tries the exact relative path, then with `.md`, then a fuzzy basename
+/// match where the candidate closest to the vault root wins.
+pub(crate) fn find_file_fuzzy(vault: &Path, link_text: &str) -> Option<std::path::PathBuf> {
+    find_file_with(vault, link_text, |stem, candidates| {
+        collect_fuzzy_matches(vault, stem, candidates)
+    })
+}
+
+/// `find_file_fuzzy` with the basename candidates supplied by `collect`
+fn find_file_with(
+    vault: &Path,
+    link_text: &str,
+    collect: impl FnOnce(&str, &mut Vec<std::path::PathBuf>),
+) -> Option<std::path::PathBuf> {
+    let full_path = vault.join(link_text);
+    if full_path.is_file() {
+        return Some(full_path);
+    }
+
+    let with_ext = full_path.with_extension("md");
+    if with_ext.is_file() {
+        return Some(with_ext);
+    }
+
+    let link_stem = Path::new(link_text)
+        .file_stem()?
+        .to_string_lossy()
+        .to_string();
+    let mut candidates = Vec::new();
+    collect(&link_stem, &mut candidates);
+
+    candidates.sort();
+    candidates
+        .into_iter()
+        .min_by_key(|candidate| path_distance(vault, candidate))
+}
+
+/// Every file in a vault keyed by lowercase stem, for resolving many links
+/// with one walk of the vault instead of one per link
+pub(crate) struct FuzzyIndex {
+    vault: std::path::PathBuf,
+    by_stem: std::collections::HashMap<String, Vec<std::path::PathBuf>>,
+}
+
+impl FuzzyIndex {
+    pub(crate) fn build(vault: &Path) -> Self {
+        fn walk(
+            dir: &Path,
+            by_stem: &mut std::collections::HashMap<String, Vec<std::path::PathBuf>>,
+        ) {
+            let Ok(entries) = fs::read_dir(dir) else {
+                return;
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if entry.file_name().to_string_lossy().starts_with('.') {
+                    continue;
+                }
+
+                if path.is_dir() {
+                    walk(&path, by_stem);
+                } else if let Some(stem) = path.file_stem() {
+                    let key = stem.to_string_lossy().to_lowercase();
+                    by_stem.entry(key).or_default().push(path);
+                }
+            }
+        }
+
+        let mut by_stem = std::collections::HashMap::new();
+        walk(vault, &mut by_stem);
+        Self {
+            vault: vault.to_path_buf(),
+            by_stem,
+        }
+    }
+
+    /// Same matching rules as `find_file_fuzzy`
+    pub(crate) fn find(&self, link_text: &str) -> Option<std::path::PathBuf> {
+        find_file_with(&self.vault, link_text, |stem, candidates| {
+            let lower = stem.to_lowercase();
+            let slug = lower.replace(' ', "-");
+            candidates.extend(self.by_stem.get(&lower).into_iter().flatten().cloned());
+            if slug != lower {
+                candidates.extend(self.by_stem.get(&slug).into_iter().flatten().cloned());
+            }
+        })
+    }
+}
+
+/// Turn a GitHub issue into a note with source metadata in its frontmatter
+/// Returns the relative path of the created note
+#[command]
+pub async fn create_note_from_github_issue(
+    vault_path: String,
+    issue: crate::github::GitHubIssue,
+    target_folder: Option<String>,
+) -> Result<String, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!(
+            "Vault path '{}' does not exist or is not a directory",
+            vault_path
+        ));
+    }
+
+    let mut mapping = serde_yaml::Mapping::new();
+    mapping.insert("source".into(), issue.url.clone().into());
+    mapping.insert("issue_number".into(), issue.number.into());
+    mapping.insert(
+        "labels".into(),
+        serde_yaml::Value::Sequence(issue.labels.iter().map(|l| l.as_str().into()).collect()),
+    );
+    mapping.insert("state".into(), issue.state.clone().into());
+
+    let mut body = format!(
+        "# {}
+
+",
+        issue.title
+    );
+    if !issue.author.is_empty() {
+        body.push_str(&format!(
+            "Opened by @{} on {}\n\n",
+            issue.author,
+            issue
+                .created_at
+                .split('T')
+                .next()
+                .unwrap_or(&issue.created_at)
+        ));
+    }
+    body.push_str(issue.body.trim());
+    body.push('\n');
+    let content = crate::frontmatter::compose(&mapping, &body)?;
+
+    // No `#`, it would start a heading anchor in `[[links]]` to the note
+    let file_stem = sanitize_file_stem(&format!("{} {}", issue.number, issue.title));
+
+    let folder = match target_folder
+        .filter(|f| !f.trim().is_empty())
+        .or_else(|| crate::vault_config::default_note_folder(vault))
+    {
+        Some(folder) => {
+            let relative = Path::new(&folder);
+            if relative.is_absolute()
+                || relative
+                    .components()
+                    .any(|c| c == std::path::Component::ParentDir)
+            {
+                return Err(format!("Folder '{}' must be inside the vault", folder));
+            }
+            vault.join(relative)
+        }
+        None => vault.to_path_buf(),
+    };
+    fs::create_dir_all(&folder).map_err(|e| format!("Failed to create folder: {}", e))?;
+
+    let mut note_path = folder.join(format!("{}.md", file_stem));
+    let mut counter = 1;
+    while note_path.exists() {
+        note_path = folder.join(format!("{} {}.md", file_stem, counter));
+        counter += 1;
+    }
+
+    fs::write(&note_path, content).map_err(|e| format!("Failed to create note: {}", e))?;
+
+    // Auto-commit if Git repository
+    if let Some(repo) = crate::git_manager::open_repository(vault) {
+        let _ = crate::git_manager::auto_commit_mosaic_changes(
+            &repo,
+            &format!("Imported GitHub issue #{}", issue.number),
+            &[&note_path],
+        ); // Silently fail if commit fails
+    }
+
+    Ok(sanitize_path(&note_path, &vault_path))
+}
+
+/// Rename a note so its file name matches its first `# Heading`
+/// Links across the vault are updated. Returns the new relative path
+#[command]
+pub async fn rename_note_to_match_heading(
+    vault_path: String,
+    note_path: String,
+) -> Result<String, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!(
+            "Vault path '{}' does not exist or is not a directory",
+            vault_path
+        ));
+    }
+
+    let path = resolve_note_path(vault, &note_path)
+        .filter(|p| p.is_file())
+        .ok_or_else(|| format!("Note '{}' not found", note_path))?;
+    let content = fs::read_to_string(&path).map_err(|e| format!("Failed to read note: {}", e))?;
+
+    let heading = extract_note_title(&content)
+        .ok_or_else(|| format!("Note '{}' has no # heading", note_path))?;
+    let file_stem = sanitize_file_stem(&heading);
+    if file_stem.is_empty() {
+        return Err(format!("Heading '{}' is not a usable file name", heading));
+    }
+
+    let new_path = path.with_file_name(format!("{}.md", file_stem));
+    if new_path == path {
+        return Ok(sanitize_path(&path, &vault_path));
+    }
+
+    crate::fs_extra::rename_note(
+        vault_path.clone(),
+        path.to_string_lossy().to_string(),
+        new_path.to_string_lossy().to_string(),
+    )
+    .await?;
+
+    Ok(sanitize_path(&new_path, &vault_path))
+}
+
+// ============================================================================
+// Frontmatter Tools
+// ============================================================================
+
+/// Resolve and read a note for frontmatter editing (encrypted notes are refused)
+fn read_note_for_frontmatter(
+    vault: &Path,
+    note_path: &str,
+) -> Result<(std::path::PathBuf, String), String> {
+    let path = resolve_note_path(vault, note_path)
+        .filter(|p| p.is_file())
+        .ok_or_else(|| format!("Note '{}' not found", note_path))?;
+    if path.extension().is_some_and(|ext| ext == "enc") {
+        return Err(format!(
+            "Note '{}' is encrypted. Ask the user to decrypt it first.",
+            note_path
+        ));
+    }
+
+    let content = fs::read_to_string(&path).map_err(|e| {
+        format!(
+            "Failed to read note '{}': {}",
+            sanitize_path(&path, &vault.to_string_lossy()),
+            e
+        )
+    })?;
+    Ok((path, content))
+}
+
+/// Write back a modified frontmatter mapping, leaving the body untouched
+fn write_frontmatter(
+    vault: &Path,
+    path: &Path,
+    content: &str,
+    mapping: &serde_yaml::Mapping,
+    message: &str,
+) -> Result<(), String> {
+    let new_content = crate::frontmatter::compose(mapping, crate::frontmatter::strip(content))?;
+    fs::write(path, new_content).map_err(|e| format!("Failed to write note: {}", e))?;
+
+    if let Some(repo) = crate::git_manager::open_repository(vault) {
+        let _ = crate::git_manager::auto_commit_mosaic_changes(&repo, message, &[path]);
+        // Silently fail if commit fails
+    }
+
+    Ok(())
+}
+
+/// Get a note's frontmatter as a JSON object (empty when it has none)
+#[command]
+pub async fn agent_get_frontmatter(
+    vault_path: String,
+    note_path: String,
+) -> Result<serde_json::Value, String> {
+    let (_, content) = read_note_for_frontmatter(Path::new(&vault_path), &note_path)?;
+    let mapping = crate::frontmatter::parse(&content)?;
+    serde_json::to_value(&mapping).map_err(|e| format!("Failed to convert frontmatter: {}", e))
+}
+
+/// Set one frontmatter field, creating the frontmatter block if needed
+#[command]
+pub async fn agent_set_frontmatter_field(
+    vault_path: String,
+    note_path: String,
+    key: String,
+    value: serde_json::Value,
+) -> Result<(), String> {
+    let key = key.trim().to_string();
+    if key.is_empty() {
+        return Err("Frontmatter key cannot be empty".to_string());
+    }
+
+    let vault = Path::new(&vault_path);
+    let (path, content) = read_note_for_frontmatter(vault, &note_path)?;
+    let mut mapping = crate::frontmatter::parse(&content)?;
+
+    let yaml_value = serde_yaml::to_value(&value)
+        .map_err(|e| format!("Failed to convert value for '{}': {}", key, e))?;
+    mapping.insert(serde_yaml::Value::String(key.clone()), yaml_value);
+
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    write_frontmatter(
+        vault,
+        &path,
+        &content,
+        &mapping,
+        &format!("Set {} in {}", key, file_name),
+    )
+}
+
+/// Remove one frontmatter field (dropping the block once it is empty)
+#[command]
+pub async fn agent_delete_frontmatter_field(
+    vault_path: String,
+    note_path: String,
+    key: String,
+) -> Result<(), String> {
+    let vault = Path::new(&vault_path);
+    let (path, content) = read_note_for_frontmatter(vault, &note_path)?;
+    let mut mapping = crate::frontmatter::parse(&content)?;
+
+    if mapping.remove(key.trim()).is_none() {
+        return Err(format!("Frontmatter field '{}' not found", key));
+    }
+
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    write_frontmatter(
+        vault,
+        &path,
+        &content,
+        &mapping,
+        &format!("Removed {} from {}", key.trim(), file_name),
+    )
+}
+
+// ============================================================================
+// Reading Time
+// ============================================================================
+
+const READING_TIME_CACHE_FILE: &str = ".moss/reading_time_cache.json";
+const READING_TIME_CACHE_VERSION: u32 = 1;
+const WORDS_PER_MINUTE: f32 = 200.0;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedReadingTime {
+    minutes: f32,
+    last_modified: u64, // Milliseconds, for cache invalidation
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ReadingTimeCache {
+    version: u32,
+    files: std::collections::HashMap<String, CachedReadingTime>, // Key is relative path
+}
+
+/// Estimated reading time of a note's prose, ignoring frontmatter and code blocks
+/// Short notes are rounded up to one minute
+fn estimate_reading_minutes(content: &str) -> f32 {
+    let mut in_code_block = false;
+    let mut words = 0;
+
+    for line in crate::frontmatter::strip(content).lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if !in_code_block {
+            words += line.split_whitespace().count();
+        }
+    }
+
+    (words as f32 / WORDS_PER_MINUTE).max(1.0)
+}
+
+/// Fill in `estimated_reading_minutes`, re-reading only notes modified since the last run
+fn apply_reading_times(vault: &Path, notes: &mut [NoteMetadata]) {
+    let cache_path = vault.join(READING_TIME_CACHE_FILE);
+    let mut cache = fs::read_to_string(&cache_path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<ReadingTimeCache>(&content).ok())
+        .filter(|cache| cache.version == READING_TIME_CACHE_VERSION)
+        .unwrap_or_else(|| ReadingTimeCache {
+            version: READING_TIME_CACHE_VERSION,
+            files: std::collections::HashMap::new(),
+        });
+
+    let mut seen = std::collections::HashSet::new();
+    for note in notes.iter_mut().filter(|n| !n.is_encrypted) {
+        let path = vault.join(&note.path);
+        let modified = path
+            .metadata()
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+
+        let cached = cache
+            .files
+            .get(&note.path)
+            .filter(|cached| cached.last_modified == modified)
+            .map(|cached| cached.minutes);
+        let minutes = match cached {
+            Some(minutes) => minutes,
+            None => {
+                let Ok(content) = fs::read_to_string(&path) else {
+                    continue;
+                };
+                let minutes = estimate_reading_minutes(&content);
+                cache.files.insert(
+                    note.path.clone(),
+                    CachedReadingTime {
+                        minutes,
+                        last_modified: modified,
+                    },
+                );
+                minutes
+            }
+        };
+
+        note.estimated_reading_minutes = minutes;
+        seen.insert(note.path.clone());
+    }
+
+    // Drop deleted notes, then save; a failed write only costs a re-scan next time
+    cache.files.retain(|path, _| seen.contains(path));
+    if fs::create_dir_all(vault.join(".moss")).is_ok() {
+        if let Ok(json) = serde_json::to_string(&cache) {
+            let _ = fs::write(&cache_path, json);
+        }
+    }
 }
 
 // ============================================================================
@@ -721,10 +1422,24 @@ fn search_directory(
 }
 
 /// Recursively collect all note metadata
-fn collect_notes(
+/// Folders listed in the vault config's `excluded_folders` are skipped
+pub(crate) fn collect_notes(
     dir: &Path,
     notes: &mut Vec<NoteMetadata>,
     vault_path: &Path,
+) -> Result<(), String> {
+    let excluded: Vec<std::path::PathBuf> = crate::vault_config::excluded_folders(vault_path)
+        .iter()
+        .map(|folder| vault_path.join(folder))
+        .collect();
+    collect_notes_excluding(dir, notes, vault_path, &excluded)
+}
+
+fn collect_notes_excluding(
+    dir: &Path,
+    notes: &mut Vec<NoteMetadata>,
+    vault_path: &Path,
+    excluded: &[std::path::PathBuf],
 ) -> Result<(), String> {
     let entries = fs::read_dir(dir)
         .map_err(|e| format!("Failed to read directory '{}': {}", dir.display(), e))?;
@@ -741,15 +1456,18 @@ fn collect_notes(
         }
 
         if path.is_dir() {
+            if excluded.contains(&path) {
+                continue;
+            }
             // Recursively collect from subdirectories
-            collect_notes(&path, notes, vault_path)?;
+            collect_notes_excluding(&path, notes, vault_path, excluded)?;
         } else if path.is_file() {
-            // Only process .md files
-            if let Some(ext) = path.extension() {
-                if ext == "md" {
-                    if let Some(metadata) = create_note_metadata(&path, vault_path) {
-                        notes.push(metadata);
-                    }
+            // Only process .md files (and their encrypted .md.enc counterparts)
+            let is_note = path.extension().is_some_and(|ext| ext == "md")
+                || path.to_string_lossy().ends_with(".md.enc");
+            if is_note {
+                if let Some(metadata) = create_note_metadata(&path, vault_path) {
+                    notes.push(metadata);
                 }
             }
         }
@@ -758,8 +1476,46 @@ fn collect_notes(
     Ok(())
 }
 
+/// Text of the first `# Heading` in a note (outside frontmatter and code blocks)
+pub fn extract_note_title(content: &str) -> Option<String> {
+    let mut in_code_block = false;
+
+    for line in crate::frontmatter::strip(content).lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+
+        if let Some(heading) = line.strip_prefix("# ") {
+            let heading = heading.trim().trim_end_matches('#').trim_end();
+            if !heading.is_empty() {
+                return Some(heading.to_string());
+            }
+        }
+    }
+
+    None
+}
+
+/// Replace characters that aren't valid in file names
+pub(crate) fn sanitize_file_stem(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '-',
+            _ => c,
+        })
+        .collect::<String>()
+        .trim()
+        .trim_end_matches('.')
+        .to_string()
+}
+
 /// Create note metadata from a file path
-fn create_note_metadata(path: &Path, vault_path: &Path) -> Option<NoteMetadata> {
+pub(crate) fn create_note_metadata(path: &Path, vault_path: &Path) -> Option<NoteMetadata> {
     let metadata = fs::metadata(path).ok()?;
     let modified = metadata
         .modified()
@@ -768,7 +1524,13 @@ fn create_note_metadata(path: &Path, vault_path: &Path) -> Option<NoteMetadata>
         .ok()?
         .as_secs();
 
-    let title = path.file_stem()?.to_string_lossy().to_string();
+    let file_name = path.file_name()?.to_string_lossy().to_string();
+    let is_encrypted = file_name.ends_with(".md.enc");
+    let title = if is_encrypted {
+        file_name.trim_end_matches(".md.enc").to_string()
+    } else {
+        path.file_stem()?.to_string_lossy().to_string()
+    };
 
     // Calculate relative path for the AI
     let relative_path = path
@@ -777,12 +1539,26 @@ fn create_note_metadata(path: &Path, vault_path: &Path) -> Option<NoteMetadata>
         .to_string_lossy()
         .to_string();
 
+    let display_title = if is_encrypted {
+        None
+    } else {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|content| extract_note_title(&content))
+    }
+    .unwrap_or_else(|| title.clone());
+
     Some(NoteMetadata {
         id: relative_path.clone(), // Use relative path for ID to prevent leaking absolute paths to Agent
         title,
+        display_title,
         path: relative_path, // AI sees this relative path
         modified,
         size: metadata.len(),
+        is_encrypted,
+        is_pinned: false,
+        is_starred: false,
+        estimated_reading_minutes: 0.0,
     })
 }
 
@@ -0,0 +1,573 @@
+use rand::Rng;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+use tauri::command;
+
+// ============================================================================
+// Transclusion
+// ============================================================================
+//
+// `![[Note]]` embeds a whole note, `![[Note#Heading]]` one of its sections and
+// `![[Note#^block-id]]` (or `![[Note^block-id]]`) a single block.
+// Embedded notes are inlined without their frontmatter.
+
+const MAX_TRANSCLUSION_DEPTH: usize = 3;
+
+/// Cached expansions beyond this are dropped wholesale
+const MAX_CACHED_EXPANSIONS: usize = 1000;
+
+/// Notes (with modification times) an expansion was built from
+type Sources = Vec<(PathBuf, SystemTime)>;
+
+/// Cache key: (embedding note, embed text, remaining depth)
+type ExpansionKey = (PathBuf, String, usize);
+
+/// Expanded embeds. An entry is only reused while the source and target (and
+/// anything they embed in turn) are unmodified
+static EXPANSION_CACHE: Mutex<Option<HashMap<ExpansionKey, (Sources, String)>>> = Mutex::new(None);
+
+fn embed_regex() -> Regex {
+    Regex::new(r"!\[\[([^\]|#]+)(?:#([^\]|]*))?(?:\|[^\]]*)?\]\]").unwrap()
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+fn is_unchanged(sources: &Sources) -> bool {
+    sources
+        .iter()
+        .all(|(path, time)| modified_time(path) == Some(*time))
+}
+
+/// Split link text into the note name and block ID, if it has a `^block-id`
+fn split_block_ref(name: &str) -> (&str, Option<&str>) {
+    match name.split_once('^') {
+        Some((note, block_id)) => (note.trim(), Some(block_id.trim())),
+        None => (name.trim(), None),
+    }
+}
+
+/// Resolve an embed target to a markdown note in the vault
+fn find_embed_target(vault: &Path, name: &str) -> Option<PathBuf> {
+    crate::tools::find_file_fuzzy(vault, name.trim())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+}
+
+/// Lines after a heading, up to the next heading of the same or higher level
+fn heading_section(body: &str, heading: &str) -> Option<String> {
+    let headings = crate::toc::extract_headings(body, 6);
+    let index = headings
+        .iter()
+        .position(|h| h.text.eq_ignore_ascii_case(heading.trim()))?;
+    let start = headings[index].line + 1;
+    let end = headings[index + 1..]
+        .iter()
+        .find(|h| h.level <= headings[index].level)
+        .map(|h| h.line)
+        .unwrap_or(usize::MAX);
+
+    let lines: Vec<&str> = body
+        .lines()
+        .skip(start)
+        .take(end.saturating_sub(start))
+        .collect();
+    Some(lines.join("\n"))
+}
+
+/// Replace the embeds in `content` with the content they point to
+/// Missing targets and cycles are left as written. `stack` holds the notes
+/// currently being expanded, with the note `content` belongs to last
+fn inline_embeds(
+    vault: &Path,
+    content: &str,
+    depth: usize,
+    stack: &mut Vec<PathBuf>,
+    sources: &mut Sources,
+) -> String {
+    if depth == 0 {
+        return content.to_string();
+    }
+
+    let regex = embed_regex();
+    let mut resolved = String::with_capacity(content.len());
+    let mut last = 0;
+    for caps in regex.captures_iter(content) {
+        let whole = caps.get(0).unwrap();
+        resolved.push_str(&content[last..whole.start()]);
+        last = whole.end();
+
+        match expand_embed(vault, &caps, depth, stack) {
+            Some((embed_sources, text)) => {
+                sources.extend(embed_sources);
+                resolved.push_str(&text);
+            }
+            None => resolved.push_str(whole.as_str()),
+        }
+    }
+    resolved.push_str(&content[last..]);
+
+    resolved
+}
+
+fn expand_embed(
+    vault: &Path,
+    caps: &regex::Captures,
+    depth: usize,
+    stack: &mut Vec<PathBuf>,
+) -> Option<(Sources, String)> {
+    let source = stack.last()?.clone();
+    let (name, block_id) = split_block_ref(&caps[1]);
+    let target = find_embed_target(vault, name)?;
+    if stack.contains(&target) {
+        return None;
+    }
+
+    let key = (source.clone(), caps[0].to_string(), depth);
+    if let Ok(cache) = EXPANSION_CACHE.lock() {
+        if let Some((sources, text)) = cache.as_ref().and_then(|c| c.get(&key)) {
+            if is_unchanged(sources) {
+                return Some((sources.clone(), text.clone()));
+            }
+        }
+    }
+
+    let mut sources = vec![
+        (source.clone(), modified_time(&source)?),
+        (target.clone(), modified_time(&target)?),
+    ];
+    let content = fs::read_to_string(&target).ok()?;
+    let body = crate::frontmatter::strip(&content);
+    let anchor = caps.get(2).map(|m| m.as_str().trim());
+    let section = match (block_id, anchor) {
+        (Some(block_id), _) => block_text(body, block_id)?,
+        (None, Some(anchor)) if anchor.starts_with('^') => block_text(body, &anchor[1..])?,
+        (None, Some(heading)) if !heading.is_empty() => heading_section(body, heading)?,
+        _ => body.to_string(),
+    };
+
+    stack.push(target);
+    let text = inline_embeds(vault, &section, depth - 1, stack, &mut sources);
+    stack.pop();
+    let text = text.trim().to_string();
+
+    if let Ok(mut cache) = EXPANSION_CACHE.lock() {
+        let cache = cache.get_or_insert_with(HashMap::new);
+        if cache.len() >= MAX_CACHED_EXPANSIONS {
+            cache.clear();
+        }
+        cache.insert(key, (sources.clone(), text.clone()));
+    }
+
+    Some((sources, text))
+}
+
+/// Note content with `![[Note]]` and `![[Note#Heading]]` embeds inlined
+/// Embeds inside embedded notes are followed up to `depth` levels (at most 3)
+#[command]
+pub async fn resolve_transclusions(
+    vault_path: String,
+    note_path: String,
+    depth: usize,
+) -> Result<String, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let path = crate::tools::resolve_note_path(vault, &note_path)
+        .filter(|p| p.is_file())
+        .ok_or_else(|| format!("Note '{}' not found", note_path))?;
+    let content = fs::read_to_string(&path).map_err(|e| format!("Failed to read note: {}", e))?;
+
+    let mut stack = vec![path];
+    let mut sources = Vec::new();
+    Ok(inline_embeds(
+        vault,
+        &content,
+        depth.min(MAX_TRANSCLUSION_DEPTH),
+        &mut stack,
+        &mut sources,
+    ))
+}
+
+/// Notes that embed this note, as relative paths sorted alphabetically
+#[command]
+pub async fn get_notes_that_transclude(
+    vault_path: String,
+    note_path: String,
+) -> Result<Vec<String>, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let target = crate::tools::resolve_note_path(vault, &note_path)
+        .filter(|p| p.is_file())
+        .ok_or_else(|| format!("Note '{}' not found", note_path))?;
+
+    let mut notes = Vec::new();
+    crate::tools::collect_notes(vault, &mut notes, vault)?;
+
+    let regex = embed_regex();
+    let mut resolved: HashMap<String, Option<PathBuf>> = HashMap::new();
+    let mut transcluding = Vec::new();
+    for note in notes.iter().filter(|n| !n.is_encrypted) {
+        let path = vault.join(&note.path);
+        if path == target {
+            continue;
+        }
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+
+        let embeds_target = regex.captures_iter(&content).any(|caps| {
+            let name = split_block_ref(&caps[1]).0;
+            resolved
+                .entry(name.to_string())
+                .or_insert_with(|| find_embed_target(vault, name))
+                .as_ref()
+                == Some(&target)
+        });
+        if embeds_target {
+            transcluding.push(note.path.clone());
+        }
+    }
+
+    transcluding.sort();
+    Ok(transcluding)
+}
+
+// ============================================================================
+// Block References
+// ============================================================================
+//
+// A block is a paragraph or a list item. Its ID is a trailing ` ^block-id`,
+// added to its last line.
+
+const BLOCK_ID_LENGTH: usize = 6;
+const BLOCK_ID_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+/// A block's line range in the note body (inclusive) and its ID, if it has one
+struct Block {
+    start: usize,
+    end: usize,
+    id: Option<String>,
+}
+
+fn block_id_regex() -> Regex {
+    Regex::new(r"(?:^|\s)\^([A-Za-z0-9-]+)\s*$").unwrap()
+}
+
+/// Paragraphs and list items of a note body, skipping headings (ATX and
+/// setext), tables, rules, blockquotes, HTML and comments, and fenced or
+/// indented code
+fn find_blocks(body: &str) -> Vec<Block> {
+    let list_item = Regex::new(r"^\s*(?:[-*+]|\d+[.)])\s+").unwrap();
+    let setext_underline = Regex::new(r"^(?:=+|-+)\s*$").unwrap();
+    let id_regex = block_id_regex();
+
+    let mut blocks: Vec<Block> = Vec::new();
+    // Whether the last block is still open, and whether it's a list item
+    let mut open: Option<bool> = None;
+    let mut in_code_block = false;
+    let mut in_comment = false;
+    let mut in_toc = false;
+
+    for (index, line) in body.lines().enumerate() {
+        let trimmed = line.trim();
+        if in_comment {
+            in_comment = !trimmed.contains("-->");
+            continue;
+        }
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code_block = !in_code_block;
+            open = None;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+
+        // A paragraph followed by `===` or `---` is a setext heading
+        if open == Some(false) && setext_underline.is_match(trimmed) {
+            blocks.pop();
+            open = None;
+            continue;
+        }
+
+        if trimmed.starts_with("<!--") {
+            in_comment = !trimmed.contains("-->");
+            // Generated tables of contents are rewritten wholesale
+            match trimmed {
+                crate::toc::TOC_START => in_toc = true,
+                crate::toc::TOC_END => in_toc = false,
+                _ => {}
+            }
+            open = None;
+            continue;
+        }
+        if in_toc {
+            continue;
+        }
+
+        // Indented code only starts outside paragraphs and list items
+        let is_indented_code =
+            open.is_none() && (line.starts_with("    ") || line.starts_with('\t'));
+        let is_structure = trimmed.is_empty()
+            || is_indented_code
+            || (trimmed.starts_with('#')
+                && trimmed.trim_start_matches('#').starts_with([' ', '\t']))
+            || trimmed.starts_with('|')
+            || trimmed.starts_with('>')
+            || trimmed.starts_with('<')
+            || matches!(trimmed, "---" | "***" | "___");
+        if is_structure {
+            open = None;
+            continue;
+        }
+
+        let is_list_item = list_item.is_match(line);
+        // Indented lines continue a list item; any line continues a paragraph
+        let continues = match open {
+            Some(true) => !is_list_item && line.starts_with([' ', '\t']),
+            Some(false) => !is_list_item,
+            None => false,
+        };
+
+        match blocks.last_mut() {
+            Some(block) if continues => block.end = index,
+            _ => blocks.push(Block {
+                start: index,
+                end: index,
+                id: None,
+            }),
+        }
+        open = Some(is_list_item || (continues && open == Some(true)));
+    }
+
+    let lines: Vec<&str> = body.lines().collect();
+    for block in &mut blocks {
+        // List items often carry the ID on their first line
+        block.id = lines[block.start..=block.end]
+            .iter()
+            .rev()
+            .find_map(|line| id_regex.captures(line))
+            .map(|caps| caps[1].to_string());
+    }
+
+    blocks
+}
+
+/// Text of the block with this ID, without the ID itself
+fn block_text(body: &str, block_id: &str) -> Option<String> {
+    let block = find_blocks(body)
+        .into_iter()
+        .find(|b| b.id.as_deref() == Some(block_id))?;
+
+    let id_regex = block_id_regex();
+    let lines: Vec<String> = body
+        .lines()
+        .skip(block.start)
+        .take(block.end - block.start + 1)
+        .map(|line| id_regex.replace(line, "").to_string())
+        .collect();
+    Some(lines.join("\n").trim().to_string())
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BrokenBlockRef {
+    pub source_note: String, // Relative path of the note containing the reference
+    pub link_text: String,
+    pub target_note: Option<String>, // Relative path, if the note was found
+    pub block_id: String,
+    pub reason: String, // "note not found" or "block id not found in note"
+}
+
+/// Block references (`[[Note#^block-id]]`, embedded or not) that don't resolve
+/// `[[#^block-id]]` refers to a block in the same note
+#[command]
+pub async fn scan_broken_block_references(
+    vault_path: String,
+) -> Result<Vec<BrokenBlockRef>, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let link_regex = Regex::new(r"\[\[([^\]|]+)(?:\|[^\]]*)?\]\]").unwrap();
+
+    let mut notes = Vec::new();
+    crate::tools::collect_notes(vault, &mut notes, vault)?;
+
+    // Block IDs per target note, read once
+    let mut block_ids: HashMap<PathBuf, Vec<String>> = HashMap::new();
+    let mut broken = Vec::new();
+    for note in notes.iter().filter(|n| !n.is_encrypted) {
+        let source = vault.join(&note.path);
+        let Ok(content) = fs::read_to_string(&source) else {
+            continue;
+        };
+
+        for caps in link_regex.captures_iter(&content) {
+            let link_text = caps[1].trim();
+            let (name, Some(block_id)) = split_block_ref(link_text) else {
+                continue;
+            };
+            let name = name.trim_end_matches('#').trim();
+            if block_id.is_empty() {
+                continue;
+            }
+
+            let target = if name.is_empty() {
+                Some(source.clone())
+            } else {
+                crate::tools::find_file_fuzzy(vault, name)
+            };
+            let Some(target) = target else {
+                broken.push(BrokenBlockRef {
+                    source_note: note.path.clone(),
+                    link_text: link_text.to_string(),
+                    target_note: None,
+                    block_id: block_id.to_string(),
+                    reason: "note not found".to_string(),
+                });
+                continue;
+            };
+
+            let ids = block_ids.entry(target.clone()).or_insert_with(|| {
+                let content = fs::read_to_string(&target).unwrap_or_default();
+                find_blocks(crate::frontmatter::strip(&content))
+                    .into_iter()
+                    .filter_map(|b| b.id)
+                    .collect()
+            });
+            if !ids.iter().any(|id| id == block_id) {
+                broken.push(BrokenBlockRef {
+                    source_note: note.path.clone(),
+                    link_text: link_text.to_string(),
+                    target_note: Some(
+                        target
+                            .strip_prefix(vault)
+                            .unwrap_or(&target)
+                            .to_string_lossy()
+                            .to_string(),
+                    ),
+                    block_id: block_id.to_string(),
+                    reason: "block id not found in note".to_string(),
+                });
+            }
+        }
+    }
+
+    broken.sort_by(|a, b| {
+        a.source_note
+            .cmp(&b.source_note)
+            .then_with(|| a.link_text.cmp(&b.link_text))
+    });
+
+    Ok(broken)
+}
+
+fn random_block_id() -> String {
+    let mut rng = rand::thread_rng();
+    (0..BLOCK_ID_LENGTH)
+        .map(|_| BLOCK_ID_CHARS[rng.gen_range(0..BLOCK_ID_CHARS.len())] as char)
+        .collect()
+}
+
+/// Give every paragraph and list item without a block ID a random one
+/// Returns how many IDs were added
+#[command]
+pub async fn generate_block_ids(vault_path: String, note_path: String) -> Result<usize, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let path = crate::tools::resolve_note_path(vault, &note_path)
+        .filter(|p| p.is_file())
+        .ok_or_else(|| format!("Note '{}' not found", note_path))?;
+    let content = fs::read_to_string(&path).map_err(|e| format!("Failed to read note: {}", e))?;
+
+    let body = crate::frontmatter::strip(&content);
+    let frontmatter = &content[..content.len() - body.len()];
+    let blocks = find_blocks(body);
+
+    let mut used: std::collections::HashSet<String> =
+        blocks.iter().filter_map(|b| b.id.clone()).collect();
+    let mut ids: std::collections::HashMap<usize, String> = std::collections::HashMap::new();
+    for block in blocks.iter().filter(|b| b.id.is_none()) {
+        let id = loop {
+            let id = random_block_id();
+            if used.insert(id.clone()) {
+                break id;
+            }
+        };
+        ids.insert(block.end, id);
+    }
+
+    if ids.is_empty() {
+        return Ok(0);
+    }
+
+    // Splice the IDs in before each line's ending, keeping CRLF and the
+    // trailing newline as they were
+    let mut new_content = String::with_capacity(content.len() + ids.len() * 9);
+    new_content.push_str(frontmatter);
+    for (index, line) in body.split_inclusive('\n').enumerate() {
+        match ids.get(&index) {
+            Some(id) => {
+                let text = line.trim_end_matches(['\r', '\n']);
+                new_content.push_str(text.trim_end());
+                new_content.push_str(&format!(" ^{}", id));
+                new_content.push_str(&line[text.len()..]);
+            }
+            None => new_content.push_str(line),
+        }
+    }
+    let added = ids.len();
+    fs::write(&path, new_content).map_err(|e| format!("Failed to write note: {}", e))?;
+
+    // Auto-commit if Git repository
+    if let Some(repo) = crate::git_manager::open_repository(vault) {
+        let _ = crate::git_manager::auto_commit_mosaic_changes(
+            &repo,
+            &format!("Added block IDs to {}", note_path),
+            &[&path],
+        ); // Silently fail if commit fails
+    }
+
+    Ok(added)
+}
+
+/// Text of the paragraph or list item ending with `^{block_id}`
+#[command]
+pub async fn resolve_block_reference(
+    vault_path: String,
+    note_path: String,
+    block_id: String,
+) -> Result<String, String> {
+    let vault = Path::new(&vault_path);
+
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let path = crate::tools::resolve_note_path(vault, &note_path)
+        .filter(|p| p.is_file())
+        .ok_or_else(|| format!("Note '{}' not found", note_path))?;
+    let content = fs::read_to_string(&path).map_err(|e| format!("Failed to read note: {}", e))?;
+
+    let block_id = block_id.trim().trim_start_matches('^');
+    block_text(crate::frontmatter::strip(&content), block_id)
+        .ok_or_else(|| format!("Block '^{}' not found in '{}'", block_id, note_path))
+}
@@ -0,0 +1,247 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::command;
+
+// ============================================================================
+// Vault Configuration
+// ============================================================================
+
+const CONFIG_FILE_NAME: &str = ".moss/vault_config.json";
+
+const DEFAULT_AUTO_COMMIT_INTERVAL_MINUTES: u32 = 15;
+
+/// Vault-wide settings, used as fallbacks when a command isn't given a value
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VaultConfig {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub owner: Option<String>,
+    #[serde(default)]
+    pub created_at: u64, // Unix timestamp
+    #[serde(default)]
+    pub default_note_folder: Option<String>, // Relative folder for new and imported notes
+    #[serde(default)]
+    pub excluded_folders: Vec<String>, // Relative folders left out of note listings
+    #[serde(default)]
+    pub ai_provider: Option<String>, // Used by AI note commands called with an empty provider
+    #[serde(default)]
+    pub default_ai_model: Option<String>, // Used by AI note commands called with an empty model
+    #[serde(default)]
+    pub auto_commit_enabled: bool, // Until auto-commit is first started or stopped
+    #[serde(default = "default_auto_commit_interval")]
+    pub auto_commit_interval_minutes: u32,
+    #[serde(default)]
+    pub graph_snapshot_interval_hours: u32, // 0 disables automatic graph snapshots
+    #[serde(default)]
+    pub inbox_note: Option<String>, // Relative path for quick captures (default `Inbox.md`)
+    #[serde(default)]
+    pub external_editor: Option<String>, // Last command used to open notes externally
+}
+
+fn default_auto_commit_interval() -> u32 {
+    DEFAULT_AUTO_COMMIT_INTERVAL_MINUTES
+}
+
+impl VaultConfig {
+    /// Fresh config named after the vault folder
+    fn new_for(vault: &Path) -> Self {
+        Self {
+            name: vault
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| "Vault".to_string()),
+            description: None,
+            owner: None,
+            created_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            default_note_folder: None,
+            excluded_folders: Vec::new(),
+            ai_provider: None,
+            default_ai_model: None,
+            auto_commit_enabled: false,
+            auto_commit_interval_minutes: DEFAULT_AUTO_COMMIT_INTERVAL_MINUTES,
+            graph_snapshot_interval_hours: 0,
+            inbox_note: None,
+            external_editor: None,
+        }
+    }
+}
+
+/// Load the vault config, or a fresh one if none has been saved yet
+pub fn load_config(vault: &Path) -> Result<VaultConfig, String> {
+    let path = vault.join(CONFIG_FILE_NAME);
+    if !path.exists() {
+        return Ok(VaultConfig::new_for(vault));
+    }
+
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read vault config: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse vault config: {}", e))
+}
+
+/// Vault folders to leave out when collecting notes, without surrounding slashes
+pub(crate) fn excluded_folders(vault: &Path) -> Vec<String> {
+    load_config(vault)
+        .map(|config| config.excluded_folders)
+        .unwrap_or_default()
+        .iter()
+        .map(|f| f.trim().trim_matches('/').to_string())
+        .filter(|f| !f.is_empty())
+        .collect()
+}
+
+/// Vault default note folder, for commands called without an explicit folder
+pub(crate) fn default_note_folder(vault: &Path) -> Option<String> {
+    load_config(vault)
+        .ok()?
+        .default_note_folder
+        .filter(|f| !f.trim().is_empty())
+}
+
+#[command]
+pub async fn load_vault_config(vault_path: String) -> Result<VaultConfig, String> {
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    load_config(vault)
+}
+
+#[command]
+pub async fn save_vault_config(vault_path: String, config: VaultConfig) -> Result<(), String> {
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+    if config.name.trim().is_empty() {
+        return Err("Vault name cannot be empty".to_string());
+    }
+
+    save_config(vault, &config)
+}
+
+/// Write the vault config to `.moss/vault_config.json`
+pub(crate) fn save_config(vault: &Path, config: &VaultConfig) -> Result<(), String> {
+    let path = vault.join(CONFIG_FILE_NAME);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create .moss dir: {}", e))?;
+    }
+
+    let json = serde_json::to_string_pretty(config)
+        .map_err(|e| format!("Failed to serialize vault config: {}", e))?;
+    fs::write(&path, json).map_err(|e| format!("Failed to write vault config: {}", e))
+}
+
+// ============================================================================
+// Folder Sort Order
+// ============================================================================
+
+const FOLDER_SORT_FILE_NAME: &str = ".moss/folder_sort_config.json";
+
+const SORT_FIELDS: &[&str] = &["name", "modified", "created", "size", "link_count"];
+
+/// How the file tree orders one folder's direct children
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FolderSortConfig {
+    pub sort_by: String,   // "name", "modified", "created", "size" or "link_count"
+    pub direction: String, // "asc" or "desc"
+    pub folders_first: bool,
+    pub pinned_first: bool, // Pinned notes above folders too (they always lead the files)
+}
+
+impl Default for FolderSortConfig {
+    fn default() -> Self {
+        Self {
+            sort_by: "name".to_string(),
+            direction: "asc".to_string(),
+            folders_first: true,
+            pinned_first: true,
+        }
+    }
+}
+
+/// Saved sort configs keyed by relative folder path ("" for the vault root)
+pub(crate) fn load_folder_sort_configs(
+    vault: &Path,
+) -> Result<HashMap<String, FolderSortConfig>, String> {
+    let path = vault.join(FOLDER_SORT_FILE_NAME);
+    if !path.exists() {
+        return Ok(HashMap::new());
+    }
+
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read folder sort config: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse folder sort config: {}", e))
+}
+
+/// Normalize a folder path (absolute or relative) to its config key
+fn folder_sort_key(vault: &Path, folder_path: &str) -> String {
+    let folder = Path::new(folder_path);
+    folder
+        .strip_prefix(vault)
+        .unwrap_or(folder)
+        .to_string_lossy()
+        .trim_matches('/')
+        .to_string()
+}
+
+#[command]
+pub async fn get_folder_sort_config(
+    vault_path: String,
+    folder_path: String,
+) -> Result<FolderSortConfig, String> {
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let key = folder_sort_key(vault, &folder_path);
+    Ok(load_folder_sort_configs(vault)?
+        .remove(&key)
+        .unwrap_or_default())
+}
+
+#[command]
+pub async fn set_folder_sort_config(
+    vault_path: String,
+    folder_path: String,
+    config: FolderSortConfig,
+) -> Result<(), String> {
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+    if !SORT_FIELDS.contains(&config.sort_by.as_str()) {
+        return Err(format!(
+            "Unknown sort field '{}' (expected one of: {})",
+            config.sort_by,
+            SORT_FIELDS.join(", ")
+        ));
+    }
+    if config.direction != "asc" && config.direction != "desc" {
+        return Err(format!(
+            "Unknown sort direction '{}' (expected 'asc' or 'desc')",
+            config.direction
+        ));
+    }
+
+    let mut configs = load_folder_sort_configs(vault)?;
+    configs.insert(folder_sort_key(vault, &folder_path), config);
+
+    let path = vault.join(FOLDER_SORT_FILE_NAME);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create .moss dir: {}", e))?;
+    }
+
+    let json = serde_json::to_string_pretty(&configs)
+        .map_err(|e| format!("Failed to serialize folder sort config: {}", e))?;
+    fs::write(&path, json).map_err(|e| format!("Failed to write folder sort config: {}", e))
+}
@@ -0,0 +1,369 @@
+use crate::vault_config::{self, VaultConfig};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::State;
+
+// ============================================================================
+// Vault Registry
+// ============================================================================
+//
+// Tracks every vault the user has open so personal and work vaults can be
+// used side by side. The list of open vaults survives restarts through
+// `.moss/global_registry.json` in the app data directory.
+
+const REGISTRY_FILE_NAME: &str = ".moss/global_registry.json";
+
+pub struct VaultHandle {
+    pub path: PathBuf,
+    pub config: VaultConfig,
+    pub watcher_active: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VaultInfo {
+    pub id: String,
+    pub name: String,
+    pub path: String,
+    pub is_active: bool,
+    pub watcher_active: bool,
+}
+
+/// On-disk form of the registry (configs are re-read from each vault)
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct RegistryFile {
+    vaults: HashMap<String, String>, // vault ID -> path
+    active: Option<String>,
+}
+
+pub struct VaultRegistry {
+    pub vaults: Mutex<HashMap<String, VaultHandle>>,
+    pub active: Mutex<Option<String>>,
+    registry_path: Option<PathBuf>,
+}
+
+impl VaultRegistry {
+    /// Restore the registry saved under `app_data_dir`, dropping vaults that no longer exist
+    pub fn load(app_data_dir: Option<PathBuf>) -> Self {
+        let registry_path = app_data_dir.map(|dir| dir.join(REGISTRY_FILE_NAME));
+
+        let saved: RegistryFile = registry_path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+
+        let mut vaults = HashMap::new();
+        for (id, path) in saved.vaults {
+            let path = PathBuf::from(path);
+            if !path.is_dir() {
+                continue;
+            }
+            let Ok(config) = vault_config::load_config(&path) else {
+                continue;
+            };
+            vaults.insert(
+                id,
+                VaultHandle {
+                    path,
+                    config,
+                    watcher_active: false,
+                },
+            );
+        }
+
+        let active = saved.active.filter(|id| vaults.contains_key(id));
+
+        Self {
+            vaults: Mutex::new(vaults),
+            active: Mutex::new(active),
+            registry_path,
+        }
+    }
+
+    fn save(&self) -> Result<(), String> {
+        let Some(path) = &self.registry_path else {
+            return Ok(());
+        };
+
+        let vaults = self.vaults.lock().map_err(|e| e.to_string())?;
+        let active = self.active.lock().map_err(|e| e.to_string())?;
+        let file = RegistryFile {
+            vaults: vaults
+                .iter()
+                .map(|(id, handle)| (id.clone(), handle.path.to_string_lossy().to_string()))
+                .collect(),
+            active: active.clone(),
+        };
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Failed to create .moss dir: {}", e))?;
+        }
+        let json = serde_json::to_string_pretty(&file)
+            .map_err(|e| format!("Failed to serialize vault registry: {}", e))?;
+        fs::write(path, json).map_err(|e| format!("Failed to write vault registry: {}", e))
+    }
+
+    /// Record whether a registered vault currently has a file watcher
+    pub fn set_watcher_active(&self, vault_id: &str, active: bool) {
+        if let Ok(mut vaults) = self.vaults.lock() {
+            if let Some(handle) = vaults.get_mut(vault_id) {
+                handle.watcher_active = active;
+            }
+        }
+    }
+}
+
+/// Stable ID for a vault, derived from its canonical path
+fn vault_id_for(path: &Path) -> String {
+    let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let digest = Sha256::digest(canonical.to_string_lossy().as_bytes());
+    digest[..6].iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Open a vault alongside any others, returning its ID
+/// Registering the same folder twice returns the existing ID
+#[tauri::command]
+pub async fn register_vault(
+    registry: State<'_, VaultRegistry>,
+    vault_path: String,
+) -> Result<String, String> {
+    let path = Path::new(&vault_path);
+    if !path.exists() || !path.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let id = vault_id_for(path);
+    let config = vault_config::load_config(path)?;
+    {
+        let mut vaults = registry.vaults.lock().map_err(|e| e.to_string())?;
+        let watcher_active = vaults.get(&id).is_some_and(|h| h.watcher_active);
+        vaults.insert(
+            id.clone(),
+            VaultHandle {
+                path: path.to_path_buf(),
+                config,
+                watcher_active,
+            },
+        );
+
+        // The first vault opened becomes the active one
+        let mut active = registry.active.lock().map_err(|e| e.to_string())?;
+        if active.is_none() {
+            *active = Some(id.clone());
+        }
+    }
+
+    registry.save()?;
+    Ok(id)
+}
+
+/// Close a vault and stop watching it
+#[tauri::command]
+pub async fn unregister_vault(
+    registry: State<'_, VaultRegistry>,
+    watcher_state: State<'_, crate::watcher::WatcherState>,
+    vault_id: String,
+) -> Result<(), String> {
+    {
+        let mut vaults = registry.vaults.lock().map_err(|e| e.to_string())?;
+        if vaults.remove(&vault_id).is_none() {
+            return Err(format!("Vault '{}' is not open", vault_id));
+        }
+
+        let mut active = registry.active.lock().map_err(|e| e.to_string())?;
+        if active.as_deref() == Some(vault_id.as_str()) {
+            *active = None;
+        }
+    }
+
+    watcher_state.stop(&vault_id)?;
+    registry.save()
+}
+
+#[tauri::command]
+pub async fn list_open_vaults(
+    registry: State<'_, VaultRegistry>,
+) -> Result<Vec<VaultInfo>, String> {
+    let vaults = registry.vaults.lock().map_err(|e| e.to_string())?;
+    let active = registry.active.lock().map_err(|e| e.to_string())?;
+
+    let mut infos: Vec<VaultInfo> = vaults
+        .iter()
+        .map(|(id, handle)| VaultInfo {
+            id: id.clone(),
+            name: handle.config.name.clone(),
+            path: handle.path.to_string_lossy().to_string(),
+            is_active: active.as_deref() == Some(id.as_str()),
+            watcher_active: handle.watcher_active,
+        })
+        .collect();
+    infos.sort_by_key(|info| info.name.to_lowercase());
+
+    Ok(infos)
+}
+
+#[tauri::command]
+pub async fn get_active_vault(
+    registry: State<'_, VaultRegistry>,
+) -> Result<Option<String>, String> {
+    Ok(registry.active.lock().map_err(|e| e.to_string())?.clone())
+}
+
+#[tauri::command]
+pub async fn set_active_vault(
+    app_handle: tauri::AppHandle,
+    registry: State<'_, VaultRegistry>,
+    watcher_state: State<'_, crate::watcher::WatcherState>,
+    vault_id: String,
+) -> Result<(), String> {
+    let watched_path = {
+        let vaults = registry.vaults.lock().map_err(|e| e.to_string())?;
+        let handle = vaults
+            .get(&vault_id)
+            .ok_or_else(|| format!("Vault '{}' is not open", vault_id))?;
+        handle.watcher_active.then(|| handle.path.clone())
+    };
+
+    *registry.active.lock().map_err(|e| e.to_string())? = Some(vault_id);
+    registry.save()?;
+
+    // Worktree watchers follow the active vault
+    match watched_path {
+        Some(path) => crate::watcher::watch_vault_worktrees(app_handle, &watcher_state, &path),
+        None => watcher_state.stop_worktrees(),
+    }
+}
+
+// ============================================================================
+// Cross-Vault Links
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrossVaultLink {
+    pub vault_path: String,
+    pub note_path: String, // Relative to `vault_path`
+    pub vault_name: String,
+}
+
+/// Split `[[VaultName::NoteName]]` link text into its vault and note parts
+pub(crate) fn split_vault_link(link_text: &str) -> (Option<&str>, &str) {
+    match link_text.split_once("::") {
+        Some((vault, note)) if !vault.trim().is_empty() => (Some(vault.trim()), note.trim()),
+        _ => (None, link_text.trim()),
+    }
+}
+
+/// Registered vaults as (name, path), copied out so the lock isn't held while searching
+pub(crate) fn registered_vaults(
+    registry: &VaultRegistry,
+) -> Result<Vec<(String, PathBuf)>, String> {
+    let vaults = registry.vaults.lock().map_err(|e| e.to_string())?;
+    let mut list: Vec<(String, PathBuf)> = vaults
+        .values()
+        .map(|handle| (handle.config.name.clone(), handle.path.clone()))
+        .collect();
+    list.sort();
+    Ok(list)
+}
+
+fn same_vault(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+fn find_in_vault(
+    name: &str,
+    vault: &Path,
+    note: &str,
+    find: &impl Fn(&Path, &str) -> Option<PathBuf>,
+) -> Option<CrossVaultLink> {
+    let found = find(vault, note)?;
+    Some(CrossVaultLink {
+        vault_path: vault.to_string_lossy().to_string(),
+        note_path: found
+            .strip_prefix(vault)
+            .unwrap_or(&found)
+            .to_string_lossy()
+            .to_string(),
+        vault_name: name.to_string(),
+    })
+}
+
+/// Resolve link text against the source vault first, then every other open vault
+/// A `VaultName::` prefix restricts the search to the vault with that name
+pub(crate) fn resolve_across(
+    vaults: &[(String, PathBuf)],
+    source_vault: &Path,
+    link_text: &str,
+) -> Option<CrossVaultLink> {
+    resolve_across_with(
+        vaults,
+        source_vault,
+        link_text,
+        &crate::tools::find_file_fuzzy,
+    )
+}
+
+/// `resolve_across` with a custom note lookup, e.g. prebuilt `FuzzyIndex`es
+pub(crate) fn resolve_across_with(
+    vaults: &[(String, PathBuf)],
+    source_vault: &Path,
+    link_text: &str,
+    find: &impl Fn(&Path, &str) -> Option<PathBuf>,
+) -> Option<CrossVaultLink> {
+    let (vault_name, note) = split_vault_link(link_text);
+
+    if let Some(vault_name) = vault_name {
+        return vaults
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(vault_name))
+            .and_then(|(name, path)| find_in_vault(name, path, note, find));
+    }
+
+    let source_name = vaults
+        .iter()
+        .find(|(_, path)| same_vault(path, source_vault))
+        .map(|(name, _)| name.clone())
+        .or_else(|| vault_config::load_config(source_vault).ok().map(|c| c.name))
+        .unwrap_or_default();
+
+    find_in_vault(&source_name, source_vault, note, find).or_else(|| {
+        vaults
+            .iter()
+            .filter(|(_, path)| !same_vault(path, source_vault))
+            .find_map(|(name, path)| find_in_vault(name, path, note, find))
+    })
+}
+
+/// Resolve a wikilink in any open vault, preferring the vault it was written in
+/// Supports `[[VaultName::NoteName]]` to target a specific vault
+#[tauri::command]
+pub async fn resolve_wikilink_across_vaults(
+    registry: State<'_, VaultRegistry>,
+    link_text: String,
+    source_vault_path: String,
+) -> Result<CrossVaultLink, String> {
+    let source_vault = Path::new(&source_vault_path);
+    if !source_vault.exists() || !source_vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", source_vault_path));
+    }
+
+    let vaults = registered_vaults(&registry)?;
+    if let (Some(vault_name), _) = split_vault_link(&link_text) {
+        if !vaults
+            .iter()
+            .any(|(name, _)| name.eq_ignore_ascii_case(vault_name))
+        {
+            return Err(format!("No open vault named '{}'", vault_name));
+        }
+    }
+
+    resolve_across(&vaults, source_vault, &link_text)
+        .ok_or_else(|| format!("Link target not found in any vault: {}", link_text))
+}
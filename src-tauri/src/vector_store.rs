@@ -1,5 +1,6 @@
 use rusqlite::{params, Connection, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::Path;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -10,6 +11,48 @@ pub struct DocumentChunk {
     pub vector: Vec<f32>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StoreStats {
+    pub total_chunks: usize,
+    pub unique_files: usize,
+    pub size_bytes: u64, // On-disk size of the SQLite database
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DuplicatePair {
+    pub note_a: String,
+    pub note_b: String,
+    pub similarity: f32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SimilarityMatrix {
+    pub note_ids: Vec<String>,           // Relative paths, alphabetically
+    pub pairs: Vec<(usize, usize, f32)>, // (index_i, index_j, cosine similarity), i < j
+}
+
+/// How query and stored vectors are compared
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistanceMetric {
+    Cosine,
+    Euclidean, // Smaller is more similar
+    DotProduct,
+}
+
+impl DistanceMetric {
+    pub fn parse(metric: &str) -> Result<Self, String> {
+        match metric.to_lowercase().as_str() {
+            "cosine" => Ok(Self::Cosine),
+            "euclidean" => Ok(Self::Euclidean),
+            "dot" | "dot_product" | "dotproduct" => Ok(Self::DotProduct),
+            _ => Err(format!(
+                "Unknown distance metric '{}' (expected cosine, euclidean or dot_product)",
+                metric
+            )),
+        }
+    }
+}
+
 pub struct VectorStore {
     conn: Connection,
 }
@@ -33,6 +76,18 @@ impl VectorStore {
         )
         .map_err(|e| e.to_string())?;
 
+        // When each file was last indexed and its modification time, used to prune the
+        // least recently modified files
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS file_mtimes (
+                file_path TEXT PRIMARY KEY,
+                mtime INTEGER NOT NULL,
+                indexed_at INTEGER NOT NULL
+            )",
+            [],
+        )
+        .map_err(|e| e.to_string())?;
+
         Ok(Self { conn })
     }
 
@@ -72,6 +127,7 @@ impl VectorStore {
         &self,
         query_vector: &[f32],
         limit: usize,
+        metric: DistanceMetric,
     ) -> Result<Vec<(DocumentChunk, f32)>, String> {
         let mut stmt = self
             .conn
@@ -107,28 +163,221 @@ impl VectorStore {
         let mut scores: Vec<(DocumentChunk, f32)> = Vec::new();
         for chunk_result in chunk_iter {
             let chunk = chunk_result.map_err(|e| e.to_string())?;
-            let score = cosine_similarity(query_vector, &chunk.vector);
+            let score = match metric {
+                DistanceMetric::Cosine => cosine_similarity(query_vector, &chunk.vector),
+                DistanceMetric::Euclidean => euclidean_distance(query_vector, &chunk.vector),
+                DistanceMetric::DotProduct => dot_product(query_vector, &chunk.vector),
+            };
             scores.push((chunk, score));
         }
 
-        // Sort by score descending
-        scores.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        // Sort best match first (distances ascending, similarities descending)
+        scores.sort_by(|a, b| {
+            let ordering = match metric {
+                DistanceMetric::Euclidean => a.1.partial_cmp(&b.1),
+                DistanceMetric::Cosine | DistanceMetric::DotProduct => b.1.partial_cmp(&a.1),
+            };
+            ordering.unwrap_or(std::cmp::Ordering::Equal)
+        });
 
         // Take top K
         Ok(scores.into_iter().take(limit).collect())
     }
 
+    /// Average all chunk vectors per file into one note-level embedding
+    pub fn file_embeddings(&self) -> Result<HashMap<String, Vec<f32>>, String> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT file_path, vector FROM chunks")
+            .map_err(|e| e.to_string())?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                let file_path: String = row.get(0)?;
+                let vector_blob: Vec<u8> = row.get(1)?;
+                Ok((file_path, vector_blob))
+            })
+            .map_err(|e| e.to_string())?;
+
+        // Running sums and chunk counts per file
+        let mut sums: HashMap<String, (Vec<f32>, usize)> = HashMap::new();
+        for row in rows {
+            let (file_path, vector_blob) = row.map_err(|e| e.to_string())?;
+            let vector: Vec<f32> = vector_blob
+                .chunks_exact(4)
+                .map(|chunk| {
+                    let bytes: [u8; 4] = chunk.try_into().unwrap();
+                    f32::from_le_bytes(bytes)
+                })
+                .collect();
+
+            let entry = sums
+                .entry(file_path)
+                .or_insert_with(|| (vec![0.0; vector.len()], 0));
+            if entry.0.len() != vector.len() {
+                continue; // Skip chunks embedded with a different model
+            }
+            for (sum, value) in entry.0.iter_mut().zip(&vector) {
+                *sum += value;
+            }
+            entry.1 += 1;
+        }
+
+        Ok(sums
+            .into_iter()
+            .filter(|(_, (_, count))| *count > 0)
+            .map(|(file_path, (sum, count))| {
+                let average = sum.into_iter().map(|v| v / count as f32).collect();
+                (file_path, average)
+            })
+            .collect())
+    }
+
+    /// Dimension of the stored embeddings (None when the store is empty)
+    pub fn check_vector_dimension_consistency(&self) -> Result<Option<usize>, String> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT LENGTH(vector)/4 as dim FROM chunks LIMIT 1")
+            .map_err(|e| e.to_string())?;
+
+        let mut rows = stmt.query([]).map_err(|e| e.to_string())?;
+        match rows.next().map_err(|e| e.to_string())? {
+            Some(row) => {
+                let dim: i64 = row.get(0).map_err(|e| e.to_string())?;
+                Ok(Some(dim as usize))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn count_chunks(&self) -> Result<usize, String> {
+        self.conn
+            .query_row("SELECT COUNT(*) FROM chunks", [], |row| {
+                row.get::<_, i64>(0)
+            })
+            .map(|count| count as usize)
+            .map_err(|e| e.to_string())
+    }
+
+    /// Record that a file (with the given modification time) was just indexed
+    pub fn record_file_indexed(&self, file_path: &str, mtime: u64) -> Result<(), String> {
+        let indexed_at = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        self.conn
+            .execute(
+                "INSERT OR REPLACE INTO file_mtimes (file_path, mtime, indexed_at) VALUES (?1, ?2, ?3)",
+                params![file_path, mtime as i64, indexed_at as i64],
+            )
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// Indexed files with their chunk counts, most recently modified first
+    /// Ties are broken by when the file was indexed
+    pub fn files_by_recency(&self) -> Result<Vec<(String, usize)>, String> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT c.file_path, COUNT(*) FROM chunks c
+                 LEFT JOIN file_mtimes m ON m.file_path = c.file_path
+                 GROUP BY c.file_path
+                 ORDER BY COALESCE(m.mtime, 0) DESC, COALESCE(m.indexed_at, 0) DESC, c.file_path",
+            )
+            .map_err(|e| e.to_string())?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                let file_path: String = row.get(0)?;
+                let count: i64 = row.get(1)?;
+                Ok((file_path, count as usize))
+            })
+            .map_err(|e| e.to_string())?;
+
+        rows.collect::<Result<Vec<_>>>().map_err(|e| e.to_string())
+    }
+
+    /// Delete every chunk belonging to a file, returning how many were removed
+    pub fn delete_file(&self, file_path: &str) -> Result<usize, String> {
+        let removed = self
+            .conn
+            .execute(
+                "DELETE FROM chunks WHERE file_path = ?1",
+                params![file_path],
+            )
+            .map_err(|e| e.to_string())?;
+        self.conn
+            .execute(
+                "DELETE FROM file_mtimes WHERE file_path = ?1",
+                params![file_path],
+            )
+            .map_err(|e| e.to_string())?;
+        Ok(removed)
+    }
+
     // Helper to clear the store before re-indexing
     pub fn clear(&self) -> Result<(), String> {
         self.conn
             .execute("DELETE FROM chunks", [])
             .map_err(|e| e.to_string())?;
+        self.conn
+            .execute("DELETE FROM file_mtimes", [])
+            .map_err(|e| e.to_string())?;
         Ok(())
     }
 }
 
-fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
-    let dot_product: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+/// Chunk and file counts plus the database size
+pub fn get_store_stats(store: &VectorStore) -> Result<StoreStats, String> {
+    let unique_files: i64 = store
+        .conn
+        .query_row("SELECT COUNT(DISTINCT file_path) FROM chunks", [], |row| {
+            row.get(0)
+        })
+        .map_err(|e| e.to_string())?;
+    let size_bytes: i64 = store
+        .conn
+        .query_row(
+            "SELECT page_count * page_size FROM pragma_page_count(), pragma_page_size()",
+            [],
+            |row| row.get(0),
+        )
+        .map_err(|e| e.to_string())?;
+
+    Ok(StoreStats {
+        total_chunks: store.count_chunks()?,
+        unique_files: unique_files as usize,
+        size_bytes: size_bytes as u64,
+    })
+}
+
+/// Keep the `keep_count` most recently modified files and drop the rest
+/// Returns the number of chunks removed
+pub fn prune_oldest_files(store: &mut VectorStore, keep_count: usize) -> Result<usize, String> {
+    let files = store.files_by_recency()?;
+    let mut removed = 0;
+    for (file_path, _) in files.iter().skip(keep_count) {
+        removed += store.delete_file(file_path)?;
+    }
+    Ok(removed)
+}
+
+fn dot_product(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn euclidean_distance(a: &[f32], b: &[f32]) -> f32 {
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| (x - y) * (x - y))
+        .sum::<f32>()
+        .sqrt()
+}
+
+pub(crate) fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot_product = dot_product(a, b);
     let norm_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
     let norm_b: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
 
@@ -138,3 +387,65 @@ fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
         dot_product / (norm_a * norm_b)
     }
 }
+
+/// Compare every pair of note embeddings and keep those above `threshold`
+/// Results are sorted by similarity, most similar first
+pub fn find_similar_pairs(
+    embeddings: &HashMap<String, Vec<f32>>,
+    threshold: f32,
+) -> Vec<DuplicatePair> {
+    let mut files: Vec<(&String, &Vec<f32>)> = embeddings.iter().collect();
+    files.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut pairs = Vec::new();
+    for (i, (path_a, vector_a)) in files.iter().enumerate() {
+        for (path_b, vector_b) in &files[i + 1..] {
+            let similarity = cosine_similarity(vector_a, vector_b);
+            if similarity > threshold {
+                pairs.push(DuplicatePair {
+                    note_a: path_a.to_string(),
+                    note_b: path_b.to_string(),
+                    similarity,
+                });
+            }
+        }
+    }
+
+    pairs.sort_by(|a, b| {
+        b.similarity
+            .partial_cmp(&a.similarity)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    pairs
+}
+
+/// Upper triangle of the note-to-note cosine similarity matrix
+/// Only pairs above `threshold` are kept, most similar first, at most `max_pairs`
+pub fn similarity_matrix(
+    embeddings: &HashMap<String, Vec<f32>>,
+    threshold: f32,
+    max_pairs: usize,
+) -> SimilarityMatrix {
+    let mut note_ids: Vec<String> = embeddings.keys().cloned().collect();
+    note_ids.sort();
+    let vectors: Vec<&Vec<f32>> = note_ids.iter().map(|id| &embeddings[id]).collect();
+
+    let mut pairs = Vec::new();
+    for i in 0..vectors.len() {
+        for j in i + 1..vectors.len() {
+            // Vectors from different embedding models can't be compared
+            if vectors[i].len() != vectors[j].len() {
+                continue;
+            }
+            let similarity = cosine_similarity(vectors[i], vectors[j]);
+            if similarity > threshold {
+                pairs.push((i, j, similarity));
+            }
+        }
+    }
+
+    pairs.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal));
+    pairs.truncate(max_pairs);
+
+    SimilarityMatrix { note_ids, pairs }
+}
@@ -1,42 +1,55 @@
 use notify::{RecommendedWatcher, RecursiveMode, Watcher};
 use notify_debouncer_full::{new_debouncer, DebouncedEvent, Debouncer, FileIdMap};
+use std::collections::HashMap;
 use std::path::Path;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tauri::{Emitter, State};
 
+/// Slot used when the caller doesn't pass a vault ID (single-vault mode)
+const DEFAULT_SLOT: &str = "default";
+
+/// Prefix of the watcher slots used for git worktrees
+const WORKTREE_SLOT_PREFIX: &str = "worktree:";
+
 pub struct WatcherState {
-    pub watcher: Arc<Mutex<Option<Debouncer<RecommendedWatcher, FileIdMap>>>>,
+    // One watcher per vault ID, so several vaults can be watched at once
+    pub watchers: Arc<Mutex<HashMap<String, Debouncer<RecommendedWatcher, FileIdMap>>>>,
 }
 
 impl WatcherState {
     pub fn new() -> Self {
         Self {
-            watcher: Arc::new(Mutex::new(None)),
+            watchers: Arc::new(Mutex::new(HashMap::new())),
         }
     }
-}
 
-#[tauri::command]
-pub async fn watch_vault(
-    app_handle: tauri::AppHandle,
-    state: State<'_, WatcherState>,
-    vault_path: String,
-) -> Result<(), String> {
-    let path = Path::new(&vault_path);
-    if !path.exists() {
-        return Err(format!("Path does not exist: {}", vault_path));
+    /// Stop the watcher in a slot, if there is one
+    pub fn stop(&self, vault_id: &str) -> Result<(), String> {
+        self.watchers
+            .lock()
+            .map_err(|e| e.to_string())?
+            .remove(vault_id);
+        Ok(())
     }
 
-    let mut watcher_guard = state.watcher.lock().map_err(|e| e.to_string())?;
-
-    // Stop existing watcher if any
-    if watcher_guard.is_some() {
-        *watcher_guard = None;
+    /// Stop every git worktree watcher
+    pub fn stop_worktrees(&self) -> Result<(), String> {
+        self.watchers
+            .lock()
+            .map_err(|e| e.to_string())?
+            .retain(|slot, _| !slot.starts_with(WORKTREE_SLOT_PREFIX));
+        Ok(())
     }
+}
 
-    let app_handle_clone = app_handle.clone();
-
+/// Recursive watcher emitting `file-changed` with `event_vault_id` as payload
+/// Changes inside `.git` and `.moss` are ignored
+fn create_vault_watcher(
+    app_handle: tauri::AppHandle,
+    path: &Path,
+    event_vault_id: Option<String>,
+) -> Result<Debouncer<RecommendedWatcher, FileIdMap>, String> {
     // Create a new debouncer
     let mut debouncer = new_debouncer(
         Duration::from_millis(500),
@@ -57,7 +70,7 @@ pub async fn watch_vault(
                         });
 
                         if has_relevant_changes {
-                            let _ = app_handle_clone.emit("file-changed", ());
+                            let _ = app_handle.emit("file-changed", event_vault_id.clone());
                         }
                     }
                 }
@@ -77,7 +90,241 @@ pub async fn watch_vault(
 
     debouncer.cache().add_root(path, RecursiveMode::Recursive);
 
-    *watcher_guard = Some(debouncer);
+    Ok(debouncer)
+}
+
+/// Watch a vault for changes and emit `file-changed` (with the vault ID as payload)
+/// Watching again under the same vault ID replaces the previous watcher
+#[tauri::command]
+pub async fn watch_vault(
+    app_handle: tauri::AppHandle,
+    state: State<'_, WatcherState>,
+    registry: State<'_, crate::vault_registry::VaultRegistry>,
+    vault_path: String,
+    vault_id: Option<String>,
+) -> Result<(), String> {
+    let path = Path::new(&vault_path);
+    if !path.exists() {
+        return Err(format!("Path does not exist: {}", vault_path));
+    }
+
+    let mut watchers = state.watchers.lock().map_err(|e| e.to_string())?;
+
+    // Stop existing watcher for this slot if any
+    let slot = vault_id.clone().unwrap_or_else(|| DEFAULT_SLOT.to_string());
+    watchers.remove(&slot);
+
+    let debouncer = create_vault_watcher(app_handle.clone(), path, vault_id.clone())?;
+
+    watchers.insert(slot, debouncer);
+    drop(watchers);
+    if let Some(vault_id) = &vault_id {
+        registry.set_watcher_active(vault_id, true);
+    }
+
+    // Only the active vault's worktrees are watched
+    let is_active = match &vault_id {
+        Some(vault_id) => {
+            registry
+                .active
+                .lock()
+                .map_err(|e| e.to_string())?
+                .as_deref()
+                == Some(vault_id.as_str())
+        }
+        None => true,
+    };
+    if is_active {
+        watch_vault_worktrees(app_handle, &state, path)?;
+    }
 
     Ok(())
 }
+
+/// Replace the worktree watchers with ones for `vault`'s worktrees
+/// Worktree watchers don't outlive the app, so they're brought back with their vault
+pub(crate) fn watch_vault_worktrees(
+    app_handle: tauri::AppHandle,
+    state: &WatcherState,
+    vault: &Path,
+) -> Result<(), String> {
+    state.stop_worktrees()?;
+
+    if let Some(repo) = crate::git_manager::open_repository(vault) {
+        for worktree in crate::git_manager::list_worktrees(&repo).unwrap_or_default() {
+            if !worktree.is_prunable && Path::new(&worktree.path).is_dir() {
+                if let Err(e) = watch_worktree(app_handle.clone(), state, &worktree.path) {
+                    eprintln!("Failed to watch worktree {}: {}", worktree.path, e);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Stop watching a vault
+#[tauri::command]
+pub async fn unwatch_vault(
+    state: State<'_, WatcherState>,
+    registry: State<'_, crate::vault_registry::VaultRegistry>,
+    vault_id: Option<String>,
+) -> Result<(), String> {
+    match vault_id {
+        Some(vault_id) => {
+            state.stop(&vault_id)?;
+            registry.set_watcher_active(&vault_id, false);
+            if registry
+                .active
+                .lock()
+                .map_err(|e| e.to_string())?
+                .as_deref()
+                == Some(&vault_id)
+            {
+                state.stop_worktrees()?;
+            }
+            Ok(())
+        }
+        None => {
+            state.stop(DEFAULT_SLOT)?;
+            state.stop_worktrees()
+        }
+    }
+}
+
+/// Watcher slot for a git worktree, also used as its `file-changed` payload
+/// Keyed by canonical path so every spelling of the path maps to one watcher
+fn worktree_slot(worktree_path: &str) -> String {
+    let path = Path::new(worktree_path)
+        .canonicalize()
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|_| worktree_path.to_string());
+    format!("{}{}", WORKTREE_SLOT_PREFIX, path)
+}
+
+/// Watch a git worktree alongside its vault
+pub(crate) fn watch_worktree(
+    app_handle: tauri::AppHandle,
+    state: &WatcherState,
+    worktree_path: &str,
+) -> Result<(), String> {
+    let slot = worktree_slot(worktree_path);
+    let debouncer = create_vault_watcher(app_handle, Path::new(worktree_path), Some(slot.clone()))?;
+    state
+        .watchers
+        .lock()
+        .map_err(|e| e.to_string())?
+        .insert(slot, debouncer);
+    Ok(())
+}
+
+pub(crate) fn unwatch_worktree(state: &WatcherState, worktree_path: &str) -> Result<(), String> {
+    state.stop(&worktree_slot(worktree_path))
+}
+
+// ============================================================================
+// External Editors
+// ============================================================================
+
+/// How often the editor process is checked for exit
+const EDITOR_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, serde::Serialize)]
+struct ExternalEditorEvent {
+    note_path: String,
+    pid: u32,
+}
+
+/// Open a note in an external editor (e.g. `nvim`, `code --wait`)
+/// Emits `external-editor-saved` whenever the file changes and
+/// `external-editor-closed` once the editor exits. Returns the editor's PID
+#[tauri::command]
+pub async fn open_note_in_external_editor(
+    app_handle: tauri::AppHandle,
+    vault_path: String,
+    note_path: String,
+    editor_command: String,
+) -> Result<u32, String> {
+    let vault = Path::new(&vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let file_path = crate::tools::resolve_note_path(vault, &note_path)
+        .ok_or_else(|| format!("Note '{}' not found", note_path))?;
+    let (Some(parent), Some(file_name)) = (file_path.parent(), file_path.file_name()) else {
+        return Err(format!("Note '{}' not found", note_path));
+    };
+
+    // Split like a shell would, so quoted paths with spaces stay one argument
+    let parts = shlex::split(&editor_command)
+        .ok_or_else(|| "Editor command has unbalanced quotes".to_string())?;
+    let (program, args) = parts
+        .split_first()
+        .ok_or_else(|| "Editor command is empty".to_string())?;
+
+    let mut child = std::process::Command::new(program)
+        .args(args)
+        .arg(&file_path)
+        .spawn()
+        .map_err(|e| format!("Failed to launch '{}': {}", program, e))?;
+    let pid = child.id();
+
+    // Remember the editor for next time
+    let mut config = crate::vault_config::load_config(vault)?;
+    if config.external_editor.as_deref() != Some(editor_command.trim()) {
+        config.external_editor = Some(editor_command.trim().to_string());
+        let _ = crate::vault_config::save_config(vault, &config);
+    }
+
+    // Watch the folder rather than the file, since many editors save by
+    // writing a new file and renaming it over the old one
+    let saved_handle = app_handle.clone();
+    let saved_event = ExternalEditorEvent {
+        note_path: note_path.clone(),
+        pid,
+    };
+    let file_name = file_name.to_os_string();
+    let mut debouncer = new_debouncer(
+        Duration::from_millis(500),
+        None,
+        move |result: Result<Vec<DebouncedEvent>, _>| {
+            if let Ok(events) = result {
+                let touched = events.iter().any(|e| {
+                    e.paths
+                        .iter()
+                        .any(|p| p.file_name() == Some(file_name.as_os_str()))
+                });
+                if touched {
+                    let _ = saved_handle.emit("external-editor-saved", saved_event.clone());
+                }
+            }
+        },
+    )
+    .map_err(|e| format!("Failed to create watcher: {:?}", e))?;
+
+    debouncer
+        .watcher()
+        .watch(parent, RecursiveMode::NonRecursive)
+        .map_err(|e| format!("Failed to watch path: {:?}", e))?;
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(EDITOR_POLL_INTERVAL);
+        loop {
+            interval.tick().await;
+            match child.try_wait() {
+                Ok(None) => continue,
+                Ok(Some(_)) | Err(_) => break,
+            }
+        }
+
+        // Stop watching before announcing the close
+        drop(debouncer);
+        let _ = app_handle.emit(
+            "external-editor-closed",
+            ExternalEditorEvent { note_path, pid },
+        );
+    });
+
+    Ok(pid)
+}
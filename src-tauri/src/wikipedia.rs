@@ -1,5 +1,6 @@
 use reqwest;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 // ============================================================================
 // Wikipedia API Types
@@ -31,18 +32,59 @@ pub struct WikiContent {
     pub url: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WikiLanguage {
+    pub code: String,
+    pub name: String,       // English name
+    pub local_name: String, // Name in the language itself
+}
+
 // ============================================================================
 // Wikipedia API Client
 // ============================================================================
 
-const WIKIPEDIA_API_BASE: &str = "https://en.wikipedia.org/api/rest_v1";
-const WIKIPEDIA_SEARCH_BASE: &str = "https://en.wikipedia.org/w/rest.php/v1";
+const DEFAULT_LANGUAGE: &str = "en";
+const SITEMATRIX_URL: &str = "https://meta.wikimedia.org/w/api.php";
 const USER_AGENT: &str = "Amber-Notes/1.0 (Educational note-taking app)";
 
+/// Language list fetched once per process
+static LANGUAGE_CACHE: std::sync::Mutex<Option<Vec<WikiLanguage>>> = std::sync::Mutex::new(None);
+
+/// Normalize a language code (defaults to English)
+fn language_code(language: Option<&str>) -> Result<String, String> {
+    let language = language
+        .map(|l| l.trim().to_lowercase())
+        .filter(|l| !l.is_empty())
+        .unwrap_or_else(|| DEFAULT_LANGUAGE.to_string());
+
+    // Language codes are short ASCII identifiers like "en", "pt" or "zh-yue"
+    let valid = language.len() <= 20
+        && language
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if !valid {
+        return Err(format!("Invalid Wikipedia language code '{}'", language));
+    }
+
+    Ok(language)
+}
+
+/// Resolve the Wikipedia host for a language code (defaults to English)
+fn wikipedia_host(language: Option<&str>) -> Result<String, String> {
+    Ok(format!(
+        "https://{}.wikipedia.org",
+        language_code(language)?
+    ))
+}
+
 /// Search Wikipedia for articles matching a query
-pub async fn search_wikipedia(query: &str, limit: usize) -> Result<SearchResults, String> {
+pub async fn search_wikipedia(
+    query: &str,
+    limit: usize,
+    language: Option<&str>,
+) -> Result<SearchResults, String> {
     // Use the correct Wikipedia REST API v1 search endpoint
-    let url = format!("{}/search/title", WIKIPEDIA_SEARCH_BASE);
+    let url = format!("{}/w/rest.php/v1/search/title", wikipedia_host(language)?);
 
     let client = reqwest::Client::builder()
         .timeout(std::time::Duration::from_secs(10)) // 10 second timeout
@@ -90,10 +132,13 @@ pub async fn search_wikipedia(query: &str, limit: usize) -> Result<SearchResults
 }
 
 /// Get summary/introduction of a Wikipedia article
-pub async fn get_wikipedia_summary(title: &str) -> Result<WikiSummary, String> {
+pub async fn get_wikipedia_summary(
+    title: &str,
+    language: Option<&str>,
+) -> Result<WikiSummary, String> {
     let url = format!(
-        "{}/page/summary/{}",
-        WIKIPEDIA_API_BASE,
+        "{}/api/rest_v1/page/summary/{}",
+        wikipedia_host(language)?,
         urlencoding::encode(title)
     );
 
@@ -135,10 +180,14 @@ pub async fn get_wikipedia_summary(title: &str) -> Result<WikiSummary, String> {
 }
 
 /// Get full Wikipedia article content in markdown format
-pub async fn get_wikipedia_content(title: &str) -> Result<WikiContent, String> {
+pub async fn get_wikipedia_content(
+    title: &str,
+    language: Option<&str>,
+) -> Result<WikiContent, String> {
+    let host = wikipedia_host(language)?;
     let url = format!(
-        "{}/page/html/{}",
-        WIKIPEDIA_API_BASE,
+        "{}/api/rest_v1/page/html/{}",
+        host,
         urlencoding::encode(title)
     );
 
@@ -181,10 +230,7 @@ pub async fn get_wikipedia_content(title: &str) -> Result<WikiContent, String> {
     }
 
     // Get the article URL
-    let article_url = format!(
-        "https://en.wikipedia.org/wiki/{}",
-        urlencoding::encode(title)
-    );
+    let article_url = format!("{}/wiki/{}", host, urlencoding::encode(title));
 
     Ok(WikiContent {
         title: title.to_string(),
@@ -192,3 +238,427 @@ pub async fn get_wikipedia_content(title: &str) -> Result<WikiContent, String> {
         url: article_url,
     })
 }
+
+/// List the languages that have a Wikipedia edition
+/// The list is fetched from the Wikimedia site matrix once and cached in memory
+pub async fn get_available_wikipedia_languages() -> Result<Vec<WikiLanguage>, String> {
+    if let Some(cached) = LANGUAGE_CACHE.lock().map_err(|e| e.to_string())?.clone() {
+        return Ok(cached);
+    }
+
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(10)) // 10 second timeout
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let response = client
+        .get(SITEMATRIX_URL)
+        .header("User-Agent", USER_AGENT)
+        .query(&[
+            ("action", "sitematrix"),
+            ("format", "json"),
+            ("smtype", "language"),
+            ("smlangprop", "code|name|localname|site"),
+            ("smsiteprop", "code"),
+        ])
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch Wikipedia languages: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Wikimedia API error: {}", response.status()));
+    }
+
+    let data: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Wikimedia response: {}", e))?;
+
+    let matrix = data["sitematrix"]
+        .as_object()
+        .ok_or("Missing site matrix in response")?;
+
+    // Entries are keyed by index, plus a "count" field we skip
+    let mut languages: Vec<WikiLanguage> = matrix
+        .values()
+        .filter(|entry| {
+            entry["site"].as_array().is_some_and(|sites| {
+                sites
+                    .iter()
+                    .any(|site| site["code"] == "wiki" && site.get("closed").is_none())
+            })
+        })
+        .filter_map(|entry| {
+            // In the site matrix "name" is the native name and "localname" the English one
+            Some(WikiLanguage {
+                code: entry["code"].as_str()?.to_string(),
+                name: entry["localname"].as_str()?.to_string(),
+                local_name: entry["name"].as_str()?.to_string(),
+            })
+        })
+        .collect();
+
+    languages.sort_by(|a, b| a.code.cmp(&b.code));
+
+    *LANGUAGE_CACHE.lock().map_err(|e| e.to_string())? = Some(languages.clone());
+
+    Ok(languages)
+}
+
+// ============================================================================
+// Wikipedia Note Import
+// ============================================================================
+
+/// Fetch a Wikipedia article and save it as a note in the vault
+/// Returns the path of the created note
+pub async fn create_note_from_wikipedia(
+    vault_path: &str,
+    title: &str,
+    language: Option<&str>,
+    folder: Option<&str>,
+) -> Result<String, String> {
+    let vault = std::path::Path::new(vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let summary = get_wikipedia_summary(title, language).await?;
+    let content = get_wikipedia_content(&summary.title, language).await?;
+
+    // Frontmatter with the article source
+    let mut mapping = serde_yaml::Mapping::new();
+    mapping.insert("source".into(), summary.url.clone().into());
+    mapping.insert(
+        "created".into(),
+        chrono::Local::now().format("%Y-%m-%d").to_string().into(),
+    );
+    mapping.insert(
+        "tags".into(),
+        serde_yaml::Value::Sequence(vec!["wikipedia".into()]),
+    );
+
+    let body = format!(
+        "# {}\n\n## Summary\n\n{}\n\n## Content\n\n{}\n",
+        summary.title,
+        summary.extract.trim(),
+        content.content.trim()
+    );
+    let note_content = crate::frontmatter::compose(&mapping, &body)?;
+
+    let file_stem = crate::tools::sanitize_file_stem(&summary.title);
+
+    let folder_path = match folder.filter(|f| !f.trim().is_empty()) {
+        Some(folder) => vault.join(folder),
+        None => vault.to_path_buf(),
+    };
+
+    std::fs::create_dir_all(&folder_path).map_err(|e| format!("Failed to create folder: {}", e))?;
+
+    // Check if file already exists (uniqueness check)
+    let mut final_path = folder_path.join(format!("{}.md", file_stem));
+    let mut counter = 1;
+    while final_path.exists() {
+        final_path = folder_path.join(format!("{} {}.md", file_stem, counter));
+        counter += 1;
+    }
+
+    std::fs::write(&final_path, note_content)
+        .map_err(|e| format!("Failed to write note: {}", e))?;
+
+    // Auto-commit if Git repository
+    if let Some(repo) = crate::git_manager::open_repository(vault) {
+        let _ = crate::git_manager::auto_commit_mosaic_changes(
+            &repo,
+            &format!("Imported Wikipedia: {}", summary.title),
+            &[&final_path],
+        ); // Silently fail if commit fails
+    }
+
+    Ok(final_path.to_string_lossy().to_string())
+}
+
+// ============================================================================
+// Wikidata Entities
+// ============================================================================
+
+const WIKIDATA_API_URL: &str = "https://www.wikidata.org/w/api.php";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WikidataEntity {
+    pub id: String,
+    pub label: String,
+    pub description: String,
+    pub aliases: Vec<String>,
+    pub wikipedia_title: Option<String>, // Article title in the requested language
+}
+
+/// Call the Wikidata action API and return the parsed JSON
+async fn wikidata_request(params: &[(&str, &str)]) -> Result<serde_json::Value, String> {
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(10))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let response = client
+        .get(WIKIDATA_API_URL)
+        .header("User-Agent", USER_AGENT)
+        .query(&[("format", "json")])
+        .query(params)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach Wikidata: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        return Err(format!("Wikidata API error: {} - {}", status, error_text));
+    }
+
+    let data: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Wikidata response: {}", e))?;
+
+    if let Some(error) = data["error"]["info"].as_str() {
+        return Err(format!("Wikidata API error: {}", error));
+    }
+
+    Ok(data)
+}
+
+/// Wikidata item/property ids look like "Q42" or "P31"
+fn validate_entity_id(entity_id: &str) -> Result<String, String> {
+    let id = entity_id.trim().to_uppercase();
+    let valid = id.len() > 1
+        && (id.starts_with('Q') || id.starts_with('P'))
+        && id[1..].chars().all(|c| c.is_ascii_digit());
+    if valid {
+        Ok(id)
+    } else {
+        Err(format!("Invalid Wikidata entity id '{}'", entity_id))
+    }
+}
+
+/// Search Wikidata items by label or alias
+pub async fn lookup_wikidata_entity(
+    query: &str,
+    language: Option<&str>,
+) -> Result<Vec<WikidataEntity>, String> {
+    let language = language_code(language)?;
+
+    let data = wikidata_request(&[
+        ("action", "wbsearchentities"),
+        ("search", query),
+        ("language", &language),
+        ("uselang", &language),
+        ("type", "item"),
+        ("limit", "10"),
+    ])
+    .await?;
+
+    let results = data["search"].as_array().cloned().unwrap_or_default();
+    if results.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // Second round-trip for full alias lists and the matching Wikipedia article
+    let ids: Vec<&str> = results.iter().filter_map(|r| r["id"].as_str()).collect();
+    let site = format!("{}wiki", language);
+    let details = wikidata_request(&[
+        ("action", "wbgetentities"),
+        ("ids", &ids.join("|")),
+        ("props", "aliases|sitelinks"),
+        ("languages", &language),
+        ("sitefilter", &site),
+    ])
+    .await
+    .unwrap_or_default();
+
+    Ok(results
+        .iter()
+        .filter_map(|result| {
+            let id = result["id"].as_str()?.to_string();
+            let entity = &details["entities"][&id];
+
+            let aliases = entity["aliases"][&language]
+                .as_array()
+                .map(|items| {
+                    items
+                        .iter()
+                        .filter_map(|a| a["value"].as_str().map(str::to_string))
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            Some(WikidataEntity {
+                label: result["label"].as_str().unwrap_or(&id).to_string(),
+                description: result["description"].as_str().unwrap_or("").to_string(),
+                aliases,
+                wikipedia_title: entity["sitelinks"][&site]["title"]
+                    .as_str()
+                    .map(str::to_string),
+                id,
+            })
+        })
+        .collect())
+}
+
+/// Fetch English labels for a set of entity ids (50 per request, the API limit)
+async fn wikidata_labels(ids: &[String]) -> Result<HashMap<String, String>, String> {
+    let mut labels = HashMap::new();
+
+    for batch in ids.chunks(50) {
+        let data = wikidata_request(&[
+            ("action", "wbgetentities"),
+            ("ids", &batch.join("|")),
+            ("props", "labels"),
+            ("languages", DEFAULT_LANGUAGE),
+        ])
+        .await?;
+
+        for id in batch {
+            if let Some(label) = data["entities"][id]["labels"][DEFAULT_LANGUAGE]["value"].as_str()
+            {
+                labels.insert(id.clone(), label.to_string());
+            }
+        }
+    }
+
+    Ok(labels)
+}
+
+/// Render a claim's main value; entity references are returned as ids to label later
+fn claim_value(claim: &serde_json::Value) -> Option<String> {
+    let value = &claim["mainsnak"]["datavalue"]["value"];
+    match claim["mainsnak"]["datavalue"]["type"].as_str()? {
+        "wikibase-entityid" => value["id"].as_str().map(str::to_string),
+        "string" => value.as_str().map(str::to_string),
+        "monolingualtext" => value["text"].as_str().map(str::to_string),
+        "time" => value["time"].as_str().map(|t| {
+            t.trim_start_matches('+')
+                .split('T')
+                .next()
+                .unwrap_or(t)
+                .to_string()
+        }),
+        "quantity" => value["amount"]
+            .as_str()
+            .map(|a| a.trim_start_matches('+').to_string()),
+        "globecoordinate" => Some(format!(
+            "{}, {}",
+            value["latitude"].as_f64()?,
+            value["longitude"].as_f64()?
+        )),
+        _ => None,
+    }
+}
+
+/// Get an entity's statements as `property label -> value labels`
+pub async fn get_wikidata_entity_properties(
+    entity_id: &str,
+) -> Result<HashMap<String, Vec<String>>, String> {
+    let id = validate_entity_id(entity_id)?;
+    let data = wikidata_request(&[
+        ("action", "wbgetentities"),
+        ("ids", &id),
+        ("props", "claims"),
+    ])
+    .await?;
+
+    let claims = data["entities"][&id]["claims"]
+        .as_object()
+        .ok_or_else(|| format!("Wikidata entity '{}' not found", id))?;
+
+    let mut raw: Vec<(String, Vec<String>)> = Vec::new();
+    for (property, statements) in claims {
+        let values: Vec<String> = statements
+            .as_array()
+            .map(|items| items.iter().filter_map(claim_value).collect())
+            .unwrap_or_default();
+        if !values.is_empty() {
+            raw.push((property.clone(), values));
+        }
+    }
+
+    // Resolve property ids and entity-valued claims to readable labels
+    let mut to_label: Vec<String> = raw.iter().map(|(p, _)| p.clone()).collect();
+    for (_, values) in &raw {
+        to_label.extend(
+            values
+                .iter()
+                .filter(|v| validate_entity_id(v).is_ok())
+                .cloned(),
+        );
+    }
+    to_label.sort();
+    to_label.dedup();
+    let labels = wikidata_labels(&to_label).await?;
+
+    let mut properties: HashMap<String, Vec<String>> = HashMap::new();
+    for (property, values) in raw {
+        let name = labels.get(&property).cloned().unwrap_or(property);
+        let readable = values
+            .into_iter()
+            .map(|v| labels.get(&v).cloned().unwrap_or(v))
+            .collect::<Vec<_>>();
+        properties.entry(name).or_default().extend(readable);
+    }
+
+    Ok(properties)
+}
+
+/// Add a Markdown link to a Wikidata entity under the note's `references:` frontmatter
+pub async fn insert_wikidata_link(
+    entity_id: &str,
+    note_path: &str,
+    vault_path: &str,
+) -> Result<(), String> {
+    let vault = std::path::Path::new(vault_path);
+    if !vault.exists() || !vault.is_dir() {
+        return Err(format!("Vault path '{}' does not exist", vault_path));
+    }
+
+    let id = validate_entity_id(entity_id)?;
+    let full_path = crate::tools::resolve_note_path(vault, note_path)
+        .ok_or_else(|| format!("Note '{}' not found", note_path))?;
+    let content =
+        std::fs::read_to_string(&full_path).map_err(|e| format!("Failed to read note: {}", e))?;
+
+    let label = wikidata_labels(std::slice::from_ref(&id))
+        .await?
+        .remove(&id)
+        .unwrap_or_else(|| id.clone());
+    let link = format!("[{}](https://www.wikidata.org/wiki/{})", label, id);
+
+    let mut mapping = crate::frontmatter::parse(&content)?;
+    let mut references = match mapping.get("references") {
+        Some(serde_yaml::Value::Sequence(items)) => items.clone(),
+        Some(serde_yaml::Value::String(single)) => vec![single.clone().into()],
+        _ => Vec::new(),
+    };
+
+    let entity_url = format!("wikidata.org/wiki/{}", id);
+    let already_linked = references
+        .iter()
+        .any(|r| r.as_str().is_some_and(|r| r.contains(&entity_url)));
+    if already_linked {
+        return Ok(());
+    }
+
+    references.push(link.into());
+    mapping.insert("references".into(), serde_yaml::Value::Sequence(references));
+
+    let updated = crate::frontmatter::compose(&mapping, crate::frontmatter::strip(&content))?;
+    std::fs::write(&full_path, updated).map_err(|e| format!("Failed to write note: {}", e))?;
+
+    // Auto-commit if Git repository
+    if let Some(repo) = crate::git_manager::open_repository(vault) {
+        let _ = crate::git_manager::auto_commit_mosaic_changes(
+            &repo,
+            &format!("Added Wikidata reference: {}", label),
+            &[&full_path],
+        ); // Silently fail if commit fails
+    }
+
+    Ok(())
+}
@@ -585,6 +585,7 @@ export async function executeTool(
                 }
                 const results = await invoke<NoteMetadata[]>('agent_list_all_notes', {
                     vaultPath: vaultPath,
+                    includeStarredFirst: false,
                 });
                 return results;
             }
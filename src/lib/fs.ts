@@ -17,7 +17,10 @@ import { invoke } from '@tauri-apps/api/core';
 
 export const readVault = async (path: string): Promise<FileNode[]> => {
     try {
-        return await invoke<FileNode[]>('get_file_tree', { vaultPath: path });
+        return await invoke<FileNode[]>('get_file_tree', {
+            vaultPath: path,
+            applySortConfigs: false,
+        });
     } catch (e) {
         console.error('Failed to read vault:', e);
         return [];